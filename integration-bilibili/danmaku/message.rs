@@ -0,0 +1,216 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, PartialEq)]
+pub enum LiveMessage {
+    Danmaku {
+        timestamp: DateTime<Utc>,
+        uid: i64,
+        uname: String,
+        text: String,
+        /// 0 for none, 1 for governor, 2 for admiral, 3 for captain
+        guard_level: i32,
+    },
+    SuperChat {
+        timestamp: DateTime<Utc>,
+        uid: i64,
+        uname: String,
+        message: String,
+        /// in CNY
+        price: i64,
+    },
+    Gift {
+        timestamp: DateTime<Utc>,
+        uid: i64,
+        uname: String,
+        gift_name: String,
+        num: i64,
+        /// either "gold" (paid, 1000 coins for 1 CNY) or "silver" (free)
+        coin_type: String,
+        total_coin: i64,
+    },
+    Guard {
+        timestamp: DateTime<Utc>,
+        uid: i64,
+        uname: String,
+        guard_level: i32,
+        num: i64,
+        /// price of each month in gold coins
+        price: i64,
+    },
+}
+
+#[derive(Deserialize)]
+struct Command {
+    cmd: String,
+    #[serde(default)]
+    info: Value,
+    #[serde(default)]
+    data: Value,
+}
+
+#[derive(Deserialize)]
+struct SuperChatData {
+    uid: i64,
+    price: i64,
+    message: String,
+    start_time: i64,
+    user_info: SuperChatUserInfo,
+}
+
+#[derive(Deserialize)]
+struct SuperChatUserInfo {
+    uname: String,
+}
+
+#[derive(Deserialize)]
+struct GiftData {
+    uid: i64,
+    uname: String,
+    #[serde(rename = "giftName")]
+    gift_name: String,
+    num: i64,
+    coin_type: String,
+    total_coin: i64,
+    timestamp: i64,
+}
+
+#[derive(Deserialize)]
+struct GuardData {
+    uid: i64,
+    username: String,
+    guard_level: i32,
+    num: i64,
+    price: i64,
+    start_time: i64,
+}
+
+/// parses body of a command packet, returns `None` for commands we don't care about
+pub fn parse_command(body: &[u8]) -> Option<LiveMessage> {
+    let command: Command = match serde_json::from_slice(body) {
+        Ok(command) => command,
+        Err(err) => {
+            tracing::warn!("Failed to parse bilibili command: {err}");
+            return None;
+        }
+    };
+
+    // cmd of danmaku may contain a suffix like `DANMU_MSG:4:0:2:2:2:0`
+    match command.cmd.split(':').next()? {
+        "DANMU_MSG" => parse_danmaku(&command.info),
+        "SUPER_CHAT_MESSAGE" => {
+            let data: SuperChatData = serde_json::from_value(command.data).ok()?;
+            Some(LiveMessage::SuperChat {
+                timestamp: Utc.timestamp_opt(data.start_time, 0).single()?,
+                uid: data.uid,
+                uname: data.user_info.uname,
+                message: data.message,
+                price: data.price,
+            })
+        }
+        "SEND_GIFT" => {
+            let data: GiftData = serde_json::from_value(command.data).ok()?;
+            Some(LiveMessage::Gift {
+                timestamp: Utc.timestamp_opt(data.timestamp, 0).single()?,
+                uid: data.uid,
+                uname: data.uname,
+                gift_name: data.gift_name,
+                num: data.num,
+                coin_type: data.coin_type,
+                total_coin: data.total_coin,
+            })
+        }
+        "GUARD_BUY" => {
+            let data: GuardData = serde_json::from_value(command.data).ok()?;
+            Some(LiveMessage::Guard {
+                timestamp: Utc.timestamp_opt(data.start_time, 0).single()?,
+                uid: data.uid,
+                uname: data.username,
+                guard_level: data.guard_level,
+                num: data.num,
+                price: data.price,
+            })
+        }
+        _ => None,
+    }
+}
+
+// info is a positional array:
+// [[.., .., .., .., timestamp_ms, ..], text, [uid, uname, ..], medal, level, title, .., guard_level, ..]
+fn parse_danmaku(info: &Value) -> Option<LiveMessage> {
+    Some(LiveMessage::Danmaku {
+        timestamp: Utc.timestamp_millis_opt(info[0][4].as_i64()?).single()?,
+        uid: info[2][0].as_i64()?,
+        uname: info[2][1].as_str()?.to_string(),
+        text: info[1].as_str()?.to_string(),
+        guard_level: info[7].as_i64().unwrap_or_default() as i32,
+    })
+}
+
+#[test]
+fn test_parse_command() {
+    use super::packet::decode;
+
+    let messages: Vec<_> = decode(include_bytes!("./testdata/zlib.bin"))
+        .unwrap()
+        .iter()
+        .filter_map(|p| parse_command(&p.body))
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            LiveMessage::Danmaku {
+                timestamp: Utc.timestamp_millis_opt(1710763300123).unwrap(),
+                uid: 12345678,
+                uname: "用户A".into(),
+                text: "草".into(),
+                guard_level: 0,
+            },
+            LiveMessage::Gift {
+                timestamp: Utc.timestamp_opt(1710763320, 0).unwrap(),
+                uid: 34567890,
+                uname: "用户C".into(),
+                gift_name: "小花花".into(),
+                num: 5,
+                coin_type: "gold".into(),
+                total_coin: 500,
+            },
+        ]
+    );
+
+    let messages: Vec<_> = decode(include_bytes!("./testdata/brotli.bin"))
+        .unwrap()
+        .iter()
+        .filter_map(|p| parse_command(&p.body))
+        .collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            LiveMessage::SuperChat {
+                timestamp: Utc.timestamp_opt(1710763310, 0).unwrap(),
+                uid: 23456789,
+                uname: "用户B".into(),
+                message: "今天也辛苦了！".into(),
+                price: 30,
+            },
+            LiveMessage::Guard {
+                timestamp: Utc.timestamp_opt(1710763330, 0).unwrap(),
+                uid: 45678901,
+                uname: "用户D".into(),
+                guard_level: 3,
+                num: 1,
+                price: 198000,
+            },
+            LiveMessage::Danmaku {
+                timestamp: Utc.timestamp_millis_opt(1710763340456).unwrap(),
+                uid: 45678901,
+                uname: "用户D".into(),
+                text: "上舰了".into(),
+                guard_level: 3,
+            },
+        ]
+    );
+}
@@ -0,0 +1,134 @@
+//! Bilibili live danmaku protocol over websocket
+//!
+//! Every websocket frame carries one or more packets, and each packet
+//! has a 16-byte header followed by a body, see [`encode`] for details.
+//! Client must send an auth packet right after connected, and a heartbeat
+//! packet every 30 seconds, otherwise the connection will be closed.
+
+mod message;
+mod packet;
+
+use std::{env, time::Duration};
+
+use anyhow::Result;
+use futures_util::{SinkExt, StreamExt};
+use reqwest::{header::COOKIE, Client, Url};
+use serde::Deserialize;
+use serde_json::json;
+use tokio::{net::TcpStream, time::Interval};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use vtstats_utils::send_request;
+
+pub use self::message::*;
+pub use self::packet::*;
+
+const FALLBACK_HOST: &str = "broadcastlv.chat.bilibili.com";
+
+#[derive(Deserialize)]
+struct DanmuInfoResponse {
+    data: DanmuInfo,
+}
+
+#[derive(Deserialize)]
+struct DanmuInfo {
+    token: String,
+    host_list: Vec<DanmuHost>,
+}
+
+#[derive(Deserialize)]
+struct DanmuHost {
+    host: String,
+    wss_port: u16,
+}
+
+pub struct DanmakuClient {
+    ws: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    heartbeat: Interval,
+}
+
+impl DanmakuClient {
+    /// connects anonymously if `BILIBILI_COOKIE` isn't set, in which case
+    /// bilibili may hide the authors of danmaku
+    pub async fn connect(room_id: &str, client: &Client) -> Result<Self> {
+        let url = Url::parse_with_params(
+            "https://api.live.bilibili.com/xlive/web-room/v1/index/getDanmuInfo",
+            &[("id", room_id), ("type", "0")],
+        )?;
+
+        let req = match env::var("BILIBILI_COOKIE") {
+            Ok(cookie) => client.get(url).header(COOKIE, cookie),
+            Err(_) => {
+                tracing::warn!("BILIBILI_COOKIE is not set, connecting anonymously");
+                client.get(url)
+            }
+        };
+
+        let res = send_request!(req)?;
+
+        let info: DanmuInfoResponse = res.json().await?;
+
+        let url = match info.data.host_list.first() {
+            Some(host) => format!("wss://{}:{}/sub", host.host, host.wss_port),
+            None => format!("wss://{FALLBACK_HOST}/sub"),
+        };
+
+        let (mut ws, _) = connect_async(url).await?;
+
+        let auth = json!({
+            "uid": 0,
+            "roomid": room_id.parse::<i64>()?,
+            "protover": PROTOVER_BROTLI,
+            "platform": "web",
+            "type": 2,
+            "key": info.data.token,
+        });
+
+        ws.send(Message::Binary(encode(
+            Operation::Auth,
+            PROTOVER_INT,
+            auth.to_string().as_bytes(),
+        )))
+        .await?;
+
+        Ok(DanmakuClient {
+            ws,
+            heartbeat: tokio::time::interval(Duration::from_secs(30)),
+        })
+    }
+
+    /// returns next batch of live messages, or `None` if connection was closed
+    pub async fn next(&mut self) -> Result<Option<Vec<LiveMessage>>> {
+        loop {
+            let frame = tokio::select! {
+                _ = self.heartbeat.tick() => {
+                    self.ws
+                        .send(Message::Binary(encode(
+                            Operation::Heartbeat,
+                            PROTOVER_INT,
+                            b"[object Object]",
+                        )))
+                        .await?;
+                    continue;
+                }
+                frame = self.ws.next() => frame,
+            };
+
+            let data = match frame.transpose()? {
+                Some(Message::Binary(data)) => data,
+                Some(Message::Close(_)) | None => return Ok(None),
+                Some(_) => continue,
+            };
+
+            let messages: Vec<_> = decode(&data)?
+                .into_iter()
+                .filter(|packet| packet.operation == Operation::Command)
+                .filter_map(|packet| parse_command(&packet.body))
+                .collect();
+
+            if !messages.is_empty() {
+                return Ok(Some(messages));
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::io::Read;
+
+use anyhow::Result;
+use brotli_decompressor::Decompressor;
+use flate2::read::ZlibDecoder;
+
+const HEADER_LEN: usize = 16;
+
+/// body is plain json
+pub const PROTOVER_JSON: u16 = 0;
+/// body is a big-endian integer, used by heartbeat reply
+pub const PROTOVER_INT: u16 = 1;
+/// body is zlib-compressed packets
+pub const PROTOVER_ZLIB: u16 = 2;
+/// body is brotli-compressed packets
+pub const PROTOVER_BROTLI: u16 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Heartbeat,
+    HeartbeatReply,
+    Command,
+    Auth,
+    AuthReply,
+    Unknown(u32),
+}
+
+impl From<u32> for Operation {
+    fn from(value: u32) -> Self {
+        match value {
+            2 => Operation::Heartbeat,
+            3 => Operation::HeartbeatReply,
+            5 => Operation::Command,
+            7 => Operation::Auth,
+            8 => Operation::AuthReply,
+            _ => Operation::Unknown(value),
+        }
+    }
+}
+
+impl From<Operation> for u32 {
+    fn from(value: Operation) -> Self {
+        match value {
+            Operation::Heartbeat => 2,
+            Operation::HeartbeatReply => 3,
+            Operation::Command => 5,
+            Operation::Auth => 7,
+            Operation::AuthReply => 8,
+            Operation::Unknown(op) => op,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Packet {
+    pub operation: Operation,
+    pub body: Vec<u8>,
+}
+
+/// encodes one packet, header fields are in big-endian:
+///
+/// | total length (u32) | header length (u16) | protover (u16) | operation (u32) | sequence (u32) |
+pub fn encode(operation: Operation, protover: u16, body: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(HEADER_LEN + body.len());
+    buf.extend_from_slice(&((HEADER_LEN + body.len()) as u32).to_be_bytes());
+    buf.extend_from_slice(&(HEADER_LEN as u16).to_be_bytes());
+    buf.extend_from_slice(&protover.to_be_bytes());
+    buf.extend_from_slice(&u32::from(operation).to_be_bytes());
+    buf.extend_from_slice(&1u32.to_be_bytes());
+    buf.extend_from_slice(body);
+    buf
+}
+
+/// decodes all packets from one websocket frame, compressed
+/// packets are decompressed and flattened
+pub fn decode(mut buf: &[u8]) -> Result<Vec<Packet>> {
+    let mut packets = Vec::new();
+
+    while !buf.is_empty() {
+        if buf.len() < HEADER_LEN {
+            anyhow::bail!("Incomplete packet header, len={}", buf.len());
+        }
+
+        let total_len = u32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
+        let header_len = u16::from_be_bytes([buf[4], buf[5]]) as usize;
+        let protover = u16::from_be_bytes([buf[6], buf[7]]);
+        let operation = u32::from_be_bytes([buf[8], buf[9], buf[10], buf[11]]).into();
+
+        if total_len < header_len || header_len < HEADER_LEN || total_len > buf.len() {
+            anyhow::bail!(
+                "Malformed packet, total_len={total_len} header_len={header_len} len={}",
+                buf.len()
+            );
+        }
+
+        let body = &buf[header_len..total_len];
+
+        match protover {
+            PROTOVER_ZLIB => {
+                let mut decompressed = Vec::new();
+                ZlibDecoder::new(body).read_to_end(&mut decompressed)?;
+                packets.extend(decode(&decompressed)?);
+            }
+            PROTOVER_BROTLI => {
+                let mut decompressed = Vec::new();
+                Decompressor::new(body, 4096).read_to_end(&mut decompressed)?;
+                packets.extend(decode(&decompressed)?);
+            }
+            _ => packets.push(Packet {
+                operation,
+                body: body.to_vec(),
+            }),
+        }
+
+        buf = &buf[total_len..];
+    }
+
+    Ok(packets)
+}
+
+#[test]
+fn test_encode() {
+    assert_eq!(
+        encode(Operation::Heartbeat, PROTOVER_INT, b"[object Object]"),
+        include_bytes!("./testdata/heartbeat.bin")
+    );
+}
+
+#[test]
+fn test_decode() {
+    let packets = decode(include_bytes!("./testdata/auth_reply.bin")).unwrap();
+    assert_eq!(packets.len(), 1);
+    assert_eq!(packets[0].operation, Operation::AuthReply);
+    assert_eq!(packets[0].body, br#"{"code":0}"#);
+
+    let packets = decode(include_bytes!("./testdata/heartbeat_reply.bin")).unwrap();
+    assert_eq!(packets.len(), 1);
+    assert_eq!(packets[0].operation, Operation::HeartbeatReply);
+    assert_eq!(packets[0].body[..4], 183412u32.to_be_bytes());
+
+    let packets = decode(include_bytes!("./testdata/zlib.bin")).unwrap();
+    assert_eq!(packets.len(), 2);
+    assert!(packets.iter().all(|p| p.operation == Operation::Command));
+
+    let packets = decode(include_bytes!("./testdata/brotli.bin")).unwrap();
+    assert_eq!(packets.len(), 4);
+    assert!(packets.iter().all(|p| p.operation == Operation::Command));
+
+    assert!(decode(&include_bytes!("./testdata/zlib.bin")[..20]).is_err());
+}
@@ -1 +1,3 @@
 pub mod channels;
+pub mod danmaku;
+pub mod live;
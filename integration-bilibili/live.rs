@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_json::{json, Value};
+
+use vtstats_utils::send_request;
+
+#[derive(Deserialize, Debug)]
+pub struct Response {
+    pub code: i32,
+    pub message: String,
+    pub data: Value,
+}
+
+impl Response {
+    // data is not an object when failed, so it's parsed after checking the code
+    fn into_data<T: DeserializeOwned>(self) -> Result<T> {
+        if self.code != 0 {
+            anyhow::bail!("Bilibili API error {}: {}", self.code, self.message);
+        }
+
+        Ok(serde_json::from_value(self.data)?)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "i32")]
+pub enum LiveStatus {
+    Offline,
+    Live,
+    /// room is playing archived videos in rotation
+    Round,
+}
+
+impl From<i32> for LiveStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => LiveStatus::Live,
+            2 => LiveStatus::Round,
+            _ => LiveStatus::Offline,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct StatusInfo {
+    pub uid: i64,
+    pub room_id: i64,
+    pub title: String,
+    pub online: i32,
+    /// unix timestamp in seconds, zero if offline
+    pub live_time: i64,
+    pub live_status: LiveStatus,
+    pub cover_from_user: String,
+}
+
+/// returns live room status of given user ids, keyed by user id
+///
+/// users without a live room are omitted
+pub async fn status_info_by_uids(
+    uids: &[&str],
+    client: &Client,
+) -> Result<HashMap<String, StatusInfo>> {
+    let req = client
+        .post("https://api.live.bilibili.com/room/v1/Room/get_status_info_by_uids")
+        .json(&json!({ "uids": uids }));
+
+    let res = send_request!(req)?;
+
+    let json: Response = res.json().await?;
+
+    Ok(json.into_data::<StatusInfos>()?.0)
+}
+
+#[derive(Deserialize)]
+struct StatusInfos(#[serde(deserialize_with = "map_or_empty_seq")] HashMap<String, StatusInfo>);
+
+// bilibili returns an empty array instead of an empty object
+fn map_or_empty_seq<'de, D>(deserializer: D) -> Result<HashMap<String, StatusInfo>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum MapOrSeq {
+        Map(HashMap<String, StatusInfo>),
+        Seq([(); 0]),
+    }
+
+    Ok(match MapOrSeq::deserialize(deserializer)? {
+        MapOrSeq::Map(map) => map,
+        MapOrSeq::Seq(_) => HashMap::new(),
+    })
+}
+
+#[test]
+fn de() {
+    let res: StatusInfos =
+        serde_json::from_str::<Response>(include_str!("./testdata/get_status_info_by_uids.0.json"))
+            .unwrap()
+            .into_data()
+            .unwrap();
+    let live = &res.0["672328094"];
+    assert_eq!(live.room_id, 22637261);
+    assert_eq!(live.live_status, LiveStatus::Live);
+    assert_eq!(live.live_time, 1710763205);
+    assert_eq!(res.0["672346917"].live_status, LiveStatus::Offline);
+
+    let res: StatusInfos =
+        serde_json::from_str::<Response>(include_str!("./testdata/get_status_info_by_uids.1.json"))
+            .unwrap()
+            .into_data()
+            .unwrap();
+    assert!(res.0.is_empty());
+
+    assert!(serde_json::from_str::<Response>(include_str!(
+        "./testdata/get_status_info_by_uids.2.json"
+    ))
+    .unwrap()
+    .into_data::<StatusInfos>()
+    .is_err());
+}
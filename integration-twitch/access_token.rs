@@ -25,3 +25,23 @@ pub async fn get_access_token(client: &Client) -> anyhow::Result<AccessToken> {
 
     Ok(token)
 }
+
+/// refreshes user access token with `TWITCH_USER_REFRESH_TOKEN`,
+/// which is required by eventsub websocket transport
+pub async fn get_user_access_token(client: &Client) -> anyhow::Result<AccessToken> {
+    let req = client
+        .post("https://id.twitch.tv/oauth2/token")
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(format!(
+            "client_id={}&client_secret={}&grant_type=refresh_token&refresh_token={}",
+            env::var("TWITCH_CLIENT_ID")?,
+            env::var("TWITCH_CLIENT_SECRET")?,
+            env::var("TWITCH_USER_REFRESH_TOKEN")?,
+        ));
+
+    let res = send_request!(req)?;
+
+    let token = res.json().await?;
+
+    Ok(token)
+}
@@ -0,0 +1,386 @@
+//! https://dev.twitch.tv/docs/irc/
+
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+use metrics::increment_counter;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{tcp::OwnedWriteHalf, TcpStream},
+    sync::mpsc::{
+        channel, error::TrySendError, unbounded_channel, Receiver, Sender, UnboundedReceiver,
+        UnboundedSender,
+    },
+    time::{interval, sleep, timeout, MissedTickBehavior},
+};
+use twitch_message::messages::{MessageKind, Ping, Privmsg, Reconnect, UserNotice};
+use twitch_message::{parse, ParseResult};
+
+use super::parse::{parse_privmsg, parse_usernotice, LiveChatMessage};
+
+// anonymous users can join 20 channels per 10 seconds
+const JOIN_INTERVAL: Duration = Duration::from_millis(500);
+// twitch sends PING about every 5 minutes
+const READ_TIMEOUT: Duration = Duration::from_secs(6 * 60);
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+// messages buffered for a chat room whose consumer falls behind, further
+// ones are dropped and counted in `twitch_chat_dropped_messages_total`
+const ROOM_CAPACITY: usize = 1024;
+
+enum Command {
+    Join {
+        login: String,
+        tx: Sender<LiveChatMessage>,
+    },
+    Part {
+        login: String,
+    },
+}
+
+/// anonymous irc connection shared by all chat rooms, which answers
+/// PINGs and reconnects with backoff, joined rooms are rejoined after
+/// reconnecting. the connection task stops once all clients and chat
+/// rooms are dropped, or if it panics, see `is_closed`.
+#[derive(Clone)]
+pub struct ChatClient {
+    commands: UnboundedSender<Command>,
+}
+
+impl ChatClient {
+    /// must be called within a tokio runtime
+    pub fn new() -> Self {
+        Self::with_address(twitch_message::TWITCH_IRC_ADDRESS)
+    }
+
+    pub fn with_address(address: &str) -> Self {
+        let (commands, rx) = unbounded_channel();
+        tokio::spawn(run(address.to_string(), rx));
+        ChatClient { commands }
+    }
+
+    /// returns `true` if the connection task has stopped, rooms joined
+    /// afterward never receive any message
+    pub fn is_closed(&self) -> bool {
+        self.commands.is_closed()
+    }
+
+    /// joins chat room of given channel, the room is left when
+    /// returned `ChatRoom` and all other ones of the same channel are dropped
+    pub fn join(&self, login: &str) -> ChatRoom {
+        let (tx, rx) = channel(ROOM_CAPACITY);
+        let login = login.to_lowercase();
+        let _ = self.commands.send(Command::Join {
+            login: login.clone(),
+            tx,
+        });
+        ChatRoom {
+            login,
+            rx,
+            commands: self.commands.clone(),
+        }
+    }
+}
+
+impl Default for ChatClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct ChatRoom {
+    login: String,
+    rx: Receiver<LiveChatMessage>,
+    commands: UnboundedSender<Command>,
+}
+
+impl ChatRoom {
+    /// returns `None` if the connection task has stopped
+    pub async fn recv(&mut self) -> Option<LiveChatMessage> {
+        self.rx.recv().await
+    }
+}
+
+impl Drop for ChatRoom {
+    fn drop(&mut self) {
+        // closes before parting, so the sender is recognized as gone
+        self.rx.close();
+        let _ = self.commands.send(Command::Part {
+            login: std::mem::take(&mut self.login),
+        });
+    }
+}
+
+/// chat rooms and their consumers, which outlive a single connection
+#[derive(Default)]
+struct Rooms {
+    consumers: HashMap<String, Vec<Sender<LiveChatMessage>>>,
+    pending_joins: VecDeque<String>,
+}
+
+impl Rooms {
+    /// returns login of the room to leave, if it has no consumer anymore
+    fn apply(&mut self, command: Command) -> Option<String> {
+        match command {
+            Command::Join { login, tx } => {
+                let consumers = self.consumers.entry(login.clone()).or_default();
+                if consumers.is_empty() && !self.pending_joins.contains(&login) {
+                    self.pending_joins.push_back(login);
+                }
+                consumers.push(tx);
+                None
+            }
+            Command::Part { login } => {
+                let consumers = self.consumers.get_mut(&login)?;
+                consumers.retain(|tx| !tx.is_closed());
+                if !consumers.is_empty() {
+                    return None;
+                }
+                self.consumers.remove(&login);
+                self.pending_joins.retain(|l| l != &login);
+                Some(login)
+            }
+        }
+    }
+
+    /// returns login of the room to leave, if all consumers are gone,
+    /// messages to consumers which fall behind are dropped
+    fn dispatch(&mut self, login: &str, msg: LiveChatMessage) -> Option<String> {
+        let consumers = self.consumers.get_mut(login)?;
+        consumers.retain(|tx| match tx.try_send(msg.clone()) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                increment_counter!(
+                    "twitch_chat_dropped_messages_total",
+                    "channel" => login.to_string()
+                );
+                true
+            }
+            Err(TrySendError::Closed(_)) => false,
+        });
+        if !consumers.is_empty() {
+            return None;
+        }
+        self.consumers.remove(login);
+        Some(login.to_string())
+    }
+
+    fn rejoin_all(&mut self) {
+        self.pending_joins = self.consumers.keys().cloned().collect();
+    }
+}
+
+enum SessionEnd {
+    /// all clients and chat rooms are dropped
+    Closed,
+    /// server asked for reconnecting
+    Reconnect,
+}
+
+async fn run(address: String, mut commands: UnboundedReceiver<Command>) {
+    let mut rooms = Rooms::default();
+    let mut backoff = MIN_BACKOFF;
+
+    loop {
+        rooms.rejoin_all();
+
+        match session(&address, &mut commands, &mut rooms, &mut backoff).await {
+            Ok(SessionEnd::Closed) => return,
+            Ok(SessionEnd::Reconnect) => {
+                tracing::warn!("Twitch irc server requested reconnecting");
+                continue;
+            }
+            Err(err) => {
+                tracing::warn!("Twitch irc connection lost, reconnecting in {backoff:?}: {err:?}");
+            }
+        }
+
+        sleep(backoff).await;
+        backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
+    }
+}
+
+async fn session(
+    address: &str,
+    commands: &mut UnboundedReceiver<Command>,
+    rooms: &mut Rooms,
+    backoff: &mut Duration,
+) -> anyhow::Result<SessionEnd> {
+    let (reader, mut writer) = TcpStream::connect(address).await?.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    // https://dev.twitch.tv/docs/irc/capabilities/
+    writer
+        .write_all(b"CAP REQ :twitch.tv/commands twitch.tv/membership twitch.tv/tags\r\n")
+        .await?;
+    writer.write_all(b"PASS justinfan2434\r\n").await?;
+    writer.write_all(b"NICK justinfan2434\r\n").await?;
+
+    loop {
+        let Some(line) = timeout(READ_TIMEOUT, lines.next_line()).await?? else {
+            anyhow::bail!("Connection closed before ready");
+        };
+        if parse(&line)?.message.kind == MessageKind::Ready {
+            break;
+        }
+    }
+
+    // connection works again, the next failure starts over
+    *backoff = MIN_BACKOFF;
+
+    let mut join_interval = interval(JOIN_INTERVAL);
+    join_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            line = timeout(READ_TIMEOUT, lines.next_line()) => {
+                let Some(line) = line?? else {
+                    anyhow::bail!("Connection closed by server");
+                };
+                if handle_line(&line, &mut writer, rooms).await? {
+                    return Ok(SessionEnd::Reconnect);
+                }
+            }
+            command = commands.recv() => {
+                let Some(command) = command else {
+                    return Ok(SessionEnd::Closed);
+                };
+                if let Some(login) = rooms.apply(command) {
+                    writer.write_all(format!("PART #{login}\r\n").as_bytes()).await?;
+                }
+            }
+            _ = join_interval.tick(), if !rooms.pending_joins.is_empty() => {
+                if let Some(login) = rooms.pending_joins.pop_front() {
+                    writer.write_all(format!("JOIN #{login}\r\n").as_bytes()).await?;
+                }
+            }
+        }
+    }
+}
+
+/// returns `true` if the server asked for reconnecting
+async fn handle_line(
+    line: &str,
+    writer: &mut OwnedWriteHalf,
+    rooms: &mut Rooms,
+) -> anyhow::Result<bool> {
+    let Ok(ParseResult { message, .. }) = parse(line) else {
+        tracing::warn!("failed to parse message line {line}");
+        return Ok(false);
+    };
+
+    let (channel, msg) = if message.as_typed_message::<Ping>().is_some() {
+        writer.write_all(b"PONG :tmi.twitch.tv\r\n").await?;
+        return Ok(false);
+    } else if message.as_typed_message::<Reconnect>().is_some() {
+        return Ok(true);
+    } else if let Some(msg) = message.as_typed_message::<Privmsg>() {
+        (msg.channel.to_string(), parse_privmsg(msg))
+    } else if let Some(msg) = message.as_typed_message::<UserNotice>() {
+        (msg.channel.to_string(), parse_usernotice(msg))
+    } else {
+        return Ok(false);
+    };
+
+    if let Some(msg) = msg {
+        let login = channel.trim_start_matches('#');
+        if let Some(login) = rooms.dispatch(login, msg) {
+            writer
+                .write_all(format!("PART #{login}\r\n").as_bytes())
+                .await?;
+        }
+    }
+
+    Ok(false)
+}
+
+#[tokio::test]
+async fn test_chat_client() {
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    async fn accept(listener: &TcpListener) -> (BufReader<TcpStream>, Vec<String>) {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+        let mut received = vec![];
+        let mut line = String::new();
+        // CAP, PASS and NICK
+        for _ in 0..3 {
+            line.clear();
+            stream.read_line(&mut line).await.unwrap();
+            received.push(line.trim_end().to_string());
+        }
+        stream
+            .write_all(b":tmi.twitch.tv 376 justinfan2434 :>\r\n")
+            .await
+            .unwrap();
+        (stream, received)
+    }
+
+    async fn read_line(stream: &mut BufReader<TcpStream>) -> String {
+        let mut line = String::new();
+        stream.read_line(&mut line).await.unwrap();
+        line.trim_end().to_string()
+    }
+
+    let client = ChatClient::with_address(&address);
+    let mut room = client.join("IronMouse");
+
+    let (mut server, received) = accept(&listener).await;
+    assert_eq!(received[2], "NICK justinfan2434");
+    assert_eq!(read_line(&mut server).await, "JOIN #ironmouse");
+
+    server.write_all(b"PING :tmi.twitch.tv\r\n").await.unwrap();
+    assert_eq!(read_line(&mut server).await, "PONG :tmi.twitch.tv");
+
+    server.write_all(b"@badge-info=;badges=;color=;display-name=abc;emotes=;first-msg=0;flags=;id=ec894756-d624-470f-9c3c-8a86ea3c3e9c;mod=0;returning-chatter=0;room-id=175831187;subscriber=0;tmi-sent-ts=1693940586209;turbo=0;user-id=951421495;user-type= :abc!abc@abc.tmi.twitch.tv PRIVMSG #ironmouse :hello\r\n").await.unwrap();
+    assert!(matches!(
+        room.recv().await,
+        Some(LiveChatMessage::Text { text, .. }) if text == "hello"
+    ));
+
+    // joined rooms are rejoined after reconnecting
+    server
+        .write_all(b":tmi.twitch.tv RECONNECT\r\n")
+        .await
+        .unwrap();
+    let (mut server, _) = accept(&listener).await;
+    assert_eq!(read_line(&mut server).await, "JOIN #ironmouse");
+
+    drop(room);
+    assert_eq!(read_line(&mut server).await, "PART #ironmouse");
+}
+
+#[test]
+fn test_rooms_dispatch() {
+    let mut rooms = Rooms::default();
+    let (tx, mut rx) = channel(1);
+    rooms.apply(Command::Join {
+        login: "ironmouse".into(),
+        tx,
+    });
+
+    let text = |text: &str| LiveChatMessage::Text {
+        author_username: "abc".into(),
+        badges: None,
+        text: text.into(),
+        timestamp: Default::default(),
+        emotes: vec![],
+    };
+
+    // the second message overflows and is dropped
+    assert_eq!(rooms.dispatch("ironmouse", text("a")), None);
+    assert_eq!(rooms.dispatch("ironmouse", text("b")), None);
+    assert!(matches!(rx.try_recv(), Ok(LiveChatMessage::Text { text, .. }) if text == "a"));
+    assert!(rx.try_recv().is_err());
+
+    drop(rx);
+    assert_eq!(
+        rooms.dispatch("ironmouse", text("c")),
+        Some("ironmouse".into())
+    );
+}
@@ -1,5 +1,5 @@
-mod collect;
+mod client;
 mod parse;
 
-pub use collect::*;
+pub use client::*;
 pub use parse::*;
@@ -1,13 +1,17 @@
 use chrono::{DateTime, TimeZone, Utc};
-use twitch_message::messages::Privmsg;
+use twitch_message::messages::{Privmsg, UserNotice};
+use vtstats_database::stream_events::Money;
+use vtstats_utils::currency::CurrencyCode;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum LiveChatMessage {
     Text {
         author_username: String,
         timestamp: DateTime<Utc>,
         text: String,
         badges: Option<String>,
+        /// names of emotes in the text, once per occurrence
+        emotes: Vec<String>,
     },
     Cheering {
         bits: String,
@@ -21,16 +25,41 @@ pub enum LiveChatMessage {
         timestamp: DateTime<Utc>,
         text: String,
         badges: Option<String>,
+        /// names of emotes in the text, once per occurrence
+        emotes: Vec<String>,
     },
     HyperChat {
         author_username: String,
         timestamp: DateTime<Utc>,
         text: String,
         badges: Option<String>,
-        currency_code: String,
-        amount: String,
+        money: Money,
         level: String,
     },
+    /// new subscription or resubscription shared to chat
+    Subscription {
+        author_username: String,
+        timestamp: DateTime<Utc>,
+        text: Option<String>,
+        badges: Option<String>,
+        /// `Prime`, `1000`, `2000` or `3000`
+        plan: String,
+        cumulative_months: String,
+    },
+    /// single gift sub or a gift bomb of `count` subs
+    GiftSubscription {
+        author_username: String,
+        timestamp: DateTime<Utc>,
+        badges: Option<String>,
+        plan: String,
+        count: i64,
+        recipient_username: Option<String>,
+    },
+    Raid {
+        from_username: String,
+        timestamp: DateTime<Utc>,
+        viewer_count: i64,
+    },
 }
 
 pub fn parse_privmsg(msg: Privmsg<'_>) -> Option<LiveChatMessage> {
@@ -54,24 +83,30 @@ pub fn parse_privmsg(msg: Privmsg<'_>) -> Option<LiveChatMessage> {
             timestamp,
             bits: bits.to_string(),
         })
-    } else if let (Some(amount), Some(currency_code), Some(level)) = (
+    } else if let (Some(amount), Some(exponent), Some(currency_code), Some(level)) = (
         msg.tags.get("pinned-chat-paid-amount"),
+        msg.tags.get("pinned-chat-paid-exponent"),
         msg.tags.get("pinned-chat-paid-currency"),
         msg.tags.get("pinned-chat-paid-level"),
     ) {
+        // amount is in minor units, e.g. 200 with exponent 2 means 2.00
         Some(LiveChatMessage::HyperChat {
             author_username,
             badges,
             text,
             timestamp,
-            amount: amount.to_string(),
-            currency_code: currency_code.to_string(),
+            money: Money::from_minor_units(
+                amount.parse().ok()?,
+                exponent.parse().ok()?,
+                CurrencyCode::new(currency_code)?,
+            )?,
             level: level.to_string(),
         })
     } else if matches!(&badges, Some(b) if b.contains("subscriber/")) {
         Some(LiveChatMessage::Subscriber {
             author_username,
             badges,
+            emotes: parse_emotes(msg.tags.get("emotes").unwrap_or_default(), &text),
             text,
             timestamp,
         })
@@ -79,12 +114,91 @@ pub fn parse_privmsg(msg: Privmsg<'_>) -> Option<LiveChatMessage> {
         Some(LiveChatMessage::Text {
             author_username,
             badges,
+            emotes: parse_emotes(msg.tags.get("emotes").unwrap_or_default(), &text),
             text,
             timestamp,
         })
     }
 }
 
+/// extracts emote names from `emotes` tag, e.g. `25:0-4,12-16/1902:6-10`,
+/// positions are inclusive character indices of the text
+fn parse_emotes(tag: &str, text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+
+    tag.split('/')
+        .filter_map(|emote| emote.split_once(':'))
+        .flat_map(|(_, positions)| positions.split(','))
+        .filter_map(|position| {
+            let (start, end) = position.split_once('-')?;
+            let (start, end) = (start.parse::<usize>().ok()?, end.parse::<usize>().ok()?);
+            let name = chars.get(start..=end)?;
+            Some(name.iter().collect())
+        })
+        .collect()
+}
+
+pub fn parse_usernotice(msg: UserNotice<'_>) -> Option<LiveChatMessage> {
+    // https://dev.twitch.tv/docs/irc/tags/#usernotice-tags
+    let badges = msg
+        .tags
+        .get("badge-info")
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string());
+
+    let timestamp = msg.tmi_sent_ts()?;
+    let timestamp = Utc.timestamp_millis_opt(timestamp.parse().ok()?).single()?;
+    let author_username = msg.login()?.to_string();
+    let plan = || msg.tags.get("msg-param-sub-plan").map(|s| s.to_string());
+
+    match msg.tags.get("msg-id")? {
+        "sub" | "resub" => Some(LiveChatMessage::Subscription {
+            author_username,
+            badges,
+            timestamp,
+            text: msg.data.as_ref().map(|s| s.to_string()),
+            plan: plan()?,
+            cumulative_months: msg
+                .tags
+                .get("msg-param-cumulative-months")
+                .unwrap_or("1")
+                .to_string(),
+        }),
+        // subs of a gift bomb are sent one by one after `submysterygift`,
+        // which already counts them
+        "subgift" if msg.tags.get("msg-param-community-gift-id").is_some() => None,
+        "subgift" => Some(LiveChatMessage::GiftSubscription {
+            author_username,
+            badges,
+            timestamp,
+            plan: plan()?,
+            count: 1,
+            recipient_username: msg
+                .tags
+                .get("msg-param-recipient-user-name")
+                .map(|s| s.to_string()),
+        }),
+        "submysterygift" => Some(LiveChatMessage::GiftSubscription {
+            author_username,
+            badges,
+            timestamp,
+            plan: plan()?,
+            count: msg.tags.get("msg-param-mass-gift-count")?.parse().ok()?,
+            recipient_username: None,
+        }),
+        "raid" => Some(LiveChatMessage::Raid {
+            from_username: msg
+                .tags
+                .get("msg-param-login")
+                .unwrap_or(&author_username)
+                .to_string(),
+            timestamp,
+            viewer_count: msg.tags.get("msg-param-viewerCount")?.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
 #[test]
 fn test_parse() {
     use twitch_message::parse;
@@ -99,9 +213,65 @@ fn test_parse() {
 
     let msg = "@badge-info=;badges=;color=;display-name=themightyspoon1;emotes=emotesv2_ad00a34a54084c56ae1698542b5b6f53:6-18/emotesv2_dcd06b30a5c24f6eb871e8f5edbd44f7:20-28;first-msg=0;flags=;id=ec894756-d624-470f-9c3c-8a86ea3c3e9c;mod=0;returning-chatter=0;room-id=175831187;subscriber=0;tmi-sent-ts=1693940586209;turbo=0;user-id=951421495;user-type= :themightyspoon1!themightyspoon1@themightyspoon1.tmi.twitch.tv PRIVMSG #ironmouse :BARRY ironmouseRAVE DinoDance";
     let msg = parse_privmsg(Privmsg::try_from(parse(msg).unwrap().message).unwrap()).unwrap();
-    assert!(matches!(msg, LiveChatMessage::Text { .. }));
+    assert!(matches!(
+        msg,
+        LiveChatMessage::Text { emotes, .. } if emotes == ["ironmouseRAVE", "DinoDance"]
+    ));
 
     let msg = "@badge-info=;badges=glhf-pledge/1;color=;emotes=;first-msg=0;flags=;id=f6fb34f8-562f-4b4d-b628-32113d0ef4b0;mod=0;pinned-chat-paid-amount=200;pinned-chat-paid-canonical-amount=200;pinned-chat-paid-currency=USD;pinned-chat-paid-exponent=2;pinned-chat-paid-is-system-message=0;pinned-chat-paid-level=ONE;returning-chatter=0;room-id=12345678;subscriber=0;tmi-sent-ts=1687471984306;turbo=0;user-id=12345678;user-type= :abc!abc@abc.tmi.twitch.tv PRIVMSG #xyz :HeyGuys";
     let msg = parse_privmsg(Privmsg::try_from(parse(msg).unwrap().message).unwrap()).unwrap();
-    assert!(matches!(msg, LiveChatMessage::HyperChat { .. }));
+    assert!(matches!(
+        msg,
+        LiveChatMessage::HyperChat { money, .. } if money.amount.to_string() == "2.00" && money.currency.as_str() == "USD"
+    ));
+
+    let msg = "@badge-info=subscriber/6;badges=subscriber/6,premium/1;color=#008000;display-name=ronni;emotes=;flags=;id=db25007f-7a18-43eb-9379-80131e44d633;login=ronni;mod=0;msg-id=resub;msg-param-cumulative-months=6;msg-param-months=0;msg-param-multimonth-duration=0;msg-param-multimonth-tenure=0;msg-param-should-share-streak=1;msg-param-streak-months=2;msg-param-sub-plan-name=Prime;msg-param-sub-plan=Prime;msg-param-was-gifted=false;room-id=175831187;subscriber=1;system-msg=ronni\\ssubscribed\\swith\\sPrime.;tmi-sent-ts=1693940572675;user-id=87654321;user-type= :tmi.twitch.tv USERNOTICE #ironmouse :Great stream -- keep it up!";
+    let msg = parse_usernotice(UserNotice::try_from(parse(msg).unwrap().message).unwrap()).unwrap();
+    assert!(matches!(
+        msg,
+        LiveChatMessage::Subscription { ref plan, ref cumulative_months, text: Some(_), .. }
+            if plan == "Prime" && cumulative_months == "6"
+    ));
+
+    let msg = "@badge-info=;badges=premium/1;color=#0000FF;display-name=TWW2;emotes=;flags=;id=e9176cd8-5e22-4684-ad40-ce53c2561c5e;login=tww2;mod=0;msg-id=subgift;msg-param-gift-months=1;msg-param-months=1;msg-param-origin-id=da\\s39\\sa3\\see\\s5e\\s6b\\s4b\\s0d\\s32\\s55\\sbf\\sef\\s95\\s60\\s18\\s90\\saf\\sd8\\s07\\s09;msg-param-recipient-display-name=Mr_Woodchuck;msg-param-recipient-id=55554444;msg-param-recipient-user-name=mr_woodchuck;msg-param-sender-count=0;msg-param-sub-plan-name=Channel\\sSubscription;msg-param-sub-plan=2000;room-id=175831187;subscriber=0;system-msg=TWW2\\sgifted\\sa\\sTier\\s2\\ssub\\sto\\sMr_Woodchuck!;tmi-sent-ts=1693940573153;user-id=87654322;user-type= :tmi.twitch.tv USERNOTICE #ironmouse";
+    let msg = parse_usernotice(UserNotice::try_from(parse(msg).unwrap().message).unwrap()).unwrap();
+    assert!(matches!(
+        msg,
+        LiveChatMessage::GiftSubscription { count: 1, ref plan, recipient_username: Some(_), .. }
+            if plan == "2000"
+    ));
+
+    let msg = "@badge-info=subscriber/3;badges=subscriber/3;color=;display-name=Gifter;emotes=;flags=;id=5b2b4a3b-7cf4-4c2a-9b0d-2c1c6f0d8a11;login=gifter;mod=0;msg-id=submysterygift;msg-param-mass-gift-count=20;msg-param-origin-id=2a\\s1c\\s8e;msg-param-sender-count=120;msg-param-sub-plan=1000;room-id=175831187;subscriber=1;system-msg=Gifter\\sis\\sgifting\\s20\\sTier\\s1\\sSubs!;tmi-sent-ts=1693940574000;user-id=87654323;user-type= :tmi.twitch.tv USERNOTICE #ironmouse";
+    let msg = parse_usernotice(UserNotice::try_from(parse(msg).unwrap().message).unwrap()).unwrap();
+    assert!(matches!(
+        msg,
+        LiveChatMessage::GiftSubscription {
+            count: 20,
+            recipient_username: None,
+            ..
+        }
+    ));
+
+    // sub of the gift bomb above
+    let msg = "@badge-info=subscriber/3;badges=subscriber/3;color=;display-name=Gifter;emotes=;flags=;id=0a7d3c1e-3b44-4bb4-8f8e-1f1d2e3c4b5a;login=gifter;mod=0;msg-id=subgift;msg-param-community-gift-id=7863398480405476000;msg-param-months=1;msg-param-origin-id=2a\\s1c\\s8e;msg-param-recipient-display-name=Someone;msg-param-recipient-id=11112222;msg-param-recipient-user-name=someone;msg-param-sender-count=0;msg-param-sub-plan=1000;room-id=175831187;subscriber=1;tmi-sent-ts=1693940574100;user-id=87654323;user-type= :tmi.twitch.tv USERNOTICE #ironmouse";
+    assert!(parse_usernotice(UserNotice::try_from(parse(msg).unwrap().message).unwrap()).is_none());
+
+    let msg = "@badge-info=;badges=;color=#9ACD32;display-name=TestChannel;emotes=;flags=;id=3d830f12-795c-447d-af3c-ea05e40fbddb;login=testchannel;mod=0;msg-id=raid;msg-param-displayName=TestChannel;msg-param-login=testchannel;msg-param-profileImageURL=https://static-cdn.jtvnw.net/jtv_user_pictures/testchannel-profile_image-70x70.png;msg-param-viewerCount=1500;room-id=175831187;subscriber=0;system-msg=1500\\sraiders\\sfrom\\sTestChannel\\shave\\sjoined!;tmi-sent-ts=1693940575000;user-id=123456;user-type= :tmi.twitch.tv USERNOTICE #ironmouse";
+    let msg = parse_usernotice(UserNotice::try_from(parse(msg).unwrap().message).unwrap()).unwrap();
+    assert!(matches!(
+        msg,
+        LiveChatMessage::Raid { viewer_count: 1500, ref from_username, .. }
+            if from_username == "testchannel"
+    ));
+}
+
+#[test]
+fn test_parse_emotes() {
+    assert_eq!(
+        parse_emotes("25:0-4,12-16/1902:6-10", "Kappa Keepo Kappa"),
+        ["Kappa", "Kappa", "Keepo"]
+    );
+    assert_eq!(parse_emotes("25:2-6", "草 Kappa"), ["Kappa"]);
+    assert_eq!(parse_emotes("25:0-40", "Kappa"), Vec::<String>::new());
+    assert_eq!(parse_emotes("", "Kappa"), Vec::<String>::new());
 }
@@ -0,0 +1,182 @@
+//! https://dev.twitch.tv/docs/eventsub/handling-websocket-events/
+
+use std::time::Duration;
+
+use anyhow::Result;
+use futures_util::StreamExt;
+use serde::Deserialize;
+use serde_json::value::RawValue;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use crate::subscription::{Event, Revocation};
+
+pub const EVENTSUB_WEBSOCKET_URL: &str = "wss://eventsub.wss.twitch.tv/ws";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Session {
+    pub id: String,
+    pub status: String,
+    #[serde(default)]
+    pub keepalive_timeout_seconds: Option<u64>,
+    #[serde(default)]
+    pub reconnect_url: Option<String>,
+}
+
+pub enum WebSocketMessage {
+    Welcome(Session),
+    Keepalive,
+    Notification(Event),
+    /// client should connect to `reconnect_url` of the session,
+    /// subscriptions will be carried over to the new connection
+    Reconnect(Session),
+    Revocation(Revocation),
+}
+
+impl WebSocketMessage {
+    /// returns `None` for message types unknown to us, as twitch
+    /// may add new ones
+    pub fn parse(text: &str) -> Result<Option<Self>> {
+        #[derive(Deserialize)]
+        struct Metadata<'a> {
+            #[serde(borrow)]
+            message_type: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct SessionPayload {
+            session: Session,
+        }
+
+        #[derive(Deserialize)]
+        struct RawMessage<'a> {
+            #[serde(borrow)]
+            metadata: Metadata<'a>,
+            #[serde(borrow)]
+            payload: &'a RawValue,
+        }
+
+        let message: RawMessage = serde_json::from_str(text)?;
+        let payload = message.payload.get();
+
+        Ok(Some(match message.metadata.message_type {
+            "session_welcome" => {
+                WebSocketMessage::Welcome(serde_json::from_str::<SessionPayload>(payload)?.session)
+            }
+            "session_keepalive" => WebSocketMessage::Keepalive,
+            "notification" => WebSocketMessage::Notification(serde_json::from_str(payload)?),
+            "session_reconnect" => WebSocketMessage::Reconnect(
+                serde_json::from_str::<SessionPayload>(payload)?.session,
+            ),
+            "revocation" => WebSocketMessage::Revocation(serde_json::from_str(payload)?),
+            ty => {
+                tracing::warn!("Skipping unknown eventsub message type {ty}");
+                return Ok(None);
+            }
+        }))
+    }
+}
+
+pub struct EventSubWebSocket {
+    ws: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    keepalive: Duration,
+}
+
+impl EventSubWebSocket {
+    /// connects to given url, and waits for the welcome message
+    pub async fn connect(url: &str) -> Result<(Self, Session)> {
+        let (ws, _) = connect_async(url).await?;
+
+        let mut ws = EventSubWebSocket {
+            ws,
+            keepalive: Duration::from_secs(10),
+        };
+
+        match ws.next().await? {
+            Some(WebSocketMessage::Welcome(session)) => {
+                if let Some(secs) = session.keepalive_timeout_seconds {
+                    ws.keepalive = Duration::from_secs(secs);
+                }
+                Ok((ws, session))
+            }
+            _ => anyhow::bail!("Expect welcome message from eventsub websocket"),
+        }
+    }
+
+    /// returns next message, or `None` if connection was closed
+    /// or no message was received within keepalive timeout
+    pub async fn next(&mut self) -> Result<Option<WebSocketMessage>> {
+        loop {
+            // twitch sends keepalive message when there's no event,
+            // gives a few more seconds for network latency
+            let timeout = self.keepalive + Duration::from_secs(5);
+
+            let Ok(frame) = tokio::time::timeout(timeout, self.ws.next()).await else {
+                tracing::warn!("No message from eventsub websocket in {timeout:?}");
+                return Ok(None);
+            };
+
+            match frame.transpose()? {
+                Some(Message::Text(text)) => match WebSocketMessage::parse(&text)? {
+                    Some(message) => return Ok(Some(message)),
+                    None => continue,
+                },
+                Some(Message::Close(_)) | None => return Ok(None),
+                Some(_) => continue,
+            }
+        }
+    }
+}
+
+#[test]
+fn de() {
+    let Ok(Some(WebSocketMessage::Welcome(session))) =
+        WebSocketMessage::parse(include_str!("./testdata/eventsub_welcome.json"))
+    else {
+        panic!("expect welcome message");
+    };
+    assert_eq!(session.id, "AQoQILE98gtqShGmLD7AM6yJThAB");
+    assert_eq!(session.keepalive_timeout_seconds, Some(10));
+
+    assert!(matches!(
+        WebSocketMessage::parse(include_str!("./testdata/eventsub_keepalive.json")),
+        Ok(Some(WebSocketMessage::Keepalive))
+    ));
+
+    assert!(matches!(
+        WebSocketMessage::parse(r#"{"metadata":{"message_type":"session_paused"},"payload":{}}"#),
+        Ok(None)
+    ));
+
+    let Ok(Some(WebSocketMessage::Notification(Event::StreamOnlineEvent(event)))) =
+        WebSocketMessage::parse(include_str!("./testdata/eventsub_notification.json"))
+    else {
+        panic!("expect stream.online notification");
+    };
+    assert_eq!(event.broadcaster_user_login, "twitch");
+
+    let Ok(Some(WebSocketMessage::Reconnect(session))) =
+        WebSocketMessage::parse(include_str!("./testdata/eventsub_reconnect.json"))
+    else {
+        panic!("expect reconnect message");
+    };
+    assert_eq!(
+        session.reconnect_url.as_deref(),
+        Some("wss://eventsub.wss.twitch.tv?...")
+    );
+
+    let Ok(Some(WebSocketMessage::Revocation(revocation))) =
+        WebSocketMessage::parse(include_str!("./testdata/eventsub_revocation.json"))
+    else {
+        panic!("expect revocation message");
+    };
+    assert_eq!(
+        revocation
+            .subscription
+            .subscription
+            .transport()
+            .session_id
+            .as_deref(),
+        Some("AQoQexAWVYKSTIu4ec_2VAxyuhAB")
+    );
+}
@@ -63,10 +63,24 @@ pub struct Stream {
     #[serde(rename = "type")]
     pub type_field: String,
     pub created_at: String,
+    #[serde(default)]
+    pub game: Option<Game>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Game {
+    pub id: String,
+    pub name: String,
 }
 
 #[test]
 fn de() {
     serde_json::from_str::<Response>(include_str!("./testdata/stream_metadata.0.json")).unwrap();
-    serde_json::from_str::<Response>(include_str!("./testdata/stream_metadata.1.json")).unwrap();
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/stream_metadata.1.json"))
+        .unwrap();
+    assert_eq!(
+        res.data.user.stream.and_then(|s| s.game).map(|g| g.name),
+        Some("VALORANT".into())
+    );
 }
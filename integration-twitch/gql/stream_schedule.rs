@@ -58,7 +58,10 @@ pub struct Data {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct User {
+    #[serde(default)]
     pub videos: Videos,
+    #[serde(default)]
+    pub channel: Channel,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -81,7 +84,74 @@ pub struct Node {
     pub created_at: String,
 }
 
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Channel {
+    pub schedule: Option<Schedule>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schedule {
+    #[serde(default)]
+    pub segments: Vec<Segment>,
+    /// vacation set by broadcaster
+    pub interruption: Option<Interruption>,
+}
+
+impl Schedule {
+    /// segments which are neither cancelled nor during vacation
+    pub fn active_segments(&self) -> impl Iterator<Item = &Segment> {
+        self.segments.iter().filter(|segment| {
+            segment.canceled_until.is_none()
+                && !matches!(
+                    &self.interruption,
+                    Some(i) if i.start_at <= segment.start_at && segment.start_at < i.end_at
+                )
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Segment {
+    pub id: String,
+    pub start_at: DateTime<Utc>,
+    pub end_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub title: String,
+    pub canceled_until: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub categories: Vec<Category>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Category {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Interruption {
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+}
+
 #[test]
 fn de() {
-    serde_json::from_str::<Response>(include_str!("./testdata/stream_schedule.json")).unwrap();
+    let res =
+        serde_json::from_str::<Response>(include_str!("./testdata/stream_schedule.json")).unwrap();
+    assert!(res.data.user.channel.schedule.is_none());
+
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/stream_schedule.1.json"))
+        .unwrap();
+    let schedule = res.data.user.channel.schedule.unwrap();
+    assert_eq!(schedule.segments.len(), 4);
+
+    let segments: Vec<_> = schedule.active_segments().collect();
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].title, "Minecraft with friends");
+    assert_eq!(segments[0].categories[0].name, "Minecraft");
 }
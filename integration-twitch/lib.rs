@@ -1,5 +1,6 @@
 mod access_token;
 mod chat;
+mod eventsub_websocket;
 pub mod gql;
 mod subscribe;
 mod subscription;
@@ -8,6 +9,7 @@ mod videos;
 
 pub use access_token::*;
 pub use chat::*;
+pub use eventsub_websocket::*;
 pub use subscribe::*;
 pub use subscription::*;
 pub use validate::*;
@@ -12,6 +12,18 @@ pub struct ListSubscriptionResponse {
     pub data: Vec<CreatedSubscription>,
     #[serde(default)]
     pub pagination: Pagination,
+    #[serde(default)]
+    pub total_cost: i32,
+    #[serde(default)]
+    pub max_total_cost: i32,
+}
+
+/// created subscription, along with the cost of all subscriptions
+/// after creating it
+pub struct CreateSubscriptionResponse {
+    pub id: String,
+    pub total_cost: i32,
+    pub max_total_cost: i32,
 }
 
 #[derive(Deserialize, Default)]
@@ -47,13 +59,14 @@ pub async fn list_subscriptions(
 
 pub async fn create_channel_update_subscription(
     broadcaster_user_id: String,
+    transport: Transport,
     token: &str,
     client: &Client,
-) -> Result<String> {
+) -> Result<CreateSubscriptionResponse> {
     create_subscription(
         Subscription::ChannelUpdate {
             version: "2".into(),
-            transport: transport()?,
+            transport,
             condition: Condition {
                 broadcaster_user_id,
             },
@@ -66,13 +79,14 @@ pub async fn create_channel_update_subscription(
 
 pub async fn create_stream_offline_subscription(
     broadcaster_user_id: String,
+    transport: Transport,
     token: &str,
     client: &Client,
-) -> Result<String> {
+) -> Result<CreateSubscriptionResponse> {
     create_subscription(
         Subscription::StreamOffline {
             version: "1".into(),
-            transport: transport()?,
+            transport,
             condition: Condition {
                 broadcaster_user_id,
             },
@@ -85,13 +99,14 @@ pub async fn create_stream_offline_subscription(
 
 pub async fn create_stream_online_subscription(
     broadcaster_user_id: String,
+    transport: Transport,
     token: &str,
     client: &Client,
-) -> Result<String> {
+) -> Result<CreateSubscriptionResponse> {
     create_subscription(
         Subscription::StreamOnline {
             version: "1".into(),
-            transport: transport()?,
+            transport,
             condition: Condition {
                 broadcaster_user_id,
             },
@@ -102,19 +117,53 @@ pub async fn create_stream_online_subscription(
     .await
 }
 
-fn transport() -> anyhow::Result<Transport> {
+pub async fn delete_subscription(id: &str, token: &str, client: &Client) -> Result<()> {
+    let url = Url::parse_with_params(
+        "https://api.twitch.tv/helix/eventsub/subscriptions",
+        &[("id", id)],
+    )?;
+
+    let req = client
+        .delete(url)
+        .header(AUTHORIZATION, format!("Bearer {token}"))
+        .header("Client-Id", env::var("TWITCH_CLIENT_ID")?);
+
+    send_request!(req)?;
+
+    Ok(())
+}
+
+/// url which twitch sends webhook notifications to
+pub fn callback_url() -> Result<String> {
+    Ok(format!(
+        "https://{}/api/twitch",
+        env::var("SERVER_HOSTNAME")?
+    ))
+}
+
+pub fn webhook_transport() -> Result<Transport> {
     Ok(Transport {
         method: "webhook".into(),
-        callback: format!("https://{}/api/twitch", env::var("SERVER_HOSTNAME")?),
+        callback: callback_url()?,
         secret: Some(env::var("TWITCH_WEBHOOK_SECRET")?),
+        session_id: None,
     })
 }
 
+/// subscriptions with websocket transport must be created with user access token
+pub fn websocket_transport(session_id: String) -> Transport {
+    Transport {
+        method: "websocket".into(),
+        session_id: Some(session_id),
+        ..Default::default()
+    }
+}
+
 async fn create_subscription(
     subscription: Subscription,
     token: &str,
     client: &Client,
-) -> Result<String> {
+) -> Result<CreateSubscriptionResponse> {
     let req = client
         .post("https://api.twitch.tv/helix/eventsub/subscriptions")
         .header(AUTHORIZATION, format!("Bearer {token}"))
@@ -126,7 +175,11 @@ async fn create_subscription(
     let mut list: ListSubscriptionResponse = res.json().await?;
 
     if let Some(s) = list.data.pop() {
-        Ok(s.id)
+        Ok(CreateSubscriptionResponse {
+            id: s.id,
+            total_cost: list.total_cost,
+            max_total_cost: list.max_total_cost,
+        })
     } else {
         anyhow::bail!("twitch responses nothing");
     }
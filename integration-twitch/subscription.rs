@@ -39,6 +39,30 @@ pub enum Subscription {
     },
 }
 
+impl Subscription {
+    pub fn condition(&self) -> &Condition {
+        match self {
+            Subscription::StreamOnline { condition, .. }
+            | Subscription::StreamOffline { condition, .. }
+            | Subscription::ChannelUpdate { condition, .. } => condition,
+        }
+    }
+
+    pub fn transport(&self) -> &Transport {
+        match self {
+            Subscription::StreamOnline { transport, .. }
+            | Subscription::StreamOffline { transport, .. }
+            | Subscription::ChannelUpdate { transport, .. } => transport,
+        }
+    }
+}
+
+/// https://dev.twitch.tv/docs/eventsub/handling-webhook-events/#revoking-your-subscription
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Revocation {
+    pub subscription: CreatedSubscription,
+}
+
 /// https://dev.twitch.tv/docs/eventsub/eventsub-reference/#stream-online-condition
 /// https://dev.twitch.tv/docs/eventsub/eventsub-reference/#stream-offline-condition
 /// https://dev.twitch.tv/docs/eventsub/eventsub-reference/#channel-upate-condition
@@ -47,12 +71,20 @@ pub struct Condition {
     pub broadcaster_user_id: String,
 }
 
+/// https://dev.twitch.tv/docs/eventsub/eventsub-reference/#transport
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Transport {
+    /// either `webhook` or `websocket`
     pub method: String,
+    /// only used by webhook transport
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub callback: String,
-    #[serde(default)]
+    /// only used by webhook transport
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub secret: Option<String>,
+    /// only used by websocket transport
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -110,6 +142,51 @@ pub enum Event {
     StreamOfflineEvent(StreamOfflineEvent),
 }
 
+/// serializes in the shape of notification body, so it can be
+/// deserialized again
+impl Serialize for Event {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct RawEventSubscription<'a> {
+            #[serde(rename = "type")]
+            ty: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct RawEvent<'a, T> {
+            event: &'a T,
+            subscription: RawEventSubscription<'a>,
+        }
+
+        match self {
+            Event::StreamOnlineEvent(event) => RawEvent {
+                event,
+                subscription: RawEventSubscription {
+                    ty: "stream.online",
+                },
+            }
+            .serialize(serializer),
+            Event::StreamOfflineEvent(event) => RawEvent {
+                event,
+                subscription: RawEventSubscription {
+                    ty: "stream.offline",
+                },
+            }
+            .serialize(serializer),
+            Event::ChannelUpdateEvent(event) => RawEvent {
+                event,
+                subscription: RawEventSubscription {
+                    ty: "channel.update",
+                },
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Event {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -151,7 +228,7 @@ impl<'de> Deserialize<'de> for Event {
 fn de() {
     use serde_json::from_str;
 
-    from_str::<Event>(
+    let event = from_str::<Event>(
         r#"
         {
             "subscription": {
@@ -182,6 +259,14 @@ fn de() {
     )
     .unwrap();
 
+    // round trips through job payload
+    let Event::StreamOnlineEvent(event) =
+        from_str::<Event>(&serde_json::to_string(&event).unwrap()).unwrap()
+    else {
+        panic!("expect stream.online event");
+    };
+    assert_eq!(event.id, "9001");
+
     from_str::<Event>(
         r#"
         {
@@ -295,4 +380,36 @@ fn de() {
     "#,
     )
     .unwrap();
+
+    let revocation = from_str::<Revocation>(
+        r#"
+        {
+            "subscription": {
+                "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+                "status": "authorization_revoked",
+                "type": "stream.online",
+                "cost": 1,
+                "version": "1",
+                "condition": {
+                    "broadcaster_user_id": "12826"
+                },
+                "transport": {
+                    "method": "webhook",
+                    "callback": "https://example.com/webhooks/callback"
+                },
+                "created_at": "2019-11-16T10:11:12.634234626Z"
+            }
+        }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(revocation.subscription.status, "authorization_revoked");
+    assert_eq!(
+        revocation
+            .subscription
+            .subscription
+            .condition()
+            .broadcaster_user_id,
+        "12826"
+    );
 }
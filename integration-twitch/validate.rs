@@ -13,7 +13,7 @@ use serde::Deserialize;
 use sha2::Sha256;
 use std::env;
 
-use crate::subscription::{Event, Subscription};
+use crate::subscription::{Event, Revocation};
 
 #[derive(Deserialize, Debug)]
 pub struct VerificationChallenge {
@@ -23,7 +23,7 @@ pub struct VerificationChallenge {
 pub enum Notification {
     Event(Event),
     Verification(VerificationChallenge),
-    Revocation(Subscription),
+    Revocation(Revocation),
 }
 
 #[async_trait]
@@ -12,7 +12,7 @@ use proto::{get_continuation, get_replay_continuation};
 use request::Request;
 use response::*;
 
-pub use response::{LiveChatMessage, MemberMessageType, PaidMessageType};
+pub use response::{LiveChatMessage, MemberMessageType, PaidMessageType, PollChoiceResult};
 
 use self::request::CurrentPlayerState;
 
@@ -106,21 +106,21 @@ pub struct LiveChatAction {
     #[serde(default)]
     pub add_live_chat_ticker_item_action: IgnoredAny,
     #[serde(default)]
-    pub mark_chat_item_as_deleted_action: IgnoredAny,
+    pub mark_chat_item_as_deleted_action: Option<MarkChatItemAsDeletedAction>,
     #[serde(default)]
-    pub mark_chat_items_by_author_as_deleted_action: IgnoredAny,
+    pub mark_chat_items_by_author_as_deleted_action: Option<MarkChatItemsByAuthorAsDeletedAction>,
     #[serde(default)]
     pub show_live_chat_tooltip_command: IgnoredAny,
     #[serde(default)]
-    pub add_banner_to_live_chat_command: IgnoredAny,
+    pub add_banner_to_live_chat_command: Option<AddBannerToLiveChatCommand>,
     #[serde(default)]
     pub remove_banner_for_live_chat_command: IgnoredAny,
     #[serde(default)]
-    pub show_live_chat_action_panel_action: IgnoredAny,
+    pub show_live_chat_action_panel_action: Option<ShowLiveChatActionPanelAction>,
     #[serde(default)]
     pub close_live_chat_action_panel_action: IgnoredAny,
     #[serde(default)]
-    pub update_live_chat_poll_action: IgnoredAny,
+    pub update_live_chat_poll_action: Option<UpdateLiveChatPollAction>,
     #[serde(default)]
     pub replace_chat_item_action: IgnoredAny,
     #[serde(default)]
@@ -167,10 +167,14 @@ pub struct ChatItem {
     pub live_chat_placeholder_item_renderer: IgnoredAny,
     #[serde(default)]
     pub live_chat_mode_change_message_renderer: IgnoredAny,
+    // gifted membership received
     #[serde(default)]
-    pub live_chat_sponsorships_gift_redemption_announcement_renderer: IgnoredAny,
+    pub live_chat_sponsorships_gift_redemption_announcement_renderer:
+        Option<LiveChatSponsorshipsGiftRedemptionAnnouncementRenderer>,
+    // gifted memberships purchased
     #[serde(default)]
-    pub live_chat_sponsorships_gift_purchase_announcement_renderer: IgnoredAny,
+    pub live_chat_sponsorships_gift_purchase_announcement_renderer:
+        Option<LiveChatSponsorshipsGiftPurchaseAnnouncementRenderer>,
 
     #[serde(flatten)]
     pub unknown: HashMap<String, IgnoredAny>,
@@ -276,6 +280,167 @@ pub struct LiveChatMembershipItemRenderer {
     pub author_badges: Vec<AuthorBadge>,
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatSponsorshipsGiftPurchaseAnnouncementRenderer {
+    pub author_external_channel_id: String,
+    pub timestamp_usec: String,
+    pub header: LiveChatSponsorshipsHeader,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatSponsorshipsHeader {
+    pub live_chat_sponsorships_header_renderer: LiveChatSponsorshipsHeaderRenderer,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatSponsorshipsHeaderRenderer {
+    #[serde(default)]
+    pub author_name: AuthorName,
+    // e.g. "Sent 5 Channel gift memberships"
+    pub primary_text: Option<Message>,
+    #[serde(default)]
+    pub author_badges: Vec<AuthorBadge>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatSponsorshipsGiftRedemptionAnnouncementRenderer {
+    #[serde(default)]
+    pub author_name: AuthorName,
+    pub author_external_channel_id: String,
+    pub timestamp_usec: String,
+    pub message: Option<Message>,
+    #[serde(default)]
+    pub author_badges: Vec<AuthorBadge>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ShowLiveChatActionPanelAction {
+    pub panel_to_show: PanelToShow,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PanelToShow {
+    pub live_chat_action_panel_renderer: Option<LiveChatActionPanelRenderer>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatActionPanelRenderer {
+    pub contents: LiveChatActionPanelContents,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatActionPanelContents {
+    pub poll_renderer: Option<PollRenderer>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLiveChatPollAction {
+    pub poll_to_update: PollToUpdate,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PollToUpdate {
+    pub poll_renderer: PollRenderer,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PollRenderer {
+    pub live_chat_poll_id: String,
+    pub header: PollHeader,
+    #[serde(default)]
+    pub choices: Vec<PollChoice>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PollHeader {
+    pub poll_header_renderer: PollHeaderRenderer,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PollHeaderRenderer {
+    pub poll_question: Option<Message>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PollChoice {
+    pub text: Option<Message>,
+    // only available after the poll has been voted
+    pub vote_ratio: Option<f64>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AddBannerToLiveChatCommand {
+    pub banner_renderer: BannerRenderer,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BannerRenderer {
+    pub live_chat_banner_renderer: LiveChatBannerRenderer,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatBannerRenderer {
+    pub action_id: String,
+    #[serde(default)]
+    pub banner_type: String,
+    pub header: Option<LiveChatBannerHeader>,
+    pub contents: Option<LiveChatBannerContents>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatBannerHeader {
+    pub live_chat_banner_header_renderer: LiveChatBannerHeaderRenderer,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatBannerHeaderRenderer {
+    pub text: Option<Message>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatBannerContents {
+    // pinned message
+    pub live_chat_text_message_renderer: Option<LiveChatBannerMessageRenderer>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveChatBannerMessageRenderer {
+    pub message: Option<Message>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkChatItemAsDeletedAction {
+    pub target_item_id: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkChatItemsByAuthorAsDeletedAction {
+    pub external_channel_id: String,
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Message {
@@ -347,6 +512,8 @@ pub enum LiveChatMessage {
         timestamp: String,
         text: String,
         badges: Vec<String>,
+        /// shortcuts of custom emojis in the text, once per occurrence
+        emotes: Vec<String>,
     },
     Member {
         ty: MemberMessageType,
@@ -366,6 +533,42 @@ pub enum LiveChatMessage {
         text: String,
         color: String,
     },
+    GiftPurchase {
+        author_name: String,
+        author_channel_id: String,
+        timestamp: String,
+        badges: Vec<String>,
+        count: i64,
+    },
+    GiftRedemption {
+        author_name: String,
+        author_channel_id: String,
+        timestamp: String,
+        badges: Vec<String>,
+        text: String,
+    },
+    /// sent when a poll starts and each time its result updates
+    Poll {
+        poll_id: String,
+        question: String,
+        choices: Vec<PollChoiceResult>,
+    },
+    Banner {
+        action_id: String,
+        banner_type: String,
+        header: String,
+        text: String,
+    },
+    /// message retracted by its author or removed by a moderator
+    Deleted { target_item_id: String },
+    /// all messages of given author are removed, usually after a ban or timeout
+    AuthorDeleted { author_channel_id: String },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PollChoiceResult {
+    pub text: String,
+    pub vote_ratio: Option<f64>,
 }
 
 fn concat_message(message: Option<Message>) -> String {
@@ -392,6 +595,45 @@ fn concat_message(message: Option<Message>) -> String {
     }
 }
 
+/// shortcuts of custom emojis, as they appear in `concat_message`
+fn custom_emojis(message: Option<&Message>) -> Vec<String> {
+    message
+        .into_iter()
+        .flat_map(|m| &m.runs)
+        .filter_map(|run| run.emoji.as_ref())
+        .filter(|emoji| emoji.is_custom_emoji)
+        .filter_map(|emoji| emoji.shortcuts.last().cloned())
+        .collect()
+}
+
+// first number in the text, or 1 for "Sent a gift membership"
+fn parse_gift_count(message: Option<Message>) -> i64 {
+    message
+        .into_iter()
+        .flat_map(|m| m.runs)
+        .filter_map(|run| run.text)
+        .find_map(|text| {
+            text.split_whitespace()
+                .find_map(|word| word.replace(',', "").parse().ok())
+        })
+        .unwrap_or(1)
+}
+
+fn poll_message(poll: PollRenderer) -> LiveChatMessage {
+    LiveChatMessage::Poll {
+        poll_id: poll.live_chat_poll_id,
+        question: concat_message(poll.header.poll_header_renderer.poll_question),
+        choices: poll
+            .choices
+            .into_iter()
+            .map(|choice| PollChoiceResult {
+                text: concat_message(choice.text),
+                vote_ratio: choice.vote_ratio,
+            })
+            .collect(),
+    }
+}
+
 fn flatten_badges(badges: Vec<AuthorBadge>) -> Vec<String> {
     badges
         .into_iter()
@@ -432,10 +674,57 @@ impl LiveChatMessage {
         let LiveChatAction {
             add_chat_item_action,
             replay_chat_item_action,
+            mark_chat_item_as_deleted_action,
+            mark_chat_items_by_author_as_deleted_action,
+            add_banner_to_live_chat_command,
+            show_live_chat_action_panel_action,
+            update_live_chat_poll_action,
             unknown,
             ..
         } = action;
 
+        if let Some(action) = mark_chat_item_as_deleted_action {
+            messages.push(LiveChatMessage::Deleted {
+                target_item_id: action.target_item_id,
+            });
+        }
+
+        if let Some(action) = mark_chat_items_by_author_as_deleted_action {
+            messages.push(LiveChatMessage::AuthorDeleted {
+                author_channel_id: action.external_channel_id,
+            });
+        }
+
+        if let Some(command) = add_banner_to_live_chat_command {
+            let banner = command.banner_renderer.live_chat_banner_renderer;
+            messages.push(LiveChatMessage::Banner {
+                action_id: banner.action_id,
+                banner_type: banner.banner_type,
+                header: concat_message(
+                    banner
+                        .header
+                        .and_then(|h| h.live_chat_banner_header_renderer.text),
+                ),
+                text: concat_message(
+                    banner
+                        .contents
+                        .and_then(|c| c.live_chat_text_message_renderer)
+                        .and_then(|r| r.message),
+                ),
+            });
+        }
+
+        if let Some(poll) = show_live_chat_action_panel_action
+            .and_then(|action| action.panel_to_show.live_chat_action_panel_renderer)
+            .and_then(|panel| panel.contents.poll_renderer)
+        {
+            messages.push(poll_message(poll));
+        }
+
+        if let Some(action) = update_live_chat_poll_action {
+            messages.push(poll_message(action.poll_to_update.poll_renderer));
+        }
+
         if let Some(replay_chat_item_action) = replay_chat_item_action {
             for action in replay_chat_item_action.actions {
                 LiveChatMessage::from_action(action, messages);
@@ -450,6 +739,8 @@ impl LiveChatMessage {
                         live_chat_paid_message_renderer,
                         live_chat_membership_item_renderer,
                         live_chat_paid_sticker_renderer,
+                        live_chat_sponsorships_gift_purchase_announcement_renderer,
+                        live_chat_sponsorships_gift_redemption_announcement_renderer,
                         unknown,
                         ..
                     },
@@ -461,6 +752,7 @@ impl LiveChatMessage {
                     author_channel_id: msg.author_external_channel_id,
                     timestamp: msg.timestamp_usec,
                     badges: flatten_badges(msg.author_badges),
+                    emotes: custom_emojis(msg.message.as_ref()),
                     text: concat_message(msg.message),
                 });
             }
@@ -517,6 +809,27 @@ impl LiveChatMessage {
                 });
             }
 
+            if let Some(msg) = live_chat_sponsorships_gift_purchase_announcement_renderer {
+                let header = msg.header.live_chat_sponsorships_header_renderer;
+                messages.push(LiveChatMessage::GiftPurchase {
+                    author_name: header.author_name.simple_text,
+                    author_channel_id: msg.author_external_channel_id,
+                    timestamp: msg.timestamp_usec,
+                    badges: flatten_badges(header.author_badges),
+                    count: parse_gift_count(header.primary_text),
+                });
+            }
+
+            if let Some(msg) = live_chat_sponsorships_gift_redemption_announcement_renderer {
+                messages.push(LiveChatMessage::GiftRedemption {
+                    author_name: msg.author_name.simple_text,
+                    author_channel_id: msg.author_external_channel_id,
+                    timestamp: msg.timestamp_usec,
+                    badges: flatten_badges(msg.author_badges),
+                    text: concat_message(msg.message),
+                });
+            }
+
             let mut keys: Vec<_> = unknown.keys().collect();
             keys.dedup();
             for key in keys {
@@ -551,7 +864,80 @@ fn de() {
         include_str!("./testdata/replay0.json"),
         include_str!("./testdata/replay1.json"),
         include_str!("./testdata/replay2.json"),
+        include_str!("./testdata/gift.json"),
+        include_str!("./testdata/poll.json"),
+        include_str!("./testdata/moderation.json"),
     ] {
         LiveChatMessage::from_response(from_str(json).unwrap());
     }
 }
+
+#[test]
+fn renderers() {
+    use serde_json::from_str;
+
+    let messages =
+        LiveChatMessage::from_response(from_str(include_str!("./testdata/timed1.json")).unwrap());
+    let emotes: Vec<_> = messages
+        .iter()
+        .flat_map(|message| match message {
+            LiveChatMessage::Text { emotes, .. } => emotes.as_slice(),
+            _ => &[],
+        })
+        .collect();
+    assert_eq!(emotes, [":トワ様いえーい:", ":トワ様キラキラ:"]);
+
+    let messages =
+        LiveChatMessage::from_response(from_str(include_str!("./testdata/gift.json")).unwrap());
+    assert_eq!(messages.len(), 3);
+    assert!(matches!(
+        &messages[0],
+        LiveChatMessage::GiftPurchase { count: 20, author_name, badges, .. }
+            if author_name == "@gifter" && badges == &["Member (6 months)"]
+    ));
+    assert!(matches!(
+        &messages[1],
+        LiveChatMessage::GiftPurchase { count: 1, .. }
+    ));
+    assert!(matches!(
+        &messages[2],
+        LiveChatMessage::GiftRedemption { author_name, text, .. }
+            if author_name == "@receiver" && text == "received a gift membership by @gifter"
+    ));
+
+    let messages =
+        LiveChatMessage::from_response(from_str(include_str!("./testdata/poll.json")).unwrap());
+    assert_eq!(messages.len(), 2);
+    assert!(matches!(
+        &messages[0],
+        LiveChatMessage::Poll { question, choices, .. }
+            if question == "What should we play next?"
+                && choices.len() == 2
+                && choices[0].vote_ratio.is_none()
+    ));
+    assert!(matches!(
+        &messages[1],
+        LiveChatMessage::Poll { choices, .. }
+            if choices[0] == PollChoiceResult { text: "Minecraft".into(), vote_ratio: Some(0.75) }
+    ));
+
+    let messages = LiveChatMessage::from_response(
+        from_str(include_str!("./testdata/moderation.json")).unwrap(),
+    );
+    assert_eq!(messages.len(), 3);
+    assert!(matches!(
+        &messages[0],
+        LiveChatMessage::Banner { banner_type, header, text, .. }
+            if banner_type == "LIVE_CHAT_BANNER_TYPE_PINNED_MESSAGE"
+                && header == "Pinned by Channel"
+                && text == "Please be nice in chat!"
+    ));
+    assert!(matches!(
+        &messages[1],
+        LiveChatMessage::Deleted { target_item_id } if target_item_id == "id"
+    ));
+    assert!(matches!(
+        &messages[2],
+        LiveChatMessage::AuthorDeleted { author_channel_id } if author_channel_id == "channelId"
+    ));
+}
@@ -3,10 +3,12 @@ pub mod browse_live;
 pub mod context;
 pub mod live_chat;
 pub mod metadata;
+pub mod next;
 pub mod player;
 
 pub use browse_channel::*;
 pub use browse_live::*;
 pub use live_chat::*;
 pub use metadata::*;
+pub use next::next;
 pub use player::*;
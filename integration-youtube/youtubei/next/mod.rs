@@ -0,0 +1,32 @@
+mod request;
+mod response;
+
+use reqwest::{Client, Url};
+use std::env;
+use vtstats_utils::send_request;
+
+use crate::youtubei::context::Context;
+use request::Request;
+
+pub use response::{Game, Response};
+
+pub async fn next(video_id: &str, client: &Client) -> anyhow::Result<Response> {
+    let url = Url::parse_with_params(
+        "https://www.youtube.com/youtubei/v1/next",
+        &[
+            ("prettyPrint", "false"),
+            ("key", &env::var("INNERTUBE_API_KEY")?),
+        ],
+    )?;
+
+    let req = client.post(url).json(&Request {
+        context: Context::new()?,
+        video_id,
+    });
+
+    let res = send_request!(req)?;
+
+    let json = res.json::<Response>().await?;
+
+    Ok(json)
+}
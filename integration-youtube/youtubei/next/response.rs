@@ -0,0 +1,157 @@
+use serde::Deserialize;
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    #[serde(default)]
+    pub contents: Option<Contents>,
+}
+
+/// game shown below the video, `id` is the game's topic channel id
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: String,
+    pub name: String,
+}
+
+impl Response {
+    /// game played in this video, from the box art row of its description
+    pub fn game(&self) -> Option<Game> {
+        self.contents
+            .as_ref()?
+            .two_column_watch_next_results
+            .results
+            .results
+            .contents
+            .iter()
+            .filter_map(|c| c.video_secondary_info_renderer.as_ref())
+            .filter_map(|r| r.metadata_row_container.as_ref())
+            .flat_map(|c| &c.metadata_row_container_renderer.rows)
+            .filter_map(|r| r.rich_metadata_row_renderer.as_ref())
+            .flat_map(|r| &r.contents)
+            .filter_map(|c| c.rich_metadata_renderer.as_ref())
+            .find(|r| r.style == "RICH_METADATA_RENDERER_STYLE_BOX_ART")
+            .and_then(|r| {
+                Some(Game {
+                    id: r.endpoint.as_ref()?.browse_endpoint.browse_id.clone(),
+                    name: r.title.simple_text.clone()?,
+                })
+            })
+    }
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contents {
+    pub two_column_watch_next_results: TwoColumnWatchNextResults,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoColumnWatchNextResults {
+    pub results: Results,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Results {
+    pub results: ResultsContents,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultsContents {
+    #[serde(default)]
+    pub contents: Vec<ResultsContent>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultsContent {
+    #[serde(default)]
+    pub video_secondary_info_renderer: Option<VideoSecondaryInfoRenderer>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoSecondaryInfoRenderer {
+    #[serde(default)]
+    pub metadata_row_container: Option<MetadataRowContainer>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataRowContainer {
+    pub metadata_row_container_renderer: MetadataRowContainerRenderer,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataRowContainerRenderer {
+    #[serde(default)]
+    pub rows: Vec<MetadataRow>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataRow {
+    #[serde(default)]
+    pub rich_metadata_row_renderer: Option<RichMetadataRowRenderer>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RichMetadataRowRenderer {
+    #[serde(default)]
+    pub contents: Vec<RichMetadataContent>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RichMetadataContent {
+    #[serde(default)]
+    pub rich_metadata_renderer: Option<RichMetadataRenderer>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RichMetadataRenderer {
+    pub style: String,
+    pub title: Text,
+    #[serde(default)]
+    pub endpoint: Option<Endpoint>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Text {
+    #[serde(default)]
+    pub simple_text: Option<String>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Endpoint {
+    pub browse_endpoint: BrowseEndpoint,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowseEndpoint {
+    pub browse_id: String,
+}
+
+#[test]
+fn test() {
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/next.0.json")).unwrap();
+    assert_eq!(
+        res.game(),
+        Some(Game {
+            id: "UCQvWX73GQygcwXOTSf_VDVg".into(),
+            name: "Minecraft".into(),
+        })
+    );
+
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/next.1.json")).unwrap();
+    assert_eq!(res.game(), None);
+}
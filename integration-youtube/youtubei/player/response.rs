@@ -2,12 +2,14 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use vtstats_database::{
     channels::Platform,
-    streams::{Stream, StreamStatus},
+    streams::{Stream, StreamAccess, StreamKind, StreamStatus, VodStatus},
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Response {
+    #[serde(default)]
+    pub playability_status: Option<PlayabilityStatus>,
     pub video_details: Option<VideoDetails>,
     #[serde(default)]
     pub microformat: Option<Microformat>,
@@ -34,6 +36,64 @@ impl Response {
         )
     }
 
+    /// availability of this video as a vod, `listed` indicates whether
+    /// data api returns this video, which excludes private or deleted ones
+    pub fn vod_status(&self, listed: bool) -> VodStatus {
+        let (status, reason) = match &self.playability_status {
+            Some(s) => (s.status.as_str(), s.reason.as_deref().unwrap_or_default()),
+            None => ("", ""),
+        };
+
+        match (status, listed) {
+            ("OK", _) => VodStatus::Available,
+            _ if reason.contains("members-only") => VodStatus::MembersOnly,
+            // age-restricted videos require login as well
+            ("LOGIN_REQUIRED", true) => VodStatus::Available,
+            ("LOGIN_REQUIRED", false) => VodStatus::Private,
+            (_, true) => VodStatus::Unarchived,
+            (_, false) => VodStatus::Deleted,
+        }
+    }
+
+    /// premieres are uploaded videos played like a live, and vertical
+    /// lives are the ones shown in shorts feed
+    pub fn stream_kind(&self) -> StreamKind {
+        if self.video_details.as_ref().and_then(|d| d.is_live_content) == Some(false) {
+            return StreamKind::Premiere;
+        }
+
+        let embed = self
+            .microformat
+            .as_ref()
+            .and_then(|m| m.player_microformat_renderer.embed.as_ref());
+
+        match embed {
+            Some(embed) if embed.height > embed.width => StreamKind::Vertical,
+            _ => StreamKind::Live,
+        }
+    }
+
+    pub fn stream_access(&self) -> StreamAccess {
+        let members_only = self
+            .playability_status
+            .as_ref()
+            .and_then(|s| s.reason.as_deref())
+            .is_some_and(|reason| reason.contains("members-only"));
+
+        let unlisted = self
+            .microformat
+            .as_ref()
+            .is_some_and(|m| m.player_microformat_renderer.is_unlisted);
+
+        if members_only {
+            StreamAccess::MembersOnly
+        } else if unlisted {
+            StreamAccess::Unlisted
+        } else {
+            StreamAccess::Public
+        }
+    }
+
     pub fn to_stream(&self) -> Option<Stream> {
         let video_id = &self.video_details.as_ref()?.video_id;
         let format = self.microformat.clone()?.player_microformat_renderer;
@@ -69,10 +129,21 @@ impl Response {
             vtuber_id: "".into(),
             viewer_avg: None,
             viewer_max: None,
+            vod_status: None,
+            stream_kind: self.stream_kind(),
+            access: self.stream_access(),
         })
     }
 }
 
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayabilityStatus {
+    pub status: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VideoDetails {
@@ -80,6 +151,8 @@ pub struct VideoDetails {
     pub video_id: String,
     pub channel_id: String,
     pub thumbnail: Thumbnail,
+    #[serde(default)]
+    pub is_live_content: Option<bool>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -107,6 +180,17 @@ pub struct Microformat {
 pub struct PlayerMicroformatRenderer {
     pub title: Title,
     pub live_broadcast_details: LiveBroadcastDetails,
+    #[serde(default)]
+    pub embed: Option<Embed>,
+    #[serde(default)]
+    pub is_unlisted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Embed {
+    pub width: i64,
+    pub height: i64,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -159,4 +243,30 @@ fn test() {
     assert!(
         stream.schedule_time.is_none() && stream.start_time.is_some() && stream.end_time.is_none()
     );
+
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/player.5.json")).unwrap();
+    assert_eq!(res.vod_status(false), VodStatus::Private);
+
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/player.6.json")).unwrap();
+    assert_eq!(res.vod_status(true), VodStatus::MembersOnly);
+
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/player.7.json")).unwrap();
+    assert_eq!(res.vod_status(true), VodStatus::Unarchived);
+    assert_eq!(res.vod_status(false), VodStatus::Deleted);
+
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/player.2.json")).unwrap();
+    assert_eq!(res.stream_kind(), StreamKind::Live);
+    assert_eq!(res.stream_access(), StreamAccess::Public);
+
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/player.6.json")).unwrap();
+    assert_eq!(res.stream_access(), StreamAccess::MembersOnly);
+
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/player.8.json")).unwrap();
+    assert_eq!(res.stream_kind(), StreamKind::Premiere);
+    assert_eq!(res.stream_access(), StreamAccess::Unlisted);
+
+    let res = serde_json::from_str::<Response>(include_str!("./testdata/player.9.json")).unwrap();
+    let stream = res.to_stream().unwrap();
+    assert_eq!(stream.stream_kind, StreamKind::Vertical);
+    assert_eq!(stream.access, StreamAccess::Public);
 }
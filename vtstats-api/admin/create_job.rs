@@ -1,8 +1,10 @@
 use axum::{extract::State, response::IntoResponse, Json};
-use chrono::Utc;
+use chrono::{serde::ts_milliseconds, DateTime, Utc};
 use serde::Deserialize;
 use vtstats_database::{
-    jobs::{JobPayload, PushJobQuery},
+    jobs::{
+        BackfillExchangeRatesJobPayload, BackfillYoutubeStreamsJobPayload, JobPayload, PushJobQuery,
+    },
     PgPool,
 };
 
@@ -16,6 +18,21 @@ pub enum CreateJobPayload {
     RefreshYoutubeRss,
     SubscribeYoutubePubsub,
     UpdateChannelStats,
+    UpdateExchangeRates,
+    RefreshBilibiliLive,
+    SubscribeTwitchEventsub,
+    RefreshTwitchSchedule,
+    RefreshVodStatus,
+    BackfillStreamEventMoney,
+    BackfillExchangeRates {
+        #[serde(with = "ts_milliseconds")]
+        since: DateTime<Utc>,
+    },
+    BackfillYoutubeStreams {
+        channel_id: i32,
+        #[serde(default)]
+        with_chats: bool,
+    },
 }
 
 pub async fn create_job(
@@ -29,6 +46,22 @@ pub async fn create_job(
             CreateJobPayload::RefreshYoutubeRss => JobPayload::RefreshYoutubeRss,
             CreateJobPayload::SubscribeYoutubePubsub => JobPayload::SubscribeYoutubePubsub,
             CreateJobPayload::UpdateChannelStats => JobPayload::UpdateChannelStats,
+            CreateJobPayload::UpdateExchangeRates => JobPayload::UpdateExchangeRates,
+            CreateJobPayload::RefreshBilibiliLive => JobPayload::RefreshBilibiliLive,
+            CreateJobPayload::SubscribeTwitchEventsub => JobPayload::SubscribeTwitchEventsub,
+            CreateJobPayload::RefreshTwitchSchedule => JobPayload::RefreshTwitchSchedule,
+            CreateJobPayload::RefreshVodStatus => JobPayload::RefreshVodStatus,
+            CreateJobPayload::BackfillStreamEventMoney => JobPayload::BackfillStreamEventMoney,
+            CreateJobPayload::BackfillExchangeRates { since } => {
+                JobPayload::BackfillExchangeRates(BackfillExchangeRatesJobPayload { since })
+            }
+            CreateJobPayload::BackfillYoutubeStreams {
+                channel_id,
+                with_chats,
+            } => JobPayload::BackfillYoutubeStreams(BackfillYoutubeStreamsJobPayload {
+                channel_id,
+                with_chats,
+            }),
         },
     }
     .execute(&pool)
@@ -2,7 +2,10 @@ mod create_job;
 mod create_vtuber;
 mod re_run_job;
 mod rename_vtuber_id;
+mod update_chat_keywords;
 mod update_groups;
+mod update_supporter_opt_outs;
+mod update_synonyms;
 mod update_vtuber;
 
 use axum::{
@@ -24,8 +27,15 @@ use vtstats_database::{
 use crate::error::ApiResult;
 
 use self::{
-    create_job::create_job, create_vtuber::create_vtuber, re_run_job::re_run_job,
-    rename_vtuber_id::rename_vtuber_id, update_groups::update_groups, update_vtuber::update_vtuber,
+    create_job::create_job,
+    create_vtuber::create_vtuber,
+    re_run_job::re_run_job,
+    rename_vtuber_id::rename_vtuber_id,
+    update_chat_keywords::{delete_chat_keyword, upsert_chat_keyword},
+    update_groups::update_groups,
+    update_supporter_opt_outs::{add_supporter_opt_out, delete_supporter_opt_out},
+    update_synonyms::{delete_synonym, upsert_synonym},
+    update_vtuber::update_vtuber,
 };
 
 pub fn router(pool: PgPool) -> Router {
@@ -46,6 +56,26 @@ pub fn router(pool: PgPool) -> Router {
         .route("/vtubers/rename", post(rename_vtuber_id))
         .route("/channels", get(list_channels))
         .route("/groups", get(list_groups).post(update_groups))
+        // search
+        .route(
+            "/synonyms",
+            get(list_synonyms)
+                .put(upsert_synonym)
+                .delete(delete_synonym),
+        )
+        .route(
+            "/chat-keywords",
+            get(list_chat_keywords)
+                .put(upsert_chat_keyword)
+                .delete(delete_chat_keyword),
+        )
+        // supporters
+        .route(
+            "/supporter-opt-outs",
+            get(list_supporter_opt_outs)
+                .put(add_supporter_opt_out)
+                .delete(delete_supporter_opt_out),
+        )
         .layer(middleware::from_fn(verify))
         .with_state(pool)
 }
@@ -62,6 +92,21 @@ async fn list_groups(State(pool): State<PgPool>) -> ApiResult<impl IntoResponse>
     Ok(Json(groups))
 }
 
+async fn list_synonyms(State(pool): State<PgPool>) -> ApiResult<impl IntoResponse> {
+    let synonyms = vtstats_database::search::list_synonyms(&pool).await?;
+    Ok(Json(synonyms))
+}
+
+async fn list_chat_keywords(State(pool): State<PgPool>) -> ApiResult<impl IntoResponse> {
+    let keywords = vtstats_database::stream_stats::list_chat_keywords(&pool).await?;
+    Ok(Json(keywords))
+}
+
+async fn list_supporter_opt_outs(State(pool): State<PgPool>) -> ApiResult<impl IntoResponse> {
+    let opt_outs = vtstats_database::stream_events::list_supporter_opt_outs(&pool).await?;
+    Ok(Json(opt_outs))
+}
+
 async fn list_jobs(
     State(pool): State<PgPool>,
     Query(parameter): Query<ListParameter>,
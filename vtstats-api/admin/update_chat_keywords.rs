@@ -0,0 +1,34 @@
+use axum::{extract::State, response::IntoResponse, Json};
+use serde::Deserialize;
+use vtstats_database::{stream_stats::ChatKeyword, PgPool};
+
+use crate::error::ApiResult;
+
+use super::ActionResponse;
+
+pub async fn upsert_chat_keyword(
+    State(pool): State<PgPool>,
+    Json(keyword): Json<ChatKeyword>,
+) -> ApiResult<impl IntoResponse> {
+    vtstats_database::stream_stats::upsert_chat_keyword(&keyword, &pool).await?;
+
+    Ok(Json(ActionResponse {
+        msg: format!("Chat keyword {:?} was updated.", keyword.keyword),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct DeleteChatKeywordBody {
+    keyword: String,
+}
+
+pub async fn delete_chat_keyword(
+    State(pool): State<PgPool>,
+    Json(body): Json<DeleteChatKeywordBody>,
+) -> ApiResult<impl IntoResponse> {
+    vtstats_database::stream_stats::delete_chat_keyword(&body.keyword, &pool).await?;
+
+    Ok(Json(ActionResponse {
+        msg: format!("Chat keyword {:?} was deleted.", body.keyword),
+    }))
+}
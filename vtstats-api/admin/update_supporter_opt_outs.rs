@@ -0,0 +1,34 @@
+use axum::{extract::State, response::IntoResponse, Json};
+use vtstats_database::{stream_events::SupporterOptOut, PgPool};
+
+use crate::error::ApiResult;
+
+use super::ActionResponse;
+
+pub async fn add_supporter_opt_out(
+    State(pool): State<PgPool>,
+    Json(opt_out): Json<SupporterOptOut>,
+) -> ApiResult<impl IntoResponse> {
+    vtstats_database::stream_events::add_supporter_opt_out(&opt_out, &pool).await?;
+
+    Ok(Json(ActionResponse {
+        msg: format!(
+            "Supporter {:?} was opted out of channel {}.",
+            opt_out.author_id, opt_out.channel_id
+        ),
+    }))
+}
+
+pub async fn delete_supporter_opt_out(
+    State(pool): State<PgPool>,
+    Json(opt_out): Json<SupporterOptOut>,
+) -> ApiResult<impl IntoResponse> {
+    vtstats_database::stream_events::delete_supporter_opt_out(&opt_out, &pool).await?;
+
+    Ok(Json(ActionResponse {
+        msg: format!(
+            "Opt-out of supporter {:?} from channel {} was deleted.",
+            opt_out.author_id, opt_out.channel_id
+        ),
+    }))
+}
@@ -0,0 +1,34 @@
+use axum::{extract::State, response::IntoResponse, Json};
+use serde::Deserialize;
+use vtstats_database::{search::Synonym, PgPool};
+
+use crate::error::ApiResult;
+
+use super::ActionResponse;
+
+pub async fn upsert_synonym(
+    State(pool): State<PgPool>,
+    Json(synonym): Json<Synonym>,
+) -> ApiResult<impl IntoResponse> {
+    vtstats_database::search::upsert_synonym(&synonym, &pool).await?;
+
+    Ok(Json(ActionResponse {
+        msg: format!("Synonyms of {:?} was updated.", synonym.term),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct DeleteSynonymBody {
+    term: String,
+}
+
+pub async fn delete_synonym(
+    State(pool): State<PgPool>,
+    Json(body): Json<DeleteSynonymBody>,
+) -> ApiResult<impl IntoResponse> {
+    vtstats_database::search::delete_synonym(&body.term, &pool).await?;
+
+    Ok(Json(ActionResponse {
+        msg: format!("Synonyms of {:?} was deleted.", body.term),
+    }))
+}
@@ -7,11 +7,12 @@ use chrono::{Duration, DurationRound, Utc};
 use integration_youtube::{
     data_api::videos::{list_videos, Stream},
     pubsub::Event,
-    youtubei::player,
+    youtubei::{next, player},
 };
 use tracing::Span;
 
 use vtstats_database::{
+    categories::{assign_stream_category, has_stream_category, UpsertCategoryQuery},
     channels::{get_active_channel_by_platform_id, Platform},
     jobs::{queue_collect_youtube_stream_metadata, queue_send_notification},
     streams::{
@@ -60,12 +61,34 @@ async fn handle_modification(
         return Ok(());
     };
 
+    // youtube streams have one category for the whole stream, so the game
+    // is only looked up until the stream gets one
+    let categorized =
+        match get_stream_by_platform_id(Platform::Youtube, platform_stream_id, pool).await? {
+            Some(stream) => has_stream_category(stream.stream_id, pool).await?,
+            None => false,
+        };
+
     let mut thumbnail_url = None;
+    let mut game = None;
+    let mut stream_kind = None;
+    let mut access = None;
     if youtube_stream.status != StreamStatus::Ended {
-        thumbnail_url = player(platform_stream_id, &client)
-            .await
-            .ok()
-            .and_then(|res| Some(res.get_thumbnail_url()?.split_once('?')?.0.to_string()));
+        if let Ok(res) = player(platform_stream_id, &client).await {
+            thumbnail_url = res
+                .get_thumbnail_url()
+                .and_then(|url| Some(url.split_once('?')?.0.to_string()));
+            stream_kind = Some(res.stream_kind());
+            access = Some(res.stream_access());
+        }
+
+        // game row is missing when streamer doesn't set one
+        if !categorized {
+            match next(platform_stream_id, &client).await {
+                Ok(res) => game = res.game(),
+                Err(err) => tracing::warn!("Can't fetch game of {platform_stream_id}: {err}"),
+            }
+        }
     }
 
     let stream_id = UpsertStreamQuery {
@@ -79,6 +102,8 @@ async fn handle_modification(
         schedule_time: youtube_stream.schedule_time,
         start_time: youtube_stream.start_time,
         end_time: youtube_stream.end_time,
+        stream_kind,
+        access,
     }
     .execute(pool)
     .await?;
@@ -87,6 +112,24 @@ async fn handle_modification(
 
     let now = Utc::now();
 
+    if let Some(game) = game {
+        let category_id = UpsertCategoryQuery {
+            platform: Platform::Youtube,
+            platform_category_id: &game.id,
+            name: &game.name,
+        }
+        .execute(pool)
+        .await?;
+
+        // youtube streams have one category for the whole stream
+        let time = youtube_stream
+            .start_time
+            .or(youtube_stream.schedule_time)
+            .unwrap_or(now);
+
+        assign_stream_category(stream_id, category_id, time, pool).await?;
+    }
+
     match (
         youtube_stream.schedule_time,
         youtube_stream.start_time,
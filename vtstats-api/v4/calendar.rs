@@ -0,0 +1,277 @@
+use axum::{
+    extract::{Query, State},
+    http::{
+        header::{CACHE_CONTROL, CONTENT_TYPE},
+        StatusCode,
+    },
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
+use tokio::try_join;
+
+use vtstats_database::{
+    channels::Platform,
+    streams::{list_calendar_streams, list_deleted_streams},
+    PgPool,
+};
+
+use crate::error::ApiResult;
+
+const HOSTNAME: &str = "vt.poi.cat";
+
+#[serde_as]
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReqQuery {
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, i32>")]
+    #[serde(default)]
+    channel_ids: Vec<i32>,
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
+    #[serde(default)]
+    vtuber_ids: Vec<String>,
+}
+
+/// returns scheduled, live and recently ended streams as an iCalendar feed
+/// (RFC 5545), streams deleted recently are included as cancelled events
+pub async fn calendar(
+    Query(query): Query<ReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<Response> {
+    if (query.channel_ids.is_empty() && query.vtuber_ids.is_empty())
+        || query.channel_ids.len() + query.vtuber_ids.len() > 100
+    {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    }
+
+    let since = Utc::now() - Duration::days(7);
+
+    let (streams, deleted) = try_join!(
+        list_calendar_streams(&query.channel_ids, &query.vtuber_ids, since, &pool),
+        list_deleted_streams(&query.channel_ids, &query.vtuber_ids, since, &pool),
+    )?;
+
+    let mut calendar = Calendar::new();
+
+    for stream in &streams {
+        let Some(start) = stream.schedule_time.or(stream.start_time) else {
+            continue;
+        };
+
+        calendar.event(&Event {
+            stream_id: stream.stream_id,
+            title: &stream.title,
+            url: stream_url(stream.platform, &stream.platform_id),
+            start,
+            end: event_end(start, stream.start_time, stream.end_time),
+            stamp: stream.updated_at,
+            sequence: stream.schedule_sequence,
+            cancelled: false,
+        });
+    }
+
+    for stream in &deleted {
+        let Some(start) = stream.schedule_time.or(stream.start_time) else {
+            continue;
+        };
+
+        calendar.event(&Event {
+            stream_id: stream.stream_id,
+            title: &stream.title,
+            url: stream_url(stream.platform, &stream.platform_id),
+            start,
+            end: event_end(start, stream.start_time, None),
+            stamp: stream.deleted_at,
+            // cancellation is one more revision of the event
+            sequence: stream.schedule_sequence + 1,
+            cancelled: true,
+        });
+    }
+
+    Ok((
+        [
+            (CONTENT_TYPE, "text/calendar; charset=utf-8"),
+            (CACHE_CONTROL, "max-age=600"), // 10 minutes
+        ],
+        calendar.finish(),
+    )
+        .into_response())
+}
+
+fn stream_url(platform: Platform, platform_id: &str) -> String {
+    match platform {
+        Platform::Youtube => format!("https://www.youtube.com/watch?v={platform_id}"),
+        Platform::Twitch => format!("https://{HOSTNAME}/twitch-stream/{platform_id}"),
+        Platform::Bilibili => format!("https://{HOSTNAME}/bilibili-stream/{platform_id}"),
+    }
+}
+
+/// streams don't have an expected duration, so an event lasts until the
+/// stream ends, or one hour after it started (or was scheduled to start)
+fn event_end(
+    start: DateTime<Utc>,
+    start_time: Option<DateTime<Utc>>,
+    end_time: Option<DateTime<Utc>>,
+) -> DateTime<Utc> {
+    end_time
+        .filter(|end| *end > start)
+        .unwrap_or_else(|| start.max(start_time.unwrap_or(start)) + Duration::hours(1))
+}
+
+struct Event<'a> {
+    stream_id: i32,
+    title: &'a str,
+    url: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    stamp: DateTime<Utc>,
+    sequence: i32,
+    cancelled: bool,
+}
+
+struct Calendar {
+    buf: String,
+}
+
+impl Calendar {
+    fn new() -> Self {
+        let mut calendar = Calendar { buf: String::new() };
+        calendar.line("BEGIN", "VCALENDAR");
+        calendar.line("VERSION", "2.0");
+        calendar.line("PRODID", "-//vtstats//calendar//EN");
+        calendar.line("CALSCALE", "GREGORIAN");
+        calendar.line("METHOD", "PUBLISH");
+        calendar.line("X-WR-CALNAME", "vtstats");
+        calendar
+    }
+
+    fn event(&mut self, event: &Event) {
+        self.line("BEGIN", "VEVENT");
+        // uid must stay the same across updates, so that
+        // calendar apps can replace or cancel the event
+        self.line("UID", &format!("stream-{}@{HOSTNAME}", event.stream_id));
+        self.line("DTSTAMP", &format_time(event.stamp));
+        self.line("LAST-MODIFIED", &format_time(event.stamp));
+        self.line("DTSTART", &format_time(event.start));
+        self.line("DTEND", &format_time(event.end));
+        self.line("SUMMARY", &escape_text(event.title));
+        self.line("URL", &event.url);
+        self.line("SEQUENCE", &event.sequence.to_string());
+        if event.cancelled {
+            self.line("STATUS", "CANCELLED");
+        } else {
+            self.line("STATUS", "CONFIRMED");
+        }
+        self.line("END", "VEVENT");
+    }
+
+    fn finish(mut self) -> String {
+        self.line("END", "VCALENDAR");
+        self.buf
+    }
+
+    /// writes a content line, folded at 75 octets as RFC 5545 requires
+    fn line(&mut self, name: &str, value: &str) {
+        let mut width = 0;
+
+        for c in name
+            .chars()
+            .chain(std::iter::once(':'))
+            .chain(value.chars())
+        {
+            if width + c.len_utf8() > 75 {
+                self.buf.push_str("\r\n ");
+                width = 1;
+            }
+            self.buf.push(c);
+            width += c.len_utf8();
+        }
+
+        self.buf.push_str("\r\n");
+    }
+}
+
+fn format_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[test]
+fn test_calendar() {
+    use chrono::TimeZone;
+
+    let start = Utc.timestamp_opt(1700000000, 0).single().unwrap();
+
+    let mut calendar = Calendar::new();
+    calendar.event(&Event {
+        stream_id: 42,
+        title: "【歌枠】Karaoke; songs, and more\\",
+        url: stream_url(Platform::Youtube, "abc"),
+        start,
+        end: event_end(start, None, None),
+        stamp: start - Duration::hours(2),
+        sequence: 3,
+        cancelled: true,
+    });
+    let ics = calendar.finish();
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+    assert!(ics.contains("\r\nUID:stream-42@vt.poi.cat\r\n"));
+    assert!(ics.contains("\r\nDTSTART:20231114T221320Z\r\n"));
+    assert!(ics.contains("\r\nDTEND:20231114T231320Z\r\n"));
+    assert!(ics.contains("\r\nDTSTAMP:20231114T201320Z\r\n"));
+    assert!(ics.contains("\r\nSUMMARY:【歌枠】Karaoke\\; songs\\, and more\\\\\r\n"));
+    assert!(ics.contains("\r\nURL:https://www.youtube.com/watch?v=abc\r\n"));
+    assert!(ics.contains("\r\nSTATUS:CANCELLED\r\n"));
+    assert!(ics.contains("\r\nSEQUENCE:3\r\n"));
+}
+
+#[test]
+fn test_event_end() {
+    use chrono::TimeZone;
+
+    let start = Utc.timestamp_opt(1700000000, 0).single().unwrap();
+
+    assert_eq!(event_end(start, None, None), start + Duration::hours(1));
+    // started late
+    assert_eq!(
+        event_end(start, Some(start + Duration::minutes(30)), None),
+        start + Duration::minutes(90)
+    );
+    assert_eq!(
+        event_end(start, Some(start), Some(start + Duration::hours(3))),
+        start + Duration::hours(3)
+    );
+}
+
+#[test]
+fn test_line_folding() {
+    let mut calendar = Calendar { buf: String::new() };
+    calendar.line("SUMMARY", &"あ".repeat(30));
+
+    let lines: Vec<_> = calendar.buf.trim_end().split("\r\n").collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines.iter().all(|l| l.len() <= 75));
+    assert!(lines[1].starts_with(' '));
+    assert_eq!(
+        lines.concat().replacen(' ', "", 1),
+        format!("SUMMARY:{}", "あ".repeat(30))
+    );
+}
@@ -0,0 +1,55 @@
+use axum::{
+    extract::{Query, State},
+    http::status::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{serde::ts_milliseconds_option, DateTime, Duration, Utc};
+use serde::Deserialize;
+
+use vtstats_database::{categories::ListCategoryStatsQuery, channels::Platform, PgPool};
+
+use super::rankings::validate_limit;
+use crate::error::ApiResult;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoriesReqQuery {
+    #[serde(default, with = "ts_milliseconds_option")]
+    start_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "ts_milliseconds_option")]
+    end_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    platform: Option<Platform>,
+    #[serde(default)]
+    limit: Option<i64>,
+}
+
+/// hours streamed, average viewers and top vtubers per category,
+/// for streams started in the last 7 days by default
+pub async fn list_categories(
+    Query(query): Query<CategoriesReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<Response> {
+    let end_at = query.end_at.unwrap_or_else(Utc::now);
+    let start_at = query.start_at.unwrap_or(end_at - Duration::days(7));
+
+    if start_at >= end_at || (end_at - start_at).num_days() > 90 {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    }
+
+    let Some(limit) = validate_limit(query.limit) else {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    };
+
+    let stats = ListCategoryStatsQuery {
+        platform: query.platform,
+        start_at,
+        end_at,
+        limit,
+    }
+    .execute(&pool)
+    .await?;
+
+    Ok(Json(stats).into_response())
+}
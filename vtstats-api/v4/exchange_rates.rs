@@ -1,11 +1,33 @@
-use axum::{extract::State, http::header::CACHE_CONTROL, response::IntoResponse, Json};
+use axum::{
+    extract::{Query, State},
+    http::header::CACHE_CONTROL,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
 
-use vtstats_database::{exchange_rates::list_exchange_rates, PgPool};
+use vtstats_database::{
+    exchange_rates::{list_exchange_rates, list_exchange_rates_at},
+    PgPool,
+};
 
 use crate::error::ApiResult;
 
-pub async fn exchange_rates(State(pool): State<PgPool>) -> ApiResult<impl IntoResponse> {
-    let res = list_exchange_rates(&pool).await?;
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReqQuery {
+    #[serde(default, with = "ts_milliseconds_option")]
+    at: Option<DateTime<Utc>>,
+}
+
+pub async fn exchange_rates(
+    Query(query): Query<ReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<impl IntoResponse> {
+    let res = match query.at {
+        Some(at) => list_exchange_rates_at(at, &pool).await?,
+        None => list_exchange_rates(&pool).await?,
+    };
 
     Ok((
         [(CACHE_CONTROL, "max-age=864000")], // 10 days
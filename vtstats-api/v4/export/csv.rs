@@ -0,0 +1,101 @@
+use chrono::SecondsFormat;
+use vtstats_database::exports::{ExportColumn, ExportRow, ExportValue};
+
+use super::Encoder;
+
+pub struct CsvEncoder {
+    columns: &'static [ExportColumn],
+    header_written: bool,
+}
+
+impl CsvEncoder {
+    pub fn new(columns: &'static [ExportColumn]) -> Self {
+        CsvEncoder {
+            columns,
+            header_written: false,
+        }
+    }
+
+    fn write(&mut self, rows: &[ExportRow]) -> anyhow::Result<Vec<u8>> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+
+        if !self.header_written {
+            writer.write_record(self.columns.iter().map(|c| c.name))?;
+            self.header_written = true;
+        }
+
+        for row in rows {
+            writer.write_record(row.iter().map(format_value))?;
+        }
+
+        Ok(writer.into_inner()?)
+    }
+}
+
+impl Encoder for CsvEncoder {
+    fn encode(&mut self, rows: &[ExportRow]) -> anyhow::Result<Vec<u8>> {
+        self.write(rows)
+    }
+
+    fn finish(mut self: Box<Self>) -> anyhow::Result<Vec<u8>> {
+        // empty export still comes with a header
+        self.write(&[])
+    }
+}
+
+fn format_value(value: &ExportValue) -> String {
+    match value {
+        ExportValue::Integer(Some(i)) => i.to_string(),
+        ExportValue::Text(Some(s)) => s.clone(),
+        ExportValue::Timestamp(Some(t)) => t.to_rfc3339_opts(SecondsFormat::Millis, true),
+        ExportValue::Integer(None) | ExportValue::Text(None) | ExportValue::Timestamp(None) => {
+            String::new()
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use chrono::{TimeZone, Utc};
+    use vtstats_database::exports::ExportColumnKind;
+
+    static COLUMNS: &[ExportColumn] = &[
+        ExportColumn {
+            name: "time",
+            kind: ExportColumnKind::Timestamp,
+        },
+        ExportColumn {
+            name: "title",
+            kind: ExportColumnKind::Text,
+        },
+        ExportColumn {
+            name: "count",
+            kind: ExportColumnKind::Integer,
+        },
+    ];
+
+    let mut encoder = Box::new(CsvEncoder::new(COLUMNS));
+
+    let first = encoder
+        .encode(&[vec![
+            ExportValue::Timestamp(Utc.timestamp_opt(1700000000, 0).single()),
+            ExportValue::Text(Some("hello, \"world\"".into())),
+            ExportValue::Integer(Some(42)),
+        ]])
+        .unwrap();
+    let second = encoder
+        .encode(&[vec![
+            ExportValue::Timestamp(None),
+            ExportValue::Text(None),
+            ExportValue::Integer(None),
+        ]])
+        .unwrap();
+    let last = encoder.finish().unwrap();
+
+    assert_eq!(
+        String::from_utf8(first).unwrap(),
+        "time,title,count\n2023-11-14T22:13:20.000Z,\"hello, \"\"world\"\"\",42\n"
+    );
+    assert_eq!(String::from_utf8(second).unwrap(), ",,\n");
+    assert!(last.is_empty());
+}
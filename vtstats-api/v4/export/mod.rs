@@ -0,0 +1,206 @@
+mod csv;
+mod parquet;
+
+use axum::{
+    body::{boxed, Body, Bytes},
+    extract::{Query, State},
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        StatusCode,
+    },
+    response::{IntoResponse, Response},
+};
+use chrono::{serde::ts_milliseconds_option, DateTime, Utc};
+use futures_util::TryStreamExt;
+use serde::Deserialize;
+use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
+
+use vtstats_database::{
+    exports::{self as db, Export, ExportRow},
+    PgPool,
+};
+
+use crate::error::ApiResult;
+
+use self::{csv::CsvEncoder, parquet::ParquetEncoder};
+
+/// number of rows encoded and sent at once,
+/// each chunk also becomes a row group in parquet
+const CHUNK_SIZE: usize = 1000;
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportSeries {
+    StreamViewer,
+    StreamChat,
+    StreamEvents,
+    Streams,
+    ChannelSubscriber,
+    ChannelView,
+    ChannelRevenue,
+}
+
+impl ExportSeries {
+    fn file_stem(self) -> &'static str {
+        match self {
+            ExportSeries::StreamViewer => "stream_viewer",
+            ExportSeries::StreamChat => "stream_chat",
+            ExportSeries::StreamEvents => "stream_events",
+            ExportSeries::Streams => "streams",
+            ExportSeries::ChannelSubscriber => "channel_subscriber",
+            ExportSeries::ChannelView => "channel_view",
+            ExportSeries::ChannelRevenue => "channel_revenue",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Parquet,
+}
+
+#[serde_as]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReqQuery {
+    series: ExportSeries,
+    #[serde(default)]
+    format: ExportFormat,
+    #[serde(default)]
+    stream_id: Option<i32>,
+    #[serde(default)]
+    channel_id: Option<i32>,
+    #[serde_as(as = "Option<StringWithSeparator::<CommaSeparator, i32>>")]
+    #[serde(default)]
+    channel_ids: Option<Vec<i32>>,
+    #[serde(default, with = "ts_milliseconds_option")]
+    start_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "ts_milliseconds_option")]
+    end_at: Option<DateTime<Utc>>,
+}
+
+impl ReqQuery {
+    fn is_valid(&self) -> bool {
+        if matches!((self.start_at, self.end_at), (Some(s), Some(e)) if s >= e) {
+            return false;
+        }
+
+        match self.series {
+            ExportSeries::StreamViewer | ExportSeries::StreamChat | ExportSeries::StreamEvents => {
+                self.stream_id.is_some()
+            }
+            ExportSeries::Streams => matches!(&self.channel_ids, Some(ids) if !ids.is_empty()),
+            ExportSeries::ChannelSubscriber
+            | ExportSeries::ChannelView
+            | ExportSeries::ChannelRevenue => self.channel_id.is_some(),
+        }
+    }
+
+    fn export<'e>(&self, pool: &'e PgPool) -> Export<'e> {
+        let stream_id = self.stream_id.unwrap_or_default();
+        let channel_id = self.channel_id.unwrap_or_default();
+        let channel_ids = self.channel_ids.as_deref().unwrap_or_default();
+
+        match self.series {
+            ExportSeries::StreamViewer => db::export_stream_viewer_stats(stream_id, pool),
+            ExportSeries::StreamChat => db::export_stream_chat_stats(stream_id, pool),
+            ExportSeries::StreamEvents => db::export_stream_events(stream_id, pool),
+            ExportSeries::Streams => {
+                db::export_streams(channel_ids, self.start_at, self.end_at, pool)
+            }
+            ExportSeries::ChannelSubscriber => {
+                db::export_channel_subscriber_stats(channel_id, self.start_at, self.end_at, pool)
+            }
+            ExportSeries::ChannelView => {
+                db::export_channel_view_stats(channel_id, self.start_at, self.end_at, pool)
+            }
+            ExportSeries::ChannelRevenue => {
+                db::export_channel_revenue_stats(channel_id, self.start_at, self.end_at, pool)
+            }
+        }
+    }
+}
+
+/// Encodes exported rows chunk by chunk, so that response can be
+/// streamed instead of buffered
+trait Encoder: Send {
+    /// returns bytes ready to be sent after encoding given rows
+    fn encode(&mut self, rows: &[ExportRow]) -> anyhow::Result<Vec<u8>>;
+
+    /// returns remaining bytes, e.g. header or footer
+    fn finish(self: Box<Self>) -> anyhow::Result<Vec<u8>>;
+}
+
+pub async fn export(
+    Query(query): Query<ReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<Response> {
+    if !query.is_valid() {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    }
+
+    let (content_type, extension) = match query.format {
+        ExportFormat::Csv => ("text/csv; charset=utf-8", "csv"),
+        ExportFormat::Parquet => ("application/vnd.apache.parquet", "parquet"),
+    };
+
+    let content_disposition = format!(
+        "attachment; filename=\"{}.{extension}\"",
+        query.series.file_stem()
+    );
+
+    let (mut sender, body) = Body::channel();
+
+    tokio::spawn(async move {
+        let export = query.export(&pool);
+
+        let encoder: anyhow::Result<Box<dyn Encoder>> = match query.format {
+            ExportFormat::Csv => Ok(Box::new(CsvEncoder::new(export.columns))),
+            ExportFormat::Parquet => {
+                ParquetEncoder::new(export.columns).map(|e| Box::new(e) as Box<dyn Encoder>)
+            }
+        };
+
+        let result = match encoder {
+            Ok(encoder) => send_export(export, encoder, &mut sender).await,
+            Err(err) => Err(err),
+        };
+
+        if let Err(err) = result {
+            tracing::error!(exception.stacktrace = ?err, message = %err);
+            // make sure client won't receive a truncated file
+            sender.abort();
+        }
+    });
+
+    Ok((
+        [
+            (CONTENT_TYPE, content_type.to_string()),
+            (CONTENT_DISPOSITION, content_disposition),
+        ],
+        boxed(body),
+    )
+        .into_response())
+}
+
+async fn send_export(
+    export: Export<'_>,
+    mut encoder: Box<dyn Encoder>,
+    sender: &mut hyper::body::Sender,
+) -> anyhow::Result<()> {
+    let mut chunks = export.rows.try_chunks(CHUNK_SIZE);
+
+    while let Some(rows) = chunks.try_next().await.map_err(|err| err.1)? {
+        let bytes = encoder.encode(&rows)?;
+        if !bytes.is_empty() {
+            sender.send_data(Bytes::from(bytes)).await?;
+        }
+    }
+
+    sender.send_data(Bytes::from(encoder.finish()?)).await?;
+
+    Ok(())
+}
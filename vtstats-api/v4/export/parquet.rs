@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use parquet::{
+    basic::{LogicalType, Repetition, TimeUnit, Type as PhysicalType},
+    column::writer::ColumnWriter,
+    data_type::ByteArray,
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    format::MilliSeconds,
+    schema::types::Type,
+};
+use vtstats_database::exports::{ExportColumn, ExportColumnKind, ExportRow, ExportValue};
+
+use super::Encoder;
+
+pub struct ParquetEncoder {
+    writer: SerializedFileWriter<Vec<u8>>,
+}
+
+impl ParquetEncoder {
+    pub fn new(columns: &'static [ExportColumn]) -> anyhow::Result<Self> {
+        let fields = columns
+            .iter()
+            .map(|column| {
+                let builder = match column.kind {
+                    ExportColumnKind::Integer => {
+                        Type::primitive_type_builder(column.name, PhysicalType::INT64)
+                    }
+                    ExportColumnKind::Text => {
+                        Type::primitive_type_builder(column.name, PhysicalType::BYTE_ARRAY)
+                            .with_logical_type(Some(LogicalType::String))
+                    }
+                    ExportColumnKind::Timestamp => {
+                        Type::primitive_type_builder(column.name, PhysicalType::INT64)
+                            .with_logical_type(Some(LogicalType::Timestamp {
+                                is_adjusted_to_u_t_c: true,
+                                unit: TimeUnit::MILLIS(MilliSeconds {}),
+                            }))
+                    }
+                };
+
+                builder
+                    .with_repetition(Repetition::OPTIONAL)
+                    .build()
+                    .map(Arc::new)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let schema = Type::group_type_builder("schema")
+            .with_fields(fields)
+            .build()?;
+
+        let writer = SerializedFileWriter::new(
+            Vec::new(),
+            Arc::new(schema),
+            Arc::new(WriterProperties::builder().build()),
+        )?;
+
+        Ok(ParquetEncoder { writer })
+    }
+}
+
+impl Encoder for ParquetEncoder {
+    fn encode(&mut self, rows: &[ExportRow]) -> anyhow::Result<Vec<u8>> {
+        let mut row_group = self.writer.next_row_group()?;
+        let mut index = 0;
+
+        while let Some(mut column) = row_group.next_column()? {
+            // definition level 1 means the value is present, 0 means null
+            let def_levels: Vec<i16> = rows
+                .iter()
+                .map(|row| match &row[index] {
+                    ExportValue::Integer(v) => v.is_some() as i16,
+                    ExportValue::Text(v) => v.is_some() as i16,
+                    ExportValue::Timestamp(v) => v.is_some() as i16,
+                })
+                .collect();
+
+            match column.untyped() {
+                ColumnWriter::Int64ColumnWriter(writer) => {
+                    let values: Vec<i64> = rows
+                        .iter()
+                        .filter_map(|row| match &row[index] {
+                            ExportValue::Integer(v) => *v,
+                            ExportValue::Timestamp(v) => v.map(|t| t.timestamp_millis()),
+                            ExportValue::Text(_) => None,
+                        })
+                        .collect();
+                    writer.write_batch(&values, Some(&def_levels), None)?;
+                }
+                ColumnWriter::ByteArrayColumnWriter(writer) => {
+                    let values: Vec<ByteArray> = rows
+                        .iter()
+                        .filter_map(|row| match &row[index] {
+                            ExportValue::Text(v) => v.as_deref().map(ByteArray::from),
+                            _ => None,
+                        })
+                        .collect();
+                    writer.write_batch(&values, Some(&def_levels), None)?;
+                }
+                _ => anyhow::bail!("unexpected parquet column type"),
+            }
+
+            column.close()?;
+            index += 1;
+        }
+
+        row_group.close()?;
+
+        // hand over whatever has been flushed to underlying buffer so far,
+        // the writer keeps tracking offsets by itself
+        Ok(std::mem::take(self.writer.inner_mut()))
+    }
+
+    fn finish(self: Box<Self>) -> anyhow::Result<Vec<u8>> {
+        Ok(self.writer.into_inner()?)
+    }
+}
+
+#[test]
+fn test() {
+    use chrono::{TimeZone, Utc};
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::record::Field;
+
+    static COLUMNS: &[ExportColumn] = &[
+        ExportColumn {
+            name: "time",
+            kind: ExportColumnKind::Timestamp,
+        },
+        ExportColumn {
+            name: "title",
+            kind: ExportColumnKind::Text,
+        },
+        ExportColumn {
+            name: "count",
+            kind: ExportColumnKind::Integer,
+        },
+    ];
+
+    let mut encoder = Box::new(ParquetEncoder::new(COLUMNS).unwrap());
+
+    let mut file = encoder
+        .encode(&[
+            vec![
+                ExportValue::Timestamp(Utc.timestamp_opt(1700000000, 0).single()),
+                ExportValue::Text(Some("title1".into())),
+                ExportValue::Integer(Some(42)),
+            ],
+            vec![
+                ExportValue::Timestamp(None),
+                ExportValue::Text(None),
+                ExportValue::Integer(None),
+            ],
+        ])
+        .unwrap();
+    file.extend(
+        encoder
+            .encode(&[vec![
+                ExportValue::Timestamp(Utc.timestamp_opt(1700000001, 0).single()),
+                ExportValue::Text(Some("title2".into())),
+                ExportValue::Integer(Some(7)),
+            ]])
+            .unwrap(),
+    );
+    file.extend(encoder.finish().unwrap());
+
+    let reader = SerializedFileReader::new(bytes::Bytes::from(file)).unwrap();
+    assert_eq!(reader.metadata().num_row_groups(), 2);
+
+    let rows: Vec<Vec<Field>> = reader
+        .get_row_iter(None)
+        .unwrap()
+        .map(|row| {
+            row.unwrap()
+                .get_column_iter()
+                .map(|(_, f)| f.clone())
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                Field::TimestampMillis(1700000000000),
+                Field::Str("title1".into()),
+                Field::Long(42),
+            ],
+            vec![Field::Null, Field::Null, Field::Null],
+            vec![
+                Field::TimestampMillis(1700000001000),
+                Field::Str("title2".into()),
+                Field::Long(7),
+            ],
+        ]
+    );
+}
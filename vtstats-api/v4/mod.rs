@@ -1,20 +1,32 @@
+mod calendar;
 mod catalog;
+mod categories;
 mod channel_stats;
 mod channels;
 mod exchange_rates;
+mod export;
+mod rankings;
+mod search;
 mod stream_events;
 mod stream_stats;
 mod stream_times;
 mod streams;
+mod supporters;
 
+pub use calendar::*;
 pub use catalog::*;
+pub use categories::*;
 pub use channel_stats::*;
 pub use channels::*;
 pub use exchange_rates::*;
+pub use export::*;
+pub use rankings::*;
+pub use search::*;
 pub use stream_events::*;
 pub use stream_stats::*;
 pub use stream_times::*;
 pub use streams::*;
+pub use supporters::*;
 
 use axum::routing::get;
 use axum::Router;
@@ -23,18 +35,29 @@ use vtstats_database::PgPool;
 pub fn router(pool: PgPool) -> Router {
     Router::new()
         .route("/catalog", get(catalog))
+        .route("/calendar.ics", get(calendar))
+        .route("/categories", get(list_categories))
         .route("/exchange-rates", get(exchange_rates))
+        .route("/export", get(export))
         .route("/channel-stats/summary", get(channel_stats_summary))
         .route("/stream-stats/viewer", get(stream_viewer_stats))
         .route("/stream-stats/chat", get(stream_chat_stats))
+        .route("/stream-stats/chat/terms", get(stream_chat_terms))
+        .route("/stream-stats/audience", get(stream_audience))
         .route("/stream-events", get(stream_events))
+        .route("/stream-events/tiers", get(stream_event_tiers))
         .route("/stream-times", get(stream_times))
         .route("/channel-stats/subscriber", get(channel_subscriber_stats))
         .route("/channel-stats/view", get(channel_view_stats))
         .route("/channel-stats/revenue", get(channel_revenue_stats))
+        .route("/rankings", get(channel_rankings))
+        .route("/rankings/streams", get(stream_rankings))
+        .route("/search", get(search))
         .route("/streams", get(find_stream_by_id))
         .route("/streams/scheduled", get(list_scheduled_streams))
         .route("/streams/live", get(list_live_streams))
         .route("/streams/ended", get(list_ended_streams))
+        .route("/streams/:id/timeline", get(stream_timeline))
+        .route("/supporters", get(supporters))
         .with_state(pool)
 }
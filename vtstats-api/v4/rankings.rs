@@ -0,0 +1,273 @@
+use axum::{
+    extract::{Query, State},
+    http::status::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{serde::ts_milliseconds_option, DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use vtstats_database::{
+    channel_stats_summary::{list_by_platform_and_group, ChannelStatsKind},
+    channels::Platform,
+    stream_events::{rank_channels_by_revenue, rank_streams_by_revenue},
+    streams::{list_streams_by_ids, RankStreamsQuery, Stream, StreamRankingColumn},
+    PgPool,
+};
+
+use crate::error::ApiResult;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 100;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelRankingsReqQuery {
+    kind: ChannelStatsKind,
+    #[serde(default = "default_days")]
+    days: i64,
+    #[serde(default)]
+    platform: Option<Platform>,
+    #[serde(default)]
+    group_id: Option<String>,
+    #[serde(default)]
+    limit: Option<i64>,
+}
+
+fn default_days() -> i64 {
+    7
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelRanking {
+    channel_id: i32,
+    value: f64,
+    previous_value: f64,
+    delta: f64,
+}
+
+pub async fn channel_rankings(
+    Query(query): Query<ChannelRankingsReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<Response> {
+    if !matches!(query.days, 1 | 7 | 30) {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    }
+
+    let Some(limit) = validate_limit(query.limit) else {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    };
+
+    if query.kind == ChannelStatsKind::Revenue {
+        let rankings = channel_revenue_rankings(&query, limit, &pool).await?;
+        return Ok(Json(rankings).into_response());
+    }
+
+    let summaries =
+        list_by_platform_and_group(query.kind, query.platform, query.group_id.as_deref(), &pool)
+            .await?;
+
+    let to_number = |value: &Value| value.as_f64().unwrap_or_default();
+
+    let mut rankings: Vec<_> = summaries
+        .iter()
+        .filter(|s| !s.value.is_null())
+        .map(|s| {
+            let previous = match query.days {
+                1 => &s.value_1_day_ago,
+                7 => &s.value_7_days_ago,
+                _ => &s.value_30_days_ago,
+            };
+            let value = to_number(&s.value);
+            let previous_value = to_number(previous);
+            ChannelRanking {
+                channel_id: s.channel_id,
+                value,
+                previous_value,
+                delta: value - previous_value,
+            }
+        })
+        .collect();
+
+    rankings.sort_by(|a, b| b.delta.total_cmp(&a.delta));
+    rankings.truncate(limit as usize);
+
+    Ok(Json(rankings).into_response())
+}
+
+/// revenue is summed up from events converted with the exchange rates of
+/// their month, rather than from the summary in original currencies
+async fn channel_revenue_rankings(
+    query: &ChannelRankingsReqQuery,
+    limit: i64,
+    pool: &PgPool,
+) -> anyhow::Result<Vec<ChannelRanking>> {
+    let since = Utc::now() - Duration::days(query.days);
+
+    let revenues = rank_channels_by_revenue(
+        query.platform,
+        query.group_id.as_deref(),
+        since,
+        limit,
+        pool,
+    )
+    .await?;
+
+    Ok(revenues
+        .into_iter()
+        .map(|r| ChannelRanking {
+            channel_id: r.channel_id,
+            value: r.value,
+            previous_value: r.previous_value,
+            delta: r.value - r.previous_value,
+        })
+        .collect())
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StreamRankingOrder {
+    ViewerMax,
+    ViewerAvg,
+    Revenue,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamRankingsReqQuery {
+    order_by: StreamRankingOrder,
+    #[serde(default, with = "ts_milliseconds_option")]
+    start_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "ts_milliseconds_option")]
+    end_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    platform: Option<Platform>,
+    #[serde(default)]
+    group_id: Option<String>,
+    #[serde(default)]
+    limit: Option<i64>,
+}
+
+impl StreamRankingsReqQuery {
+    /// returns time window of this query, or `None` if it's invalid
+    fn window(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let now = Utc::now();
+        let end_at = self.end_at.unwrap_or(now);
+        let start_at = self.start_at.unwrap_or(end_at - Duration::days(7));
+
+        if start_at >= end_at || (end_at - start_at).num_days() > 90 {
+            return None;
+        }
+
+        Some((start_at, end_at))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamRanking {
+    #[serde(flatten)]
+    stream: Stream,
+    value: f64,
+}
+
+pub async fn stream_rankings(
+    Query(query): Query<StreamRankingsReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<Response> {
+    let Some((start_at, end_at)) = query.window() else {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    };
+
+    let Some(limit) = validate_limit(query.limit) else {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    };
+
+    let order_by = match query.order_by {
+        StreamRankingOrder::ViewerMax => StreamRankingColumn::ViewerMax,
+        StreamRankingOrder::ViewerAvg => StreamRankingColumn::ViewerAvg,
+        StreamRankingOrder::Revenue => {
+            let rankings = stream_revenue_rankings(&query, start_at, end_at, limit, &pool).await?;
+            return Ok(Json(rankings).into_response());
+        }
+    };
+
+    let streams = RankStreamsQuery {
+        order_by,
+        platform: query.platform,
+        group_id: query.group_id.as_deref(),
+        start_at,
+        end_at,
+        limit,
+    }
+    .execute(&pool)
+    .await?;
+
+    let rankings: Vec<_> = streams
+        .into_iter()
+        .map(|stream| {
+            let value = match order_by {
+                StreamRankingColumn::ViewerMax => stream.viewer_max,
+                StreamRankingColumn::ViewerAvg => stream.viewer_avg,
+            };
+            StreamRanking {
+                value: value.unwrap_or_default() as f64,
+                stream,
+            }
+        })
+        .collect();
+
+    Ok(Json(rankings).into_response())
+}
+
+async fn stream_revenue_rankings(
+    query: &StreamRankingsReqQuery,
+    start_at: DateTime<Utc>,
+    end_at: DateTime<Utc>,
+    limit: i64,
+    pool: &PgPool,
+) -> anyhow::Result<Vec<StreamRanking>> {
+    let totals = rank_streams_by_revenue(
+        query.platform,
+        query.group_id.as_deref(),
+        start_at,
+        end_at,
+        limit,
+        pool,
+    )
+    .await?;
+
+    let stream_ids: Vec<_> = totals.iter().map(|t| t.stream_id).collect();
+    let mut streams = list_streams_by_ids(&stream_ids, pool).await?;
+
+    Ok(totals
+        .into_iter()
+        .filter_map(|total| {
+            let index = streams
+                .iter()
+                .position(|s| s.stream_id == total.stream_id)?;
+            Some(StreamRanking {
+                stream: streams.swap_remove(index),
+                value: total.value,
+            })
+        })
+        .collect())
+}
+
+pub(super) fn validate_limit(limit: Option<i64>) -> Option<i64> {
+    match limit {
+        None => Some(DEFAULT_LIMIT),
+        Some(l) if (1..=MAX_LIMIT).contains(&l) => Some(l),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_validate_limit() {
+    assert_eq!(validate_limit(None), Some(DEFAULT_LIMIT));
+    assert_eq!(validate_limit(Some(10)), Some(10));
+    assert_eq!(validate_limit(Some(0)), None);
+    assert_eq!(validate_limit(Some(MAX_LIMIT + 1)), None);
+}
@@ -0,0 +1,68 @@
+use axum::{
+    extract::{Query, State},
+    http::status::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tokio::try_join;
+
+use vtstats_database::{
+    search::{
+        expand_keyword, search_channels, search_groups, search_streams, search_vtubers,
+        ChannelSearchResult, GroupSearchResult, VTuberSearchResult,
+    },
+    streams::Stream,
+    PgPool,
+};
+
+use crate::error::ApiResult;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReqQuery {
+    q: String,
+    #[serde(default = "default_limit")]
+    limit: i64,
+}
+
+fn default_limit() -> i64 {
+    10
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResponse {
+    vtubers: Vec<VTuberSearchResult>,
+    groups: Vec<GroupSearchResult>,
+    channels: Vec<ChannelSearchResult>,
+    streams: Vec<Stream>,
+}
+
+pub async fn search(
+    Query(query): Query<ReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<Response> {
+    let keyword = query.q.trim();
+
+    if keyword.is_empty() || keyword.chars().count() > 100 || !(1..=50).contains(&query.limit) {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    }
+
+    let expanded = expand_keyword(keyword, &pool).await?;
+
+    let (vtubers, groups, channels, streams) = try_join!(
+        search_vtubers(&expanded, query.limit, &pool),
+        search_groups(&expanded, query.limit, &pool),
+        search_channels(keyword, query.limit, &pool),
+        search_streams(&expanded, query.limit, &pool),
+    )?;
+
+    Ok(Json(SearchResponse {
+        vtubers,
+        groups,
+        channels,
+        streams,
+    })
+    .into_response())
+}
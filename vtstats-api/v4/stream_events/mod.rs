@@ -1,3 +1,4 @@
+mod tier;
 mod types;
 
 use axum::{
@@ -5,22 +6,31 @@ use axum::{
     response::IntoResponse,
     Json,
 };
-use chrono::{serde::ts_milliseconds, DateTime, Utc};
+use chrono::{serde::ts_milliseconds, DateTime, Datelike, Utc};
 use serde::Serialize;
+use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
+use std::collections::{BTreeMap, HashMap};
 use tracing::Span;
 use vtstats_database::{
-    stream_events::{list_stream_events, StreamEventKind},
+    exchange_rates::{list_exchange_rates, list_exchange_rates_at},
+    stream_events::{list_stream_events, StreamEvent as StreamEventRow, StreamEventKind},
     PgPool,
 };
 
 use crate::error::ApiResult;
 
+use self::tier::SuperChatTier;
 use self::types::{refine, RefinedStreamEventValue};
 
+#[serde_as]
 #[derive(serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReqQuery {
     stream_id: i32,
+    /// only returns super chats and stickers of given tiers
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, SuperChatTier>")]
+    #[serde(default)]
+    tiers: Vec<SuperChatTier>,
 }
 
 #[derive(Debug, Serialize)]
@@ -37,6 +47,7 @@ pub async fn stream_events(
     State(pool): State<PgPool>,
 ) -> ApiResult<impl IntoResponse> {
     let events = list_stream_events(query.stream_id, &pool).await?;
+    let rates = list_monthly_exchange_rates(&events, &pool).await?;
 
     let events: Vec<_> = events
         .into_iter()
@@ -44,12 +55,82 @@ pub async fn stream_events(
             Some(StreamEvent {
                 time: event.time,
                 kind: event.kind,
-                value: refine(event.value)?,
+                value: refine(event.value, &rates[&month_of(event.time)])?,
             })
         })
+        .filter(|event| {
+            query.tiers.is_empty()
+                || matches!(event.value.tier(), Some(tier) if query.tiers.contains(&tier))
+        })
         .collect();
 
     Span::current().record("stream_id", query.stream_id);
 
     Ok(Json(events))
 }
+
+#[derive(Debug, Serialize)]
+pub struct TierCount {
+    pub tier: SuperChatTier,
+    pub count: usize,
+}
+
+/// counts super chats and stickers of each tier
+pub async fn stream_event_tiers(
+    Query(query): Query<ReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<impl IntoResponse> {
+    let events = list_stream_events(query.stream_id, &pool).await?;
+    let rates = list_monthly_exchange_rates(&events, &pool).await?;
+
+    let mut counts = BTreeMap::<SuperChatTier, usize>::new();
+
+    for tier in events
+        .into_iter()
+        .filter_map(|event| refine(event.value, &rates[&month_of(event.time)])?.tier())
+        .filter(|tier| query.tiers.is_empty() || query.tiers.contains(tier))
+    {
+        *counts.entry(tier).or_default() += 1;
+    }
+
+    Span::current().record("stream_id", query.stream_id);
+
+    Ok(Json(
+        counts
+            .into_iter()
+            .map(|(tier, count)| TierCount { tier, count })
+            .collect::<Vec<_>>(),
+    ))
+}
+
+fn month_of(time: DateTime<Utc>) -> (i32, u32) {
+    (time.year(), time.month())
+}
+
+/// exchange rates in force in each month of given events, falls back to
+/// current rates if the history doesn't cover a month
+async fn list_monthly_exchange_rates(
+    events: &[StreamEventRow],
+    pool: &PgPool,
+) -> ApiResult<HashMap<(i32, u32), HashMap<String, f32>>> {
+    let mut rates = HashMap::new();
+    let mut current = None;
+
+    for event in events {
+        let month = month_of(event.time);
+        if rates.contains_key(&month) {
+            continue;
+        }
+
+        let mut month_rates = list_exchange_rates_at(event.time, pool).await?;
+        if month_rates.is_empty() {
+            if current.is_none() {
+                current = Some(list_exchange_rates(pool).await?);
+            }
+            month_rates = current.clone().unwrap_or_default();
+        }
+        rates.insert(month, month_rates);
+    }
+
+    Ok(rates)
+}
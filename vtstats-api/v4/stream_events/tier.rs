@@ -0,0 +1,230 @@
+use std::{collections::HashMap, str::FromStr};
+
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use serde::{de::IntoDeserializer, Deserialize, Serialize};
+use vtstats_database::stream_events::Money;
+
+/// https://support.google.com/youtube/answer/9277801
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SuperChatTier {
+    Blue,
+    LightBlue,
+    Green,
+    Yellow,
+    Orange,
+    Magenta,
+    Red,
+}
+
+impl FromStr for SuperChatTier {
+    type Err = serde::de::value::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+
+const TIERS: [SuperChatTier; 7] = [
+    SuperChatTier::Blue,
+    SuperChatTier::LightBlue,
+    SuperChatTier::Green,
+    SuperChatTier::Yellow,
+    SuperChatTier::Orange,
+    SuperChatTier::Magenta,
+    SuperChatTier::Red,
+];
+
+/// minimum amount of each tier in currencies whose prices YouTube fixes
+/// locally instead of converting, USD ones are from the help center page
+/// above and the others from the purchase dialog of each region. other
+/// currencies are converted to USD with the exchange rates of the event's
+/// month and use its minimums. when `color_matches_amount` starts reporting
+/// mismatches for a listed currency, its prices have changed
+const THRESHOLDS: &[(&str, [i64; 7])] = &[
+    ("USD", [1, 2, 5, 10, 20, 50, 100]),
+    ("EUR", [1, 2, 5, 10, 20, 50, 100]),
+    ("GBP", [1, 2, 5, 10, 20, 50, 100]),
+    ("JPY", [100, 200, 500, 1000, 2000, 5000, 10000]),
+    ("KRW", [1000, 2000, 5000, 10000, 20000, 50000, 100000]),
+    ("TWD", [15, 30, 75, 150, 300, 750, 1500]),
+];
+
+const USD_THRESHOLDS: [i64; 7] = [1, 2, 5, 10, 20, 50, 100];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuperChatTierDetail {
+    pub tier: SuperChatTier,
+    pub pinned_seconds: u32,
+    pub max_characters: u32,
+}
+
+impl SuperChatTier {
+    /// `red_level` is 1 to 5, as red super chats are pinned
+    /// one more hour per multiple of the red minimum
+    fn detail(self, red_level: u32) -> SuperChatTierDetail {
+        let (pinned_seconds, max_characters) = match self {
+            SuperChatTier::Blue => (0, 0),
+            SuperChatTier::LightBlue => (0, 50),
+            SuperChatTier::Green => (2 * 60, 150),
+            SuperChatTier::Yellow => (5 * 60, 200),
+            SuperChatTier::Orange => (10 * 60, 225),
+            SuperChatTier::Magenta => (30 * 60, 250),
+            SuperChatTier::Red => (red_level * 60 * 60, 250 + red_level * 20),
+        };
+
+        SuperChatTierDetail {
+            tier: self,
+            pinned_seconds,
+            max_characters,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InferredTier {
+    pub detail: Option<SuperChatTierDetail>,
+    /// whether chat color agrees with the tier of the amount, `None` if
+    /// either is unknown or the amount is only converted
+    pub color_matches_amount: Option<bool>,
+}
+
+/// infers tier from amount if its currency is listed in `THRESHOLDS`,
+/// otherwise from chat color, and finally from the amount converted to
+/// USD. converted amounts are approximate as YouTube rounds the minimums
+/// of each currency, so they only serve when the color is unknown
+pub fn infer_tier(
+    money: Option<&Money>,
+    color: Option<SuperChatTier>,
+    rates: &HashMap<String, f32>,
+) -> InferredTier {
+    let exact = money.and_then(|money| {
+        let (_, thresholds) = THRESHOLDS
+            .iter()
+            .find(|(c, _)| *c == money.currency.as_str())?;
+        Some((money.amount, thresholds))
+    });
+
+    if let Some((amount, thresholds)) = exact {
+        if let Some((tier, red_level)) = tier_of(amount, thresholds) {
+            return InferredTier {
+                detail: Some(tier.detail(red_level)),
+                color_matches_amount: color.map(|color| color == tier),
+            };
+        }
+    }
+
+    let converted = money
+        .filter(|_| exact.is_none())
+        .and_then(|money| to_usd(money, rates))
+        .and_then(|amount| tier_of(amount, &USD_THRESHOLDS));
+
+    let detail = match (color, converted) {
+        (Some(SuperChatTier::Red), Some((SuperChatTier::Red, red_level))) => {
+            Some(SuperChatTier::Red.detail(red_level))
+        }
+        (Some(color), _) => Some(color.detail(1)),
+        (None, Some((tier, red_level))) => Some(tier.detail(red_level)),
+        (None, None) => None,
+    };
+
+    InferredTier {
+        detail,
+        color_matches_amount: None,
+    }
+}
+
+/// returns the highest tier whose minimum is reached, and red level
+fn tier_of(amount: Decimal, thresholds: &[i64; 7]) -> Option<(SuperChatTier, u32)> {
+    let index = thresholds
+        .iter()
+        .rposition(|min| amount >= Decimal::from(*min))?;
+
+    let red_level = (amount / Decimal::from(thresholds[6]))
+        .floor()
+        .clamp(Decimal::ONE, Decimal::from(5))
+        .try_into()
+        .unwrap_or(1);
+
+    Some((TIERS[index], red_level))
+}
+
+/// exchange rates are based on EUR
+fn to_usd(money: &Money, rates: &HashMap<String, f32>) -> Option<Decimal> {
+    let rate = |c: &str| match c {
+        "EUR" => Some(Decimal::ONE),
+        _ => rates
+            .get(c)
+            .and_then(|r| Decimal::from_f32(*r))
+            .filter(|r| *r > Decimal::ZERO),
+    };
+
+    Some(money.amount / rate(money.currency.as_str())? * rate("USD")?)
+}
+
+#[test]
+fn test_infer_tier() {
+    use vtstats_utils::currency::CurrencyCode;
+
+    let rates = HashMap::from([("USD".to_string(), 1.1), ("PHP".to_string(), 60.5)]);
+
+    let money = |amount: i64, currency: &str| Money {
+        amount: Decimal::from(amount),
+        currency: CurrencyCode::new(currency).unwrap(),
+        ambiguous: false,
+    };
+    let tier = |m: Option<Money>, color: Option<SuperChatTier>| {
+        let inferred = infer_tier(m.as_ref(), color, &rates);
+        (
+            inferred
+                .detail
+                .map(|d| (d.tier, d.pinned_seconds, d.max_characters)),
+            inferred.color_matches_amount,
+        )
+    };
+
+    assert_eq!(
+        tier(Some(money(1, "USD")), None),
+        (Some((SuperChatTier::Blue, 0, 0)), None)
+    );
+    assert_eq!(
+        tier(Some(money(10, "USD")), Some(SuperChatTier::Blue)),
+        (Some((SuperChatTier::Yellow, 300, 200)), Some(false))
+    );
+    assert_eq!(
+        tier(Some(money(1000, "JPY")), Some(SuperChatTier::Yellow)),
+        (Some((SuperChatTier::Yellow, 300, 200)), Some(true))
+    );
+    assert_eq!(
+        tier(Some(money(300, "EUR")), None),
+        (Some((SuperChatTier::Red, 3 * 3600, 310)), None)
+    );
+    assert_eq!(
+        tier(Some(money(50000, "JPY")), None),
+        (Some((SuperChatTier::Red, 5 * 3600, 350)), None)
+    );
+    assert_eq!(tier(Some(money(50, "JPY")), None), (None, None));
+
+    // 550 PHP is 10 USD
+    assert_eq!(
+        tier(Some(money(550, "PHP")), None),
+        (Some((SuperChatTier::Yellow, 300, 200)), None)
+    );
+    assert_eq!(
+        tier(Some(money(550, "PHP")), Some(SuperChatTier::Green)),
+        (Some((SuperChatTier::Green, 120, 150)), None)
+    );
+    // 16500 PHP is 300 USD
+    assert_eq!(
+        tier(Some(money(16500, "PHP")), Some(SuperChatTier::Red)),
+        (Some((SuperChatTier::Red, 3 * 3600, 310)), None)
+    );
+    assert_eq!(tier(Some(money(100, "INR")), None), (None, None));
+
+    assert_eq!(
+        tier(None, Some(SuperChatTier::Magenta)),
+        (Some((SuperChatTier::Magenta, 1800, 250)), None)
+    );
+    assert_eq!(tier(None, None), (None, None));
+}
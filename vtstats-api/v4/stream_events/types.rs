@@ -1,24 +1,44 @@
 use serde::Serialize;
-use vtstats_database::stream_events::StreamEventValue;
-use vtstats_utils::currency::currency_symbol_to_code;
+use std::collections::HashMap;
+use vtstats_database::stream_events::{Money, StreamEventValue};
+
+use super::tier::{infer_tier, InferredTier, SuperChatTier, SuperChatTierDetail};
 
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub enum RefinedStreamEventValue {
     #[serde(rename_all = "camelCase")]
     YouTubeSuperChat {
+        /// raw amount and symbol if the currency is unresolved
         amount: String,
         currency_code: String,
+        currency_resolved: bool,
+        /// currency is guessed from a symbol shared by several ones
+        currency_ambiguous: bool,
         color: YouTubeChatColor,
+        #[serde(flatten)]
+        tier: Option<SuperChatTierDetail>,
+        color_matches_amount: Option<bool>,
     },
     #[serde(rename_all = "camelCase")]
     YouTubeSuperSticker {
+        /// raw amount and symbol if the currency is unresolved
         amount: String,
         currency_code: String,
+        currency_resolved: bool,
+        /// currency is guessed from a symbol shared by several ones
+        currency_ambiguous: bool,
         color: YouTubeChatColor,
+        #[serde(flatten)]
+        tier: Option<SuperChatTierDetail>,
+        color_matches_amount: Option<bool>,
     },
     YouTubeNewMember,
     YouTubeMemberMilestone,
+    YouTubeMembershipGift {
+        count: i64,
+    },
+    YouTubeMembershipGiftRedemption,
     TwitchCheering {
         bits: usize,
     },
@@ -26,6 +46,35 @@ pub enum RefinedStreamEventValue {
         amount: String,
         currency_code: String,
     },
+    BilibiliSuperChat {
+        price: i64,
+    },
+    #[serde(rename_all = "camelCase")]
+    BilibiliGift {
+        gift_name: String,
+        num: i64,
+        total_coin: i64,
+    },
+    #[serde(rename_all = "camelCase")]
+    BilibiliGuard {
+        guard_level: i32,
+        num: i64,
+        price: i64,
+    },
+    #[serde(rename_all = "camelCase")]
+    TwitchSubscription {
+        plan: String,
+        cumulative_months: usize,
+    },
+    TwitchGiftSubscription {
+        plan: String,
+        count: i64,
+    },
+    #[serde(rename_all = "camelCase")]
+    TwitchRaid {
+        from_username: String,
+        viewer_count: i64,
+    },
 }
 
 impl RefinedStreamEventValue {
@@ -35,34 +84,104 @@ impl RefinedStreamEventValue {
             self,
             RefinedStreamEventValue::YouTubeMemberMilestone
                 | RefinedStreamEventValue::YouTubeNewMember
+                | RefinedStreamEventValue::YouTubeMembershipGiftRedemption
         )
     }
+
+    pub fn tier(&self) -> Option<SuperChatTier> {
+        match self {
+            RefinedStreamEventValue::YouTubeSuperChat { tier, .. }
+            | RefinedStreamEventValue::YouTubeSuperSticker { tier, .. } => tier.map(|t| t.tier),
+            _ => None,
+        }
+    }
 }
 
-pub fn refine(value: StreamEventValue) -> Option<RefinedStreamEventValue> {
+pub fn refine(
+    value: StreamEventValue,
+    rates: &HashMap<String, f32>,
+) -> Option<RefinedStreamEventValue> {
     match value {
-        StreamEventValue::YoutubeSuperChat(v) => Some(RefinedStreamEventValue::YouTubeSuperChat {
-            amount: v.paid_amount,
-            currency_code: currency_symbol_to_code(&v.paid_currency_symbol)?.into(),
-            color: color_hex_to_chat_color(&v.paid_color)?,
-        }),
+        StreamEventValue::YoutubeSuperChat(v) => {
+            let (color, tier) = color_and_tier(&v.paid_color, v.money.as_ref(), rates);
+            let (amount, currency_code) = match &v.money {
+                Some(m) => (m.amount.to_string(), m.currency.to_string()),
+                None => (v.paid_amount, v.paid_currency_symbol),
+            };
+            Some(RefinedStreamEventValue::YouTubeSuperChat {
+                amount,
+                currency_code,
+                currency_resolved: v.money.is_some(),
+                currency_ambiguous: v.money.as_ref().is_some_and(|m| m.ambiguous),
+                color,
+                tier: tier.detail,
+                color_matches_amount: tier.color_matches_amount,
+            })
+        }
         StreamEventValue::YoutubeSuperSticker(v) => {
+            let (color, tier) = color_and_tier(&v.paid_color, v.money.as_ref(), rates);
+            let (amount, currency_code) = match &v.money {
+                Some(m) => (m.amount.to_string(), m.currency.to_string()),
+                None => (v.paid_amount, v.paid_currency_symbol),
+            };
             Some(RefinedStreamEventValue::YouTubeSuperSticker {
-                amount: v.paid_amount,
-                currency_code: currency_symbol_to_code(&v.paid_currency_symbol)?.into(),
-                color: color_hex_to_chat_color(&v.paid_color)?,
+                amount,
+                currency_code,
+                currency_resolved: v.money.is_some(),
+                currency_ambiguous: v.money.as_ref().is_some_and(|m| m.ambiguous),
+                color,
+                tier: tier.detail,
+                color_matches_amount: tier.color_matches_amount,
             })
         }
         StreamEventValue::YoutubeNewMember(_) => Some(RefinedStreamEventValue::YouTubeNewMember),
         StreamEventValue::YoutubeMemberMilestone(_) => {
             Some(RefinedStreamEventValue::YouTubeMemberMilestone)
         }
+        StreamEventValue::YoutubeMembershipGift(v) => {
+            Some(RefinedStreamEventValue::YouTubeMembershipGift { count: v.count })
+        }
+        StreamEventValue::YoutubeMembershipGiftRedemption(_) => {
+            Some(RefinedStreamEventValue::YouTubeMembershipGiftRedemption)
+        }
         StreamEventValue::TwitchCheering(v) => Some(RefinedStreamEventValue::TwitchCheering {
             bits: v.bits.parse().ok()?,
         }),
-        StreamEventValue::TwitchHyperChat(v) => Some(RefinedStreamEventValue::TwitchHyperChat {
-            amount: v.amount,
-            currency_code: v.currency_code,
+        StreamEventValue::TwitchHyperChat(v) => {
+            let money = v.money?;
+            Some(RefinedStreamEventValue::TwitchHyperChat {
+                amount: money.amount.to_string(),
+                currency_code: money.currency.to_string(),
+            })
+        }
+        StreamEventValue::BilibiliSuperChat(v) => {
+            Some(RefinedStreamEventValue::BilibiliSuperChat { price: v.price })
+        }
+        StreamEventValue::BilibiliGift(v) => Some(RefinedStreamEventValue::BilibiliGift {
+            gift_name: v.gift_name,
+            num: v.num,
+            total_coin: v.total_coin,
+        }),
+        StreamEventValue::BilibiliGuard(v) => Some(RefinedStreamEventValue::BilibiliGuard {
+            guard_level: v.guard_level,
+            num: v.num,
+            price: v.price,
+        }),
+        StreamEventValue::TwitchSubscription(v) => {
+            Some(RefinedStreamEventValue::TwitchSubscription {
+                plan: v.plan,
+                cumulative_months: v.cumulative_months.parse().ok()?,
+            })
+        }
+        StreamEventValue::TwitchGiftSubscription(v) => {
+            Some(RefinedStreamEventValue::TwitchGiftSubscription {
+                plan: v.plan,
+                count: v.count,
+            })
+        }
+        StreamEventValue::TwitchRaid(v) => Some(RefinedStreamEventValue::TwitchRaid {
+            from_username: v.from_username,
+            viewer_count: v.viewer_count,
         }),
     }
 }
@@ -77,17 +196,49 @@ pub enum YouTubeChatColor {
     Orange,
     Magenta,
     Red,
+    Unknown,
+}
+
+impl From<SuperChatTier> for YouTubeChatColor {
+    fn from(tier: SuperChatTier) -> Self {
+        match tier {
+            SuperChatTier::Blue => YouTubeChatColor::Blue,
+            SuperChatTier::LightBlue => YouTubeChatColor::LightBlue,
+            SuperChatTier::Green => YouTubeChatColor::Green,
+            SuperChatTier::Yellow => YouTubeChatColor::Yellow,
+            SuperChatTier::Orange => YouTubeChatColor::Orange,
+            SuperChatTier::Magenta => YouTubeChatColor::Magenta,
+            SuperChatTier::Red => YouTubeChatColor::Red,
+        }
+    }
 }
 
-fn color_hex_to_chat_color(i: &str) -> Option<YouTubeChatColor> {
+fn color_hex_to_tier(i: &str) -> Option<SuperChatTier> {
     match i {
-        "1DE9B6FF" => Some(YouTubeChatColor::Green),
-        "FFCA28FF" => Some(YouTubeChatColor::Yellow),
-        "1E88E5FF" => Some(YouTubeChatColor::Blue),
-        "00E5FFFF" => Some(YouTubeChatColor::LightBlue),
-        "F57C00FF" => Some(YouTubeChatColor::Orange),
-        "E91E63FF" => Some(YouTubeChatColor::Magenta),
-        "E62117FF" => Some(YouTubeChatColor::Red),
+        "1DE9B6FF" => Some(SuperChatTier::Green),
+        "FFCA28FF" => Some(SuperChatTier::Yellow),
+        "1E88E5FF" => Some(SuperChatTier::Blue),
+        "00E5FFFF" => Some(SuperChatTier::LightBlue),
+        "F57C00FF" => Some(SuperChatTier::Orange),
+        "E91E63FF" => Some(SuperChatTier::Magenta),
+        "E62117FF" => Some(SuperChatTier::Red),
         _ => None,
     }
 }
+
+/// unknown colors fall back to the color of inferred tier
+fn color_and_tier(
+    hex: &str,
+    money: Option<&Money>,
+    rates: &HashMap<String, f32>,
+) -> (YouTubeChatColor, InferredTier) {
+    let color = color_hex_to_tier(hex);
+    let tier = infer_tier(money, color, rates);
+
+    let color = color
+        .or(tier.detail.map(|t| t.tier))
+        .map(YouTubeChatColor::from)
+        .unwrap_or(YouTubeChatColor::Unknown);
+
+    (color, tier)
+}
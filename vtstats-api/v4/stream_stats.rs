@@ -1,5 +1,6 @@
 use axum::{
     extract::{Query, State},
+    http::StatusCode,
     response::IntoResponse,
     Json,
 };
@@ -25,13 +26,192 @@ pub async fn stream_viewer_stats(
     Ok(Json(stats))
 }
 
+#[derive(serde::Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum ChatBreakdown {
+    Language,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatReqQuery {
+    #[serde(default)]
+    stream_id: Option<i32>,
+    /// only used with `breakdown`
+    #[serde(default)]
+    channel_id: Option<i32>,
+    #[serde(default)]
+    breakdown: Option<ChatBreakdown>,
+}
+
+/// returns chat stats of a stream, or message count of each language
+/// of a stream or a channel if `breakdown=language` is given
 pub async fn stream_chat_stats(
-    Query(query): Query<ReqQuery>,
+    Query(query): Query<ChatReqQuery>,
     State(pool): State<PgPool>,
 ) -> ApiResult<impl IntoResponse> {
-    let stats = db::stream_chat_stats(query.stream_id, &pool).await?;
+    if let Some(stream_id) = query.stream_id {
+        Span::current().record("stream_id", stream_id);
+    }
+
+    match (query.breakdown, query.stream_id, query.channel_id) {
+        (None, Some(stream_id), _) => {
+            let stats = db::stream_chat_stats(stream_id, &pool).await?;
+            Ok(Json(stats).into_response())
+        }
+        (Some(ChatBreakdown::Language), Some(stream_id), _) => {
+            let languages = db::stream_chat_languages(stream_id, &pool).await?;
+            Ok(Json(languages).into_response())
+        }
+        (Some(ChatBreakdown::Language), None, Some(channel_id)) => {
+            let languages = db::channel_chat_languages(channel_id, &pool).await?;
+            Ok(Json(languages).into_response())
+        }
+        _ => Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response()),
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudienceReqQuery {
+    stream_id: i32,
+    #[serde(default = "default_chatter_limit")]
+    limit: i64,
+}
+
+fn default_chatter_limit() -> i64 {
+    20
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamAudience {
+    #[serde(flatten)]
+    audience: db::StreamAudience,
+    top_chatters: Vec<db::StreamChatter>,
+}
+
+pub async fn stream_audience(
+    Query(query): Query<AudienceReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<impl IntoResponse> {
+    if !(1..=100).contains(&query.limit) {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    }
+
+    let audience = db::stream_audience(query.stream_id, &pool).await?;
+    let top_chatters = db::list_stream_chatters(query.stream_id, query.limit, &pool).await?;
 
     Span::current().record("stream_id", query.stream_id);
 
-    Ok(Json(stats))
+    Ok(Json(StreamAudience {
+        audience,
+        top_chatters,
+    })
+    .into_response())
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatTermsReqQuery {
+    stream_id: i32,
+    #[serde(default)]
+    kind: Option<db::ChatTermKind>,
+    #[serde(default = "default_term_limit")]
+    limit: i64,
+}
+
+fn default_term_limit() -> i64 {
+    10
+}
+
+#[derive(serde::Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatTerm {
+    kind: db::ChatTermKind,
+    term: String,
+    total: i64,
+    /// time and count of each bucket
+    stats: Vec<(i64, i32)>,
+}
+
+pub async fn stream_chat_terms(
+    Query(query): Query<ChatTermsReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<impl IntoResponse> {
+    if !(1..=50).contains(&query.limit) {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    }
+
+    let rows = db::stream_chat_terms(query.stream_id, query.kind, query.limit, &pool).await?;
+
+    Span::current().record("stream_id", query.stream_id);
+
+    Ok(Json(group_chat_terms(rows)).into_response())
+}
+
+/// groups rows by term, rows of the same term are adjacent
+fn group_chat_terms(rows: Vec<db::StreamChatTerm>) -> Vec<ChatTerm> {
+    let mut terms = Vec::<ChatTerm>::new();
+
+    for row in rows {
+        match terms.last_mut() {
+            Some(term) if term.kind == row.kind && term.term == row.term => {
+                term.stats.push((row.time, row.count));
+            }
+            _ => terms.push(ChatTerm {
+                kind: row.kind,
+                term: row.term,
+                total: row.total,
+                stats: vec![(row.time, row.count)],
+            }),
+        }
+    }
+
+    terms
+}
+
+#[test]
+fn test_group_chat_terms() {
+    let rows = vec![
+        db::StreamChatTerm {
+            time: 0,
+            kind: db::ChatTermKind::Keyword,
+            term: "草".into(),
+            count: 2,
+            total: 6,
+        },
+        db::StreamChatTerm {
+            time: 15_000,
+            kind: db::ChatTermKind::Keyword,
+            term: "草".into(),
+            count: 4,
+            total: 6,
+        },
+        db::StreamChatTerm {
+            time: 0,
+            kind: db::ChatTermKind::Emote,
+            term: "草".into(),
+            count: 3,
+            total: 3,
+        },
+    ];
+
+    assert_eq!(
+        group_chat_terms(rows),
+        vec![
+            ChatTerm {
+                kind: db::ChatTermKind::Keyword,
+                term: "草".into(),
+                total: 6,
+                stats: vec![(0, 2), (15_000, 4)],
+            },
+            ChatTerm {
+                kind: db::ChatTermKind::Emote,
+                term: "草".into(),
+                total: 3,
+                stats: vec![(0, 3)],
+            },
+        ]
+    );
 }
@@ -1,4 +1,4 @@
-use axum::extract::{Query, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
@@ -9,7 +9,8 @@ use tracing::Span;
 use vtstats_database::channels::Platform;
 use vtstats_database::streams::{
     filter_streams_order_by_schedule_time_asc, filter_streams_order_by_start_time_desc,
-    get_stream_by_id, get_stream_by_platform_id, StreamStatus,
+    get_stream_by_id, get_stream_by_platform_id, list_stream_timeline, StreamAccess, StreamKind,
+    StreamKindFilter, StreamStatus,
 };
 use vtstats_database::PgPool;
 
@@ -27,6 +28,21 @@ pub struct ListReqQuery {
     pub end_at: Option<DateTime<Utc>>,
     #[serde(default)]
     pub keyword: Option<String>,
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, StreamKind>")]
+    #[serde(default)]
+    pub kinds: Vec<StreamKind>,
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, StreamAccess>")]
+    #[serde(default)]
+    pub access: Vec<StreamAccess>,
+}
+
+impl ListReqQuery {
+    fn filter(&self) -> StreamKindFilter<'_> {
+        StreamKindFilter {
+            kinds: &self.kinds,
+            access: &self.access,
+        }
+    }
 }
 
 #[derive(serde::Deserialize)]
@@ -59,6 +75,18 @@ pub async fn find_stream_by_id(
     Ok(Json(stream).into_response())
 }
 
+/// title and category changes of given stream, ordered by time
+pub async fn stream_timeline(
+    Path(stream_id): Path<i32>,
+    State(pool): State<PgPool>,
+) -> ApiResult<impl IntoResponse> {
+    let timeline = list_stream_timeline(stream_id, &pool).await?;
+
+    Span::current().record("stream_id", stream_id);
+
+    Ok(Json(timeline))
+}
+
 pub async fn list_scheduled_streams(
     Query(query): Query<ListReqQuery>,
     State(pool): State<PgPool>,
@@ -68,6 +96,7 @@ pub async fn list_scheduled_streams(
         StreamStatus::Scheduled,
         query.start_at,
         query.end_at,
+        query.filter(),
         pool,
     )
     .await?;
@@ -90,6 +119,7 @@ pub async fn list_live_streams(
         StreamStatus::Live,
         query.start_at,
         query.end_at,
+        query.filter(),
         keyword,
         pool,
     )
@@ -113,6 +143,7 @@ pub async fn list_ended_streams(
         StreamStatus::Ended,
         query.start_at,
         query.end_at,
+        query.filter(),
         keyword,
         pool,
     )
@@ -0,0 +1,149 @@
+use axum::{
+    extract::{Query, State},
+    http::status::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{serde::ts_milliseconds, DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::Span;
+
+use vtstats_database::{stream_events::list_supporters, PgPool};
+
+use crate::error::ApiResult;
+
+use super::rankings::validate_limit;
+
+#[derive(Deserialize, Clone, Copy, Default)]
+pub enum SupporterPeriod {
+    #[serde(rename = "7d")]
+    Week,
+    #[default]
+    #[serde(rename = "30d")]
+    Month,
+    #[serde(rename = "90d")]
+    Quarter,
+    #[serde(rename = "365d")]
+    Year,
+    #[serde(rename = "all")]
+    All,
+}
+
+impl SupporterPeriod {
+    fn start_at(self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let days = match self {
+            SupporterPeriod::Week => 7,
+            SupporterPeriod::Month => 30,
+            SupporterPeriod::Quarter => 90,
+            SupporterPeriod::Year => 365,
+            SupporterPeriod::All => return None,
+        };
+
+        Some(now - Duration::days(days))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupportersReqQuery {
+    #[serde(default)]
+    channel_id: Option<i32>,
+    #[serde(default)]
+    stream_id: Option<i32>,
+    /// ignored if `stream_id` is given
+    #[serde(default)]
+    period: SupporterPeriod,
+    #[serde(default)]
+    limit: Option<i64>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Supporter {
+    author_id: String,
+    author_name: String,
+    /// total amount in USD
+    value: f64,
+    /// number of paid events, including ones in unknown currencies
+    count: i64,
+    /// longest membership or subscription seen, in months
+    tenure_months: Option<i64>,
+    #[serde(with = "ts_milliseconds")]
+    last_supported_at: DateTime<Utc>,
+}
+
+pub async fn supporters(
+    Query(query): Query<SupportersReqQuery>,
+    State(pool): State<PgPool>,
+) -> ApiResult<Response> {
+    if query.channel_id.is_none() && query.stream_id.is_none() {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    }
+
+    let Some(limit) = validate_limit(query.limit) else {
+        return Ok(StatusCode::UNPROCESSABLE_ENTITY.into_response());
+    };
+
+    let start_at = match query.stream_id {
+        Some(stream_id) => {
+            Span::current().record("stream_id", stream_id);
+            None
+        }
+        None => query.period.start_at(Utc::now()),
+    };
+
+    let supporters = list_supporters(query.channel_id, query.stream_id, start_at, limit, &pool)
+        .await?
+        .into_iter()
+        .map(|row| Supporter {
+            tenure_months: row
+                .badges
+                .iter()
+                .filter_map(|b| parse_tenure_months(b))
+                .chain(row.cumulative_months.map(i64::from))
+                .max(),
+            author_id: row.author_id,
+            author_name: row.author_name.unwrap_or_default(),
+            value: row.value,
+            count: row.count,
+            last_supported_at: row.last_supported_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Json(supporters).into_response())
+}
+
+/// parses membership duration from youtube badges, e.g. `Member (6 months)`
+/// or `Member (2 years)`, new members are counted as zero months
+///
+/// badges are localized by youtube, only english ones are recognized, which
+/// is what our chat collectors request
+fn parse_tenure_months(badges: &str) -> Option<i64> {
+    badges.split(',').find_map(|badge| {
+        let badge = badge.trim();
+
+        if badge.eq_ignore_ascii_case("new member") {
+            return Some(0);
+        }
+
+        let (_, duration) = badge.split_once('(')?;
+        let (number, unit) = duration.trim_end_matches(')').split_once(' ')?;
+        let number: i64 = number.parse().ok()?;
+
+        match unit {
+            "month" | "months" => Some(number),
+            "year" | "years" => Some(number * 12),
+            _ => None,
+        }
+    })
+}
+
+#[test]
+fn test_parse_tenure_months() {
+    assert_eq!(parse_tenure_months("Member (6 months)"), Some(6));
+    assert_eq!(parse_tenure_months("Member (1 month)"), Some(1));
+    assert_eq!(parse_tenure_months("Moderator,Member (2 years)"), Some(24));
+    assert_eq!(parse_tenure_months("New member"), Some(0));
+    assert_eq!(parse_tenure_months("Moderator"), None);
+    assert_eq!(parse_tenure_months("Member (a while)"), None);
+}
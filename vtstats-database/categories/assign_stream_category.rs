@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Result};
+
+/// tags stream with given category since `time`, skipped if the stream
+/// is already in that category at that time
+pub async fn assign_stream_category(
+    stream_id: i32,
+    category_id: i32,
+    time: DateTime<Utc>,
+    pool: &PgPool,
+) -> Result<()> {
+    let query = sqlx::query!(
+        r#"
+INSERT INTO stream_categories (stream_id, category_id, time)
+     SELECT $1::int4, $2::int4, $3::timestamptz
+      WHERE $2 IS DISTINCT FROM (
+                SELECT category_id
+                  FROM stream_categories
+                 WHERE stream_id = $1
+                   AND time < $3
+              ORDER BY time DESC
+                 LIMIT 1
+            )
+ON CONFLICT (stream_id, time) DO UPDATE
+        SET category_id = $2
+        "#,
+        stream_id,
+        category_id,
+        time,
+    )
+    .execute(pool);
+
+    crate::otel::execute_query!("INSERT", "stream_categories", query)?;
+
+    Ok(())
+}
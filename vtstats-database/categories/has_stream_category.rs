@@ -0,0 +1,14 @@
+use sqlx::{PgPool, Result};
+
+/// whether stream has been tagged with any category
+pub async fn has_stream_category(stream_id: i32, pool: &PgPool) -> Result<bool> {
+    let query = sqlx::query!(
+        "SELECT EXISTS (SELECT 1 FROM stream_categories WHERE stream_id = $1) as \"exists!\"",
+        stream_id,
+    )
+    .fetch_one(pool);
+
+    let record = crate::otel::execute_query!("SELECT", "stream_categories", query)?;
+
+    Ok(record.exists)
+}
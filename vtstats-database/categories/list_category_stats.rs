@@ -0,0 +1,210 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{PgPool, Result};
+
+use crate::channels::Platform;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryStats {
+    pub category_id: i32,
+    pub platform: Platform,
+    pub name: String,
+    pub stream_count: i64,
+    pub hours_streamed: f64,
+    pub viewer_avg: Option<f64>,
+    pub top_vtuber_ids: Vec<String>,
+}
+
+/// aggregates streams started in given time window by category, ordered
+/// by hours streamed descending. a stream stays in a category from its
+/// assignment until the next one or the end of stream.
+pub struct ListCategoryStatsQuery {
+    pub platform: Option<Platform>,
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+    pub limit: i64,
+}
+
+impl ListCategoryStatsQuery {
+    pub async fn execute(self, pool: &PgPool) -> Result<Vec<CategoryStats>> {
+        let query = sqlx::query_as!(
+            CategoryStats,
+            r#"
+WITH segments AS (
+    SELECT sc.category_id,
+           s.stream_id,
+           s.vtuber_id,
+           greatest(sc.time, s.start_time) AS start_time,
+           coalesce(
+               lead(sc.time) OVER (PARTITION BY sc.stream_id ORDER BY sc.time),
+               s.end_time,
+               NOW()
+           ) AS end_time
+      FROM stream_categories sc
+      JOIN streams s ON s.stream_id = sc.stream_id
+     WHERE (s.platform = $1 OR $1 IS NULL)
+       AND s.start_time >= $2
+       AND s.start_time < $3
+), durations AS (
+    SELECT category_id,
+           vtuber_id,
+           stream_id,
+           greatest(extract(epoch FROM end_time - start_time), 0)::float8 AS seconds
+      FROM segments
+), vtubers AS (
+    SELECT category_id, vtuber_id, sum(seconds) AS seconds
+      FROM durations
+  GROUP BY category_id, vtuber_id
+), viewers AS (
+    SELECT seg.category_id, avg(v.count)::float8 AS viewer_avg
+      FROM segments seg
+      JOIN stream_viewer_stats v ON v.stream_id = seg.stream_id
+                                AND v.time >= seg.start_time
+                                AND v.time < seg.end_time
+  GROUP BY seg.category_id
+)
+    SELECT c.category_id,
+           c.platform AS "platform: _",
+           c.name,
+           d.stream_count AS "stream_count!",
+           d.seconds / 3600 AS "hours_streamed!",
+           v.viewer_avg,
+           (
+               SELECT (array_agg(vt.vtuber_id ORDER BY vt.seconds DESC))[1:3]
+                 FROM vtubers vt
+                WHERE vt.category_id = c.category_id
+           ) AS "top_vtuber_ids!"
+      FROM (
+               SELECT category_id, count(DISTINCT stream_id) AS stream_count, sum(seconds) AS seconds
+                 FROM durations
+             GROUP BY category_id
+           ) d
+      JOIN categories c ON c.category_id = d.category_id
+ LEFT JOIN viewers v ON v.category_id = d.category_id
+  ORDER BY d.seconds DESC
+     LIMIT $4
+            "#,
+            self.platform as _, // $1
+            self.start_at,      // $2
+            self.end_at,        // $3
+            self.limit,         // $4
+        )
+        .fetch_all(pool);
+
+        crate::otel::execute_query!("SELECT", "stream_categories", query)
+    }
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::{Duration, TimeZone};
+
+    use super::{assign_stream_category, UpsertCategoryQuery};
+
+    sqlx::query!(
+        r#"
+INSERT INTO streams (stream_id, vtuber_id, title, channel_id, platform_id, platform, start_time, end_time, status)
+     VALUES (1, 'vtuber1', 'title1', 1, 'id1', 'twitch', to_timestamp(0), to_timestamp(7200), 'ended'),
+            (2, 'vtuber2', 'title2', 2, 'id2', 'twitch', to_timestamp(3600), to_timestamp(7200), 'ended'),
+            (3, 'vtuber3', 'title3', 3, 'id3', 'youtube', to_timestamp(0), to_timestamp(3600), 'ended')
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    sqlx::query!(
+        r#"
+INSERT INTO stream_viewer_stats (stream_id, time, count)
+     VALUES (1, to_timestamp(0), 100),
+            (1, to_timestamp(3600), 300),
+            (2, to_timestamp(3600), 200)
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    let chatting = UpsertCategoryQuery {
+        platform: Platform::Twitch,
+        platform_category_id: "509658",
+        name: "Just Chatting",
+    }
+    .execute(&pool)
+    .await?;
+    let minecraft = UpsertCategoryQuery {
+        platform: Platform::Twitch,
+        platform_category_id: "27471",
+        name: "Minecraft",
+    }
+    .execute(&pool)
+    .await?;
+    let youtube_minecraft = UpsertCategoryQuery {
+        platform: Platform::Youtube,
+        platform_category_id: "UCQvWX73GQygcwXOTSf_VDVg",
+        name: "Minecraft",
+    }
+    .execute(&pool)
+    .await?;
+
+    assert_eq!(
+        UpsertCategoryQuery {
+            platform: Platform::Twitch,
+            platform_category_id: "27471",
+            name: "Minecraft",
+        }
+        .execute(&pool)
+        .await?,
+        minecraft
+    );
+
+    let time = |s| Utc.timestamp_opt(s, 0).single().unwrap();
+
+    // assigned before stream started
+    assign_stream_category(1, chatting, time(-600), &pool).await?;
+    assign_stream_category(1, minecraft, time(1800), &pool).await?;
+    // already in minecraft, skipped
+    assign_stream_category(1, minecraft, time(2400), &pool).await?;
+    assign_stream_category(2, minecraft, time(3600), &pool).await?;
+    assign_stream_category(3, youtube_minecraft, time(0), &pool).await?;
+
+    let count = sqlx::query_scalar!("SELECT count(*) FROM stream_categories WHERE stream_id = 1")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, Some(2));
+
+    let stats = ListCategoryStatsQuery {
+        platform: Some(Platform::Twitch),
+        start_at: time(0),
+        end_at: time(0) + Duration::days(1),
+        limit: 10,
+    }
+    .execute(&pool)
+    .await?;
+
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].name, "Minecraft");
+    assert_eq!(stats[0].stream_count, 2);
+    assert_eq!(stats[0].hours_streamed, 2.5);
+    assert_eq!(stats[0].viewer_avg, Some(250.0));
+    assert_eq!(stats[0].top_vtuber_ids, vec!["vtuber1", "vtuber2"]);
+    assert_eq!(stats[1].name, "Just Chatting");
+    assert_eq!(stats[1].hours_streamed, 0.5);
+    assert_eq!(stats[1].viewer_avg, Some(100.0));
+
+    let stats = ListCategoryStatsQuery {
+        platform: None,
+        start_at: time(0),
+        end_at: time(0) + Duration::days(1),
+        limit: 1,
+    }
+    .execute(&pool)
+    .await?;
+
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].category_id, minecraft);
+
+    crate::streams::delete_stream(1, &pool).await?;
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+mod assign_stream_category;
+mod has_stream_category;
+mod list_category_stats;
+mod upsert_category;
+
+pub use assign_stream_category::*;
+pub use has_stream_category::*;
+pub use list_category_stats::*;
+pub use upsert_category::*;
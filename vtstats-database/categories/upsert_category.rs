@@ -0,0 +1,33 @@
+use sqlx::{PgPool, Result};
+
+use crate::channels::Platform;
+
+pub struct UpsertCategoryQuery<'q> {
+    pub platform: Platform,
+    pub platform_category_id: &'q str,
+    pub name: &'q str,
+}
+
+impl<'q> UpsertCategoryQuery<'q> {
+    /// inserts category or updates its name, returns category id
+    pub async fn execute(self, pool: &PgPool) -> Result<i32> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO categories AS c (platform, platform_category_id, name)
+     VALUES ($1, $2, $3)
+ON CONFLICT (platform, platform_category_id) DO UPDATE
+        SET name       = $3,
+            updated_at = NOW()
+  RETURNING category_id
+            "#,
+            self.platform as _,
+            self.platform_category_id,
+            self.name,
+        )
+        .fetch_one(pool);
+
+        let record = crate::otel::execute_query!("INSERT", "categories", query)?;
+
+        Ok(record.category_id)
+    }
+}
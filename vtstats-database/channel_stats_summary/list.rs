@@ -1,5 +1,7 @@
 use sqlx::{PgPool, Result};
 
+use crate::channels::Platform;
+
 use super::{ChannelStatsKind, ChannelStatsSummary};
 
 pub async fn list(
@@ -20,3 +22,34 @@ pub async fn list(
 
     crate::otel::execute_query!("SELECT", "channel_stats_summary", query)
 }
+
+/// list summaries of active channels, optionally filtered by platform
+/// and group (including nested groups)
+pub async fn list_by_platform_and_group(
+    kind: ChannelStatsKind,
+    platform: Option<Platform>,
+    group_id: Option<&str>,
+    pool: &PgPool,
+) -> Result<Vec<ChannelStatsSummary>> {
+    let query = sqlx::query_as!(
+        ChannelStatsSummary,
+        "WITH RECURSIVE members(id) AS (\
+            SELECT unnest(children) FROM groups WHERE group_id = $3 \
+            UNION SELECT unnest(g.children) FROM groups g JOIN members m ON g.group_id = m.id\
+        ) \
+        SELECT s.channel_id, s.kind as \"kind: _\", s.updated_at, \
+        s.value, s.value_1_day_ago, s.value_7_days_ago, s.value_30_days_ago \
+        FROM channel_stats_summary s JOIN channels c ON c.channel_id = s.channel_id \
+        WHERE s.kind = $1 \
+        AND (c.platform = $2 OR $2 IS NULL) \
+        AND (c.vtuber_id IN (SELECT id FROM members) OR $3 IS NULL) \
+        AND c.vtuber_id IN \
+        (SELECT vtuber_id FROM vtubers WHERE retired_at IS NULL OR retired_at + '2 week' >= NOW())",
+        kind as _,
+        platform as _,
+        group_id
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "channel_stats_summary", query)
+}
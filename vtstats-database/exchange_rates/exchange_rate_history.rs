@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use sqlx::{Either, PgPool, Postgres, QueryBuilder, Result};
+use std::collections::HashMap;
+
+/// insert or update exchange rates which become valid from `valid_from`,
+/// the period of a rate lasts until the next rate of the same currency
+pub async fn add_exchange_rate_history(
+    pool: &PgPool,
+    valid_from: DateTime<Utc>,
+    iter: impl Iterator<Item = (String, f32)>,
+) -> Result<()> {
+    let mut tx = pool.begin().await?;
+
+    let mut query_builder: QueryBuilder<Postgres> =
+        QueryBuilder::new("INSERT INTO exchange_rate_history (code, rate, valid_from) ");
+
+    query_builder.push_values(iter, |mut b, row| {
+        b.push_bind(row.0).push_bind(row.1).push_bind(valid_from);
+    });
+
+    query_builder.push("ON CONFLICT (code, valid_from) DO UPDATE SET rate = excluded.rate");
+
+    let query = query_builder.build().execute(&mut *tx);
+
+    crate::otel::execute_query!("INSERT", "exchange_rate_history", query)?;
+
+    // close each period with the start of the following one, so that
+    // importing months in any order keeps the periods contiguous
+    let query = sqlx::query!(
+        "UPDATE exchange_rate_history h SET valid_to = (\
+            SELECT MIN(n.valid_from) FROM exchange_rate_history n \
+            WHERE n.code = h.code AND n.valid_from > h.valid_from\
+        )"
+    )
+    .execute(&mut *tx);
+
+    crate::otel::execute_query!("UPDATE", "exchange_rate_history", query)?;
+
+    tx.commit().await
+}
+
+/// returns exchange rates (based on EUR) in force at the given time
+pub async fn list_exchange_rates_at(
+    time: DateTime<Utc>,
+    pool: &PgPool,
+) -> Result<HashMap<String, f32>> {
+    let query = sqlx::query!(
+        "SELECT code, rate FROM exchange_rate_history \
+        WHERE valid_from <= $1 AND (valid_to IS NULL OR valid_to > $1)",
+        time
+    )
+    .fetch_many(pool)
+    .try_filter_map(|step| async move {
+        Ok(match step {
+            Either::Left(_) => None,
+            Either::Right(o) => Some((o.code, o.rate)),
+        })
+    })
+    .try_collect();
+
+    crate::otel::execute_query!("SELECT", "exchange_rate_history", query)
+}
+
+/// returns the exchange rate (based on EUR) of given currency in force at the given time
+pub async fn get_exchange_rate_at(
+    code: &str,
+    time: DateTime<Utc>,
+    pool: &PgPool,
+) -> Result<Option<f32>> {
+    let query = sqlx::query!(
+        "SELECT rate FROM exchange_rate_history \
+        WHERE code = $1 AND valid_from <= $2 AND (valid_to IS NULL OR valid_to > $2)",
+        code,
+        time
+    )
+    .fetch_optional(pool);
+
+    let record = crate::otel::execute_query!("SELECT", "exchange_rate_history", query)?;
+
+    Ok(record.map(|r| r.rate))
+}
+
+#[cfg(test)]
+#[sqlx::test]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::TimeZone;
+
+    let jan = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).single().unwrap();
+    let feb = Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).single().unwrap();
+    let mar = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).single().unwrap();
+
+    // import current month first, then backfill earlier months
+    add_exchange_rate_history(&pool, mar, [("USD".into(), 1.3)].into_iter()).await?;
+    add_exchange_rate_history(&pool, jan, [("USD".into(), 1.1)].into_iter()).await?;
+    add_exchange_rate_history(
+        &pool,
+        feb,
+        [("USD".into(), 1.2), ("JPY".into(), 140.0)].into_iter(),
+    )
+    .await?;
+
+    let rows = sqlx::query!(
+        "SELECT valid_from, valid_to FROM exchange_rate_history \
+        WHERE code = 'USD' ORDER BY valid_from ASC"
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0].valid_to, Some(feb));
+    assert_eq!(rows[1].valid_to, Some(mar));
+    assert_eq!(rows[2].valid_to, None);
+
+    assert_eq!(get_exchange_rate_at("USD", jan, &pool).await?, Some(1.1));
+    assert_eq!(
+        get_exchange_rate_at("USD", feb - chrono::Duration::seconds(1), &pool).await?,
+        Some(1.1)
+    );
+    assert_eq!(get_exchange_rate_at("USD", feb, &pool).await?, Some(1.2));
+    assert_eq!(
+        get_exchange_rate_at("USD", Utc::now(), &pool).await?,
+        Some(1.3)
+    );
+    assert_eq!(get_exchange_rate_at("JPY", jan, &pool).await?, None);
+
+    let rates = list_exchange_rates_at(feb, &pool).await?;
+    assert_eq!(rates.len(), 2);
+    assert_eq!(rates.get("JPY"), Some(&140.0));
+
+    let rates = list_exchange_rates_at(Utc::now(), &pool).await?;
+    assert_eq!(rates.len(), 2);
+    assert_eq!(rates.get("USD"), Some(&1.3));
+
+    // re-importing a month overwrites its rate
+    add_exchange_rate_history(&pool, feb, [("USD".into(), 1.25)].into_iter()).await?;
+    assert_eq!(get_exchange_rate_at("USD", feb, &pool).await?, Some(1.25));
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
+mod exchange_rate_history;
 mod list_exchange_rates;
 mod update_exchange_rates;
 
+pub use self::exchange_rate_history::*;
 pub use self::list_exchange_rates::*;
 pub use self::update_exchange_rates::*;
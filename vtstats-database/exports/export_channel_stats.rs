@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::PgPool;
+
+use super::{column, Export, ExportColumn, ExportColumnKind as Kind, ExportValue as Value};
+
+const CHANNEL_STATS_COLUMNS: &[ExportColumn] = &[
+    column("time", Kind::Timestamp),
+    column("count", Kind::Integer),
+];
+
+/// revenue is exported as a json object of currency code to amount
+const CHANNEL_REVENUE_STATS_COLUMNS: &[ExportColumn] =
+    &[column("time", Kind::Timestamp), column("value", Kind::Text)];
+
+pub fn export_channel_subscriber_stats(
+    channel_id: i32,
+    start_at: Option<DateTime<Utc>>,
+    end_at: Option<DateTime<Utc>>,
+    pool: &PgPool,
+) -> Export<'_> {
+    let rows = sqlx::query!(
+        r#"
+ SELECT time ts, count v1
+   FROM channel_subscriber_stats
+  WHERE channel_id = $1
+    AND (time >= $2 OR $2 IS NULL)
+    AND (time <= $3 OR $3 IS NULL)
+  ORDER BY time
+        "#,
+        channel_id, // $1
+        start_at,   // $2
+        end_at,     // $3
+    )
+    .fetch(pool)
+    .map_ok(|row| {
+        vec![
+            Value::Timestamp(Some(row.ts)),
+            Value::Integer(Some(row.v1.into())),
+        ]
+    })
+    .boxed();
+
+    Export {
+        columns: CHANNEL_STATS_COLUMNS,
+        rows,
+    }
+}
+
+pub fn export_channel_view_stats(
+    channel_id: i32,
+    start_at: Option<DateTime<Utc>>,
+    end_at: Option<DateTime<Utc>>,
+    pool: &PgPool,
+) -> Export<'_> {
+    let rows = sqlx::query!(
+        r#"
+ SELECT time ts, count v1
+   FROM channel_view_stats
+  WHERE channel_id = $1
+    AND (time >= $2 OR $2 IS NULL)
+    AND (time <= $3 OR $3 IS NULL)
+  ORDER BY time
+        "#,
+        channel_id, // $1
+        start_at,   // $2
+        end_at,     // $3
+    )
+    .fetch(pool)
+    .map_ok(|row| {
+        vec![
+            Value::Timestamp(Some(row.ts)),
+            Value::Integer(Some(row.v1.into())),
+        ]
+    })
+    .boxed();
+
+    Export {
+        columns: CHANNEL_STATS_COLUMNS,
+        rows,
+    }
+}
+
+pub fn export_channel_revenue_stats(
+    channel_id: i32,
+    start_at: Option<DateTime<Utc>>,
+    end_at: Option<DateTime<Utc>>,
+    pool: &PgPool,
+) -> Export<'_> {
+    let rows = sqlx::query!(
+        r#"
+ SELECT time ts, value v1
+   FROM channel_revenue_stats
+  WHERE channel_id = $1
+    AND (time >= $2 OR $2 IS NULL)
+    AND (time <= $3 OR $3 IS NULL)
+  ORDER BY time
+        "#,
+        channel_id, // $1
+        start_at,   // $2
+        end_at,     // $3
+    )
+    .fetch(pool)
+    .map_ok(|row| {
+        vec![
+            Value::Timestamp(Some(row.ts)),
+            Value::Text(Some(row.v1.to_string())),
+        ]
+    })
+    .boxed();
+
+    Export {
+        columns: CHANNEL_REVENUE_STATS_COLUMNS,
+        rows,
+    }
+}
@@ -0,0 +1,37 @@
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::PgPool;
+
+use super::{column, Export, ExportColumn, ExportColumnKind as Kind, ExportValue as Value};
+
+/// event value is exported as json object, its shape depends on event kind
+const STREAM_EVENTS_COLUMNS: &[ExportColumn] = &[
+    column("time", Kind::Timestamp),
+    column("kind", Kind::Text),
+    column("value", Kind::Text),
+];
+
+pub fn export_stream_events(stream_id: i32, pool: &PgPool) -> Export<'_> {
+    let rows = sqlx::query!(
+        r#"
+ SELECT time, kind::text, value
+   FROM stream_events
+  WHERE stream_id = $1
+  ORDER BY time
+        "#,
+        stream_id,
+    )
+    .fetch(pool)
+    .map_ok(|row| {
+        vec![
+            Value::Timestamp(Some(row.time)),
+            Value::Text(row.kind),
+            Value::Text(Some(row.value.to_string())),
+        ]
+    })
+    .boxed();
+
+    Export {
+        columns: STREAM_EVENTS_COLUMNS,
+        rows,
+    }
+}
@@ -0,0 +1,160 @@
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::PgPool;
+
+use super::{column, Export, ExportColumn, ExportColumnKind as Kind, ExportValue as Value};
+
+const STREAM_VIEWER_STATS_COLUMNS: &[ExportColumn] = &[
+    column("time", Kind::Timestamp),
+    column("count", Kind::Integer),
+];
+
+const STREAM_CHAT_STATS_COLUMNS: &[ExportColumn] = &[
+    column("time", Kind::Timestamp),
+    column("count", Kind::Integer),
+    column("from_member_count", Kind::Integer),
+    column("chatter_count", Kind::Integer),
+    column("new_chatter_count", Kind::Integer),
+];
+
+pub fn export_stream_viewer_stats(stream_id: i32, pool: &PgPool) -> Export<'_> {
+    let rows = sqlx::query!(
+        r#"
+ SELECT time ts, count v1
+   FROM stream_viewer_stats
+  WHERE stream_id = $1
+  ORDER BY time
+        "#,
+        stream_id,
+    )
+    .fetch(pool)
+    .map_ok(|row| {
+        vec![
+            Value::Timestamp(Some(row.ts)),
+            Value::Integer(Some(row.v1.into())),
+        ]
+    })
+    .boxed();
+
+    Export {
+        columns: STREAM_VIEWER_STATS_COLUMNS,
+        rows,
+    }
+}
+
+pub fn export_stream_chat_stats(stream_id: i32, pool: &PgPool) -> Export<'_> {
+    let rows = sqlx::query!(
+        r#"
+   WITH new_chatters AS (
+            SELECT date_bin('15 seconds', first_message_at, TIMESTAMPTZ 'epoch') AS time,
+                   count(*)::int AS count
+              FROM stream_chatters
+             WHERE stream_id = $1
+          GROUP BY 1
+        )
+ SELECT s.time ts, s.count v1, s.from_member_count v2, s.chatter_count v3,
+        coalesce(n.count, 0) AS "v4!"
+   FROM stream_chat_stats s
+   LEFT JOIN new_chatters n ON n.time = s.time
+  WHERE s.stream_id = $1
+  ORDER BY s.time
+        "#,
+        stream_id,
+    )
+    .fetch(pool)
+    .map_ok(|row| {
+        vec![
+            Value::Timestamp(Some(row.ts)),
+            Value::Integer(Some(row.v1.into())),
+            Value::Integer(Some(row.v2.into())),
+            Value::Integer(Some(row.v3.into())),
+            Value::Integer(Some(row.v4.into())),
+        ]
+    })
+    .boxed();
+
+    Export {
+        columns: STREAM_CHAT_STATS_COLUMNS,
+        rows,
+    }
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> sqlx::Result<()> {
+    use chrono::{TimeZone, Utc};
+
+    sqlx::query!(
+        r#"
+INSERT INTO stream_viewer_stats (stream_id, time, count)
+     VALUES (1, to_timestamp(1800), 20),
+            (1, to_timestamp(900), 10),
+            (2, to_timestamp(900), 30)
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    let export = export_stream_viewer_stats(1, &pool);
+    assert_eq!(export.columns.len(), 2);
+
+    let rows: Vec<_> = export.rows.try_collect().await?;
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                Value::Timestamp(Utc.timestamp_opt(900, 0).single()),
+                Value::Integer(Some(10))
+            ],
+            vec![
+                Value::Timestamp(Utc.timestamp_opt(1800, 0).single()),
+                Value::Integer(Some(20))
+            ],
+        ]
+    );
+
+    sqlx::query!(
+        r#"
+INSERT INTO stream_chat_stats (stream_id, time, count, from_member_count, chatter_count)
+     VALUES (1, to_timestamp(900), 10, 2, 4),
+            (1, to_timestamp(915), 5, 1, 3)
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    sqlx::query!(
+        r#"
+INSERT INTO stream_chatters (stream_id, author_id, author_name, message_count, first_message_at, last_message_at)
+     VALUES (1, 'a', 'a', 6, to_timestamp(901), to_timestamp(920)),
+            (1, 'b', 'b', 5, to_timestamp(917), to_timestamp(918))
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    let export = export_stream_chat_stats(1, &pool);
+    assert_eq!(export.columns.len(), 5);
+
+    let rows: Vec<_> = export.rows.try_collect().await?;
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                Value::Timestamp(Utc.timestamp_opt(900, 0).single()),
+                Value::Integer(Some(10)),
+                Value::Integer(Some(2)),
+                Value::Integer(Some(4)),
+                Value::Integer(Some(1))
+            ],
+            vec![
+                Value::Timestamp(Utc.timestamp_opt(915, 0).single()),
+                Value::Integer(Some(5)),
+                Value::Integer(Some(1)),
+                Value::Integer(Some(3)),
+                Value::Integer(Some(1))
+            ],
+        ]
+    );
+
+    Ok(())
+}
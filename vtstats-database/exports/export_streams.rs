@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::PgPool;
+
+use super::{column, Export, ExportColumn, ExportColumnKind as Kind, ExportValue as Value};
+
+const STREAMS_COLUMNS: &[ExportColumn] = &[
+    column("stream_id", Kind::Integer),
+    column("platform", Kind::Text),
+    column("platform_id", Kind::Text),
+    column("channel_id", Kind::Integer),
+    column("vtuber_id", Kind::Text),
+    column("title", Kind::Text),
+    column("status", Kind::Text),
+    column("schedule_time", Kind::Timestamp),
+    column("start_time", Kind::Timestamp),
+    column("end_time", Kind::Timestamp),
+    column("viewer_max", Kind::Integer),
+    column("viewer_avg", Kind::Integer),
+    column("like_max", Kind::Integer),
+];
+
+/// export streams of given channels, filtered by start time,
+/// or schedule time if stream hasn't started yet
+pub fn export_streams<'e>(
+    channel_ids: &[i32],
+    start_at: Option<DateTime<Utc>>,
+    end_at: Option<DateTime<Utc>>,
+    pool: &'e PgPool,
+) -> Export<'e> {
+    let rows = sqlx::query!(
+        r#"
+ SELECT stream_id, platform::text, platform_id, channel_id, vtuber_id, title,
+        status::text, schedule_time, start_time, end_time, viewer_max, viewer_avg, like_max
+   FROM streams
+  WHERE channel_id = ANY($1)
+    AND (COALESCE(start_time, schedule_time) >= $2 OR $2 IS NULL)
+    AND (COALESCE(start_time, schedule_time) <= $3 OR $3 IS NULL)
+  ORDER BY stream_id
+        "#,
+        channel_ids, // $1
+        start_at,    // $2
+        end_at,      // $3
+    )
+    .fetch(pool)
+    .map_ok(|row| {
+        vec![
+            Value::Integer(Some(row.stream_id.into())),
+            Value::Text(row.platform),
+            Value::Text(Some(row.platform_id)),
+            Value::Integer(Some(row.channel_id.into())),
+            Value::Text(Some(row.vtuber_id)),
+            Value::Text(Some(row.title)),
+            Value::Text(row.status),
+            Value::Timestamp(row.schedule_time),
+            Value::Timestamp(row.start_time),
+            Value::Timestamp(row.end_time),
+            Value::Integer(row.viewer_max.map(Into::into)),
+            Value::Integer(row.viewer_avg.map(Into::into)),
+            Value::Integer(row.like_max.map(Into::into)),
+        ]
+    })
+    .boxed();
+
+    Export {
+        columns: STREAMS_COLUMNS,
+        rows,
+    }
+}
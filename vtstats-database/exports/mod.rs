@@ -0,0 +1,47 @@
+mod export_channel_stats;
+mod export_stream_events;
+mod export_stream_stats;
+mod export_streams;
+
+pub use self::export_channel_stats::*;
+pub use self::export_stream_events::*;
+pub use self::export_stream_stats::*;
+pub use self::export_streams::*;
+
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
+use sqlx::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportColumnKind {
+    Integer,
+    Text,
+    Timestamp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportColumn {
+    pub name: &'static str,
+    pub kind: ExportColumnKind,
+}
+
+/// a single cell of exported table, all columns are nullable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportValue {
+    Integer(Option<i64>),
+    Text(Option<String>),
+    Timestamp(Option<DateTime<Utc>>),
+}
+
+pub type ExportRow = Vec<ExportValue>;
+
+/// rows are fetched lazily from database, so that large tables
+/// can be exported without loading them into memory at once
+pub struct Export<'e> {
+    pub columns: &'static [ExportColumn],
+    pub rows: BoxStream<'e, Result<ExportRow>>,
+}
+
+const fn column(name: &'static str, kind: ExportColumnKind) -> ExportColumn {
+    ExportColumn { name, kind }
+}
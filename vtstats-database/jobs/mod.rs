@@ -39,6 +39,15 @@ pub enum JobKind {
     CollectTwitchStreamMetadata,
     UpdateExchangeRates,
     SendNotification,
+    BackfillExchangeRates,
+    RefreshBilibiliLive,
+    CollectBilibiliStreamMetadata,
+    SubscribeTwitchEventsub,
+    RefreshTwitchSchedule,
+    BackfillYoutubeStreams,
+    RefreshVodStatus,
+    BackfillStreamEventMoney,
+    HandleTwitchEvent,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
@@ -51,11 +60,36 @@ pub struct CollectTwitchStreamMetadataJobPayload {
     pub stream_id: i32,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct CollectBilibiliStreamMetadataJobPayload {
+    pub stream_id: i32,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
 pub struct SendNotificationJobPayload {
     pub stream_id: i32,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct BackfillExchangeRatesJobPayload {
+    #[serde(with = "ts_milliseconds")]
+    pub since: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct BackfillYoutubeStreamsJobPayload {
+    pub channel_id: i32,
+    /// also replays chats of backfilled streams
+    #[serde(default)]
+    pub with_chats: bool,
+}
+
+/// eventsub notification received by webhook, in the shape of its body
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct HandleTwitchEventJobPayload {
+    pub event: serde_json::Value,
+}
+
 #[derive(Serialize, PartialEq, Eq, Debug)]
 #[serde(untagged)]
 pub enum JobPayload {
@@ -64,9 +98,18 @@ pub enum JobPayload {
     SubscribeYoutubePubsub,
     UpdateChannelStats,
     UpdateExchangeRates,
+    RefreshBilibiliLive,
+    SubscribeTwitchEventsub,
+    RefreshTwitchSchedule,
+    RefreshVodStatus,
+    BackfillStreamEventMoney,
     CollectYoutubeStreamMetadata(CollectYoutubeStreamMetadataJobPayload),
     CollectTwitchStreamMetadata(CollectTwitchStreamMetadataJobPayload),
+    CollectBilibiliStreamMetadata(CollectBilibiliStreamMetadataJobPayload),
     SendNotification(SendNotificationJobPayload),
+    BackfillExchangeRates(BackfillExchangeRatesJobPayload),
+    BackfillYoutubeStreams(BackfillYoutubeStreamsJobPayload),
+    HandleTwitchEvent(HandleTwitchEventJobPayload),
 }
 
 #[derive(Serialize)]
@@ -96,6 +139,15 @@ impl JobPayload {
             JobPayload::CollectYoutubeStreamMetadata(_) => JobKind::CollectYoutubeStreamMetadata,
             JobPayload::CollectTwitchStreamMetadata(_) => JobKind::CollectTwitchStreamMetadata,
             JobPayload::SendNotification(_) => JobKind::SendNotification,
+            JobPayload::BackfillExchangeRates(_) => JobKind::BackfillExchangeRates,
+            JobPayload::RefreshBilibiliLive => JobKind::RefreshBilibiliLive,
+            JobPayload::CollectBilibiliStreamMetadata(_) => JobKind::CollectBilibiliStreamMetadata,
+            JobPayload::SubscribeTwitchEventsub => JobKind::SubscribeTwitchEventsub,
+            JobPayload::RefreshTwitchSchedule => JobKind::RefreshTwitchSchedule,
+            JobPayload::BackfillYoutubeStreams(_) => JobKind::BackfillYoutubeStreams,
+            JobPayload::RefreshVodStatus => JobKind::RefreshVodStatus,
+            JobPayload::BackfillStreamEventMoney => JobKind::BackfillStreamEventMoney,
+            JobPayload::HandleTwitchEvent(_) => JobKind::HandleTwitchEvent,
         }
     }
 
@@ -109,6 +161,15 @@ impl JobPayload {
             JobPayload::CollectYoutubeStreamMetadata(_) => "collect_youtube_stream_metadata",
             JobPayload::CollectTwitchStreamMetadata(_) => "collect_twitch_stream_metadata",
             JobPayload::SendNotification(_) => "send_notification",
+            JobPayload::BackfillExchangeRates(_) => "backfill_exchange_rates",
+            JobPayload::RefreshBilibiliLive => "refresh_bilibili_live",
+            JobPayload::CollectBilibiliStreamMetadata(_) => "collect_bilibili_stream_metadata",
+            JobPayload::SubscribeTwitchEventsub => "subscribe_twitch_eventsub",
+            JobPayload::RefreshTwitchSchedule => "refresh_twitch_schedule",
+            JobPayload::BackfillYoutubeStreams(_) => "backfill_youtube_streams",
+            JobPayload::RefreshVodStatus => "refresh_vod_status",
+            JobPayload::BackfillStreamEventMoney => "backfill_stream_event_money",
+            JobPayload::HandleTwitchEvent(_) => "handle_twitch_event",
         }
     }
 }
@@ -139,6 +200,25 @@ impl FromRow<'_, PgRow> for Job {
                 JobKind::SendNotification => {
                     JobPayload::SendNotification(row.try_get::<Json<_>, _>("payload")?.0)
                 }
+                JobKind::BackfillExchangeRates => {
+                    JobPayload::BackfillExchangeRates(row.try_get::<Json<_>, _>("payload")?.0)
+                }
+                JobKind::RefreshBilibiliLive => JobPayload::RefreshBilibiliLive,
+                JobKind::SubscribeTwitchEventsub => JobPayload::SubscribeTwitchEventsub,
+                JobKind::RefreshTwitchSchedule => JobPayload::RefreshTwitchSchedule,
+                JobKind::RefreshVodStatus => JobPayload::RefreshVodStatus,
+                JobKind::BackfillStreamEventMoney => JobPayload::BackfillStreamEventMoney,
+                JobKind::BackfillYoutubeStreams => {
+                    JobPayload::BackfillYoutubeStreams(row.try_get::<Json<_>, _>("payload")?.0)
+                }
+                JobKind::CollectBilibiliStreamMetadata => {
+                    JobPayload::CollectBilibiliStreamMetadata(
+                        row.try_get::<Json<_>, _>("payload")?.0,
+                    )
+                }
+                JobKind::HandleTwitchEvent => {
+                    JobPayload::HandleTwitchEvent(row.try_get::<Json<_>, _>("payload")?.0)
+                }
             },
         })
     }
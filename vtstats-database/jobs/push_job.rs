@@ -101,6 +101,15 @@ pub async fn queue_collect_twitch_stream_metadata(
     .await
 }
 
+pub async fn queue_handle_twitch_event(event: serde_json::Value, pool: &PgPool) -> Result<i32> {
+    PushJobQuery {
+        next_run: Some(Utc::now()),
+        payload: JobPayload::HandleTwitchEvent(HandleTwitchEventJobPayload { event }),
+    }
+    .execute(pool)
+    .await
+}
+
 #[cfg(test)]
 #[sqlx::test]
 async fn test(pool: PgPool) -> Result<()> {
@@ -211,3 +220,27 @@ async fn test(pool: PgPool) -> Result<()> {
 
     Ok(())
 }
+
+pub async fn queue_collect_bilibili_stream_metadata(
+    time: DateTime<Utc>,
+    stream_id: i32,
+    pool: &PgPool,
+) -> Result<i32> {
+    PushJobQuery {
+        next_run: Some(time),
+        payload: JobPayload::CollectBilibiliStreamMetadata(
+            CollectBilibiliStreamMetadataJobPayload { stream_id },
+        ),
+    }
+    .execute(pool)
+    .await
+}
+
+pub async fn queue_subscribe_twitch_eventsub(time: DateTime<Utc>, pool: &PgPool) -> Result<i32> {
+    PushJobQuery {
+        next_run: Some(time),
+        payload: JobPayload::SubscribeTwitchEventsub,
+    }
+    .execute(pool)
+    .await
+}
@@ -5,12 +5,15 @@
 pub mod json;
 pub mod otel;
 
+pub mod categories;
 pub mod channel_stats;
 pub mod channel_stats_summary;
 pub mod channels;
 pub mod exchange_rates;
+pub mod exports;
 pub mod groups;
 pub mod jobs;
+pub mod search;
 pub mod stream_events;
 pub mod stream_stats;
 pub mod streams;
@@ -0,0 +1,20 @@
+CREATE TABLE exchange_rate_history (
+    code text NOT NULL,
+    rate REAL NOT NULL,
+    valid_from timestamptz NOT NULL,
+    valid_to timestamptz,
+    PRIMARY KEY (code, valid_from)
+);
+
+INSERT INTO
+    exchange_rate_history (code, rate, valid_from)
+SELECT
+    code,
+    rate,
+    date_trunc('month', updated_at, 'UTC')
+FROM
+    exchange_rates;
+
+ALTER TYPE job_kind
+ADD
+    VALUE 'backfill_exchange_rates';
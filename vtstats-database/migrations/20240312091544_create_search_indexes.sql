@@ -0,0 +1,18 @@
+CREATE INDEX pgroonga_vtubers_names_index ON vtubers USING pgroonga (
+    native_name,
+    english_name,
+    japanese_name,
+    twitter_username
+) WITH (
+    tokenizer = 'TokenNgram("report_source_location", true, "loose_blank", true)'
+);
+
+CREATE INDEX pgroonga_groups_names_index ON groups USING pgroonga (
+    native_name,
+    english_name,
+    japanese_name
+) WITH (
+    tokenizer = 'TokenNgram("report_source_location", true, "loose_blank", true)'
+);
+
+CREATE INDEX pgroonga_channels_platform_id_index ON channels USING pgroonga (platform_id pgroonga_text_term_search_ops_v2);
@@ -0,0 +1,15 @@
+-- keep track of deleted streams, so that clients which have seen
+-- them (e.g. calendar apps) can be notified of cancellation
+CREATE TABLE deleted_streams (
+    stream_id integer PRIMARY KEY,
+    channel_id integer NOT NULL REFERENCES channels,
+    vtuber_id text NOT NULL,
+    platform platform NOT NULL,
+    platform_id text NOT NULL,
+    title text NOT NULL,
+    schedule_time timestamptz,
+    start_time timestamptz,
+    deleted_at timestamptz NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX deleted_streams_deleted_at ON deleted_streams (deleted_at);
@@ -0,0 +1,7 @@
+ALTER TYPE job_kind
+ADD
+    VALUE 'refresh_bilibili_live';
+
+ALTER TYPE job_kind
+ADD
+    VALUE 'collect_bilibili_stream_metadata';
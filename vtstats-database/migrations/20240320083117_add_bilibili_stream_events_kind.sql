@@ -0,0 +1,11 @@
+ALTER TYPE stream_event_kind
+ADD
+    VALUE 'bilibili_super_chat';
+
+ALTER TYPE stream_event_kind
+ADD
+    VALUE 'bilibili_gift';
+
+ALTER TYPE stream_event_kind
+ADD
+    VALUE 'bilibili_guard';
@@ -0,0 +1,3 @@
+ALTER TYPE job_kind
+ADD
+    VALUE 'subscribe_twitch_eventsub';
@@ -0,0 +1,10 @@
+CREATE TABLE stream_timeline (
+    stream_id integer NOT NULL REFERENCES streams,
+    time timestamptz NOT NULL,
+    title text NOT NULL,
+    category_id text NOT NULL,
+    category_name text NOT NULL,
+    language text NOT NULL,
+    content_labels text [] NOT NULL DEFAULT '{}',
+    PRIMARY KEY (stream_id, time)
+);
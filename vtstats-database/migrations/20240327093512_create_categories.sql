@@ -0,0 +1,21 @@
+-- games or categories a stream can be tagged with, on youtube
+-- `platform_category_id` is the game's topic channel id
+CREATE TABLE categories (
+    category_id serial PRIMARY KEY,
+    platform platform NOT NULL,
+    platform_category_id text NOT NULL,
+    name text NOT NULL,
+    updated_at timestamptz NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX categories_platform_id ON categories (platform, platform_category_id);
+
+-- category of a stream since given time, until the next assignment
+CREATE TABLE stream_categories (
+    stream_id integer NOT NULL REFERENCES streams,
+    category_id integer NOT NULL REFERENCES categories,
+    time timestamptz NOT NULL,
+    PRIMARY KEY (stream_id, time)
+);
+
+CREATE INDEX stream_categories_category_id ON stream_categories (category_id);
@@ -0,0 +1,3 @@
+ALTER TYPE job_kind
+ADD
+    VALUE 'backfill_youtube_streams';
@@ -0,0 +1,22 @@
+CREATE TYPE vod_status AS ENUM (
+    'available',
+    'members_only',
+    'private',
+    'unarchived',
+    'deleted'
+);
+
+ALTER TABLE
+    streams
+ADD
+    COLUMN vod_status vod_status,
+ADD
+    COLUMN vod_checked_at timestamptz;
+
+-- availability transitions of stream vods
+CREATE TABLE stream_vod_history (
+    stream_id integer NOT NULL REFERENCES streams,
+    time timestamptz NOT NULL,
+    status vod_status NOT NULL,
+    PRIMARY KEY (stream_id, time)
+);
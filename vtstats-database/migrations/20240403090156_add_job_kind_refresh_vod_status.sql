@@ -0,0 +1,3 @@
+ALTER TYPE job_kind
+ADD
+    VALUE 'refresh_vod_status';
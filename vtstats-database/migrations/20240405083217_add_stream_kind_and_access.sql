@@ -0,0 +1,10 @@
+CREATE TYPE stream_kind AS ENUM ('live', 'premiere', 'vertical');
+
+CREATE TYPE stream_access AS ENUM ('public', 'unlisted', 'members_only');
+
+ALTER TABLE
+    streams
+ADD
+    COLUMN stream_kind stream_kind NOT NULL DEFAULT 'live',
+ADD
+    COLUMN access stream_access NOT NULL DEFAULT 'public';
@@ -0,0 +1,11 @@
+ALTER TYPE stream_event_kind
+ADD
+    VALUE 'twitch_subscription';
+
+ALTER TYPE stream_event_kind
+ADD
+    VALUE 'twitch_gift_subscription';
+
+ALTER TYPE stream_event_kind
+ADD
+    VALUE 'twitch_raid';
@@ -0,0 +1,7 @@
+ALTER TYPE stream_event_kind
+ADD
+    VALUE 'youtube_membership_gift';
+
+ALTER TYPE stream_event_kind
+ADD
+    VALUE 'youtube_membership_gift_redemption';
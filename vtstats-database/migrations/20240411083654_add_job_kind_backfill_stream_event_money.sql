@@ -0,0 +1,6 @@
+-- `money` of youtube super chats, stickers and twitch hyper chats is
+-- filled by this job, which parses raw amounts with the same parser as
+-- the chat collectors
+ALTER TYPE job_kind
+ADD
+    VALUE 'backfill_stream_event_money';
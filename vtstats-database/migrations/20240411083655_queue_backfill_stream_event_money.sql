@@ -0,0 +1,4 @@
+INSERT INTO
+    jobs (kind, next_run)
+VALUES
+    ('backfill_stream_event_money', NOW());
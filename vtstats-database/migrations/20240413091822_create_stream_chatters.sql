@@ -0,0 +1,16 @@
+ALTER TABLE
+    stream_chat_stats
+ADD
+    COLUMN chatter_count integer NOT NULL DEFAULT 0;
+
+CREATE TABLE stream_chatters (
+    stream_id integer NOT NULL REFERENCES streams,
+    author_id text NOT NULL,
+    author_name text NOT NULL,
+    message_count integer NOT NULL,
+    first_message_at timestamptz NOT NULL,
+    last_message_at timestamptz NOT NULL,
+    PRIMARY KEY (stream_id, author_id)
+);
+
+CREATE INDEX stream_chatters_author_id_idx ON stream_chatters (author_id);
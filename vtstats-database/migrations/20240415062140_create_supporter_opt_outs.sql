@@ -0,0 +1,6 @@
+CREATE TABLE supporter_opt_outs (
+    channel_id integer NOT NULL REFERENCES channels,
+    author_id text NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (channel_id, author_id)
+);
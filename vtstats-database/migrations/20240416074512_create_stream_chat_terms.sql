@@ -0,0 +1,22 @@
+CREATE TYPE chat_term_kind AS ENUM ('emote', 'keyword');
+
+CREATE TABLE stream_chat_terms (
+    stream_id integer NOT NULL REFERENCES streams,
+    time timestamptz NOT NULL,
+    kind chat_term_kind NOT NULL,
+    term text NOT NULL,
+    count integer NOT NULL,
+    PRIMARY KEY (stream_id, kind, term, time)
+);
+
+-- a keyword is counted once per message if it contains any of the patterns
+CREATE TABLE chat_keywords (
+    keyword text PRIMARY KEY,
+    patterns text [] NOT NULL
+);
+
+INSERT INTO
+    chat_keywords (keyword, patterns)
+VALUES
+    ('草', ARRAY ['草', 'kusa']),
+    ('lol', ARRAY ['lol', 'lmao']);
@@ -0,0 +1,7 @@
+-- `language` is an ISO 639-3 code, or `und` if it can't be detected reliably
+CREATE TABLE stream_chat_languages (
+    stream_id integer NOT NULL REFERENCES streams,
+    language text NOT NULL,
+    count integer NOT NULL,
+    PRIMARY KEY (stream_id, language)
+);
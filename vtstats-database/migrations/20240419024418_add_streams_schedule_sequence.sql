@@ -0,0 +1,27 @@
+-- revision of the scheduled time, used as `SEQUENCE` of calendar events,
+-- so that calendar apps pick up reschedules
+ALTER TABLE
+    streams
+ADD
+    COLUMN schedule_sequence integer NOT NULL DEFAULT 0;
+
+ALTER TABLE
+    deleted_streams
+ADD
+    COLUMN schedule_sequence integer NOT NULL DEFAULT 0;
+
+CREATE FUNCTION bump_schedule_sequence() RETURNS trigger AS $$
+BEGIN
+    NEW.schedule_sequence := OLD.schedule_sequence + 1;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER streams_schedule_sequence BEFORE
+UPDATE
+    ON streams FOR EACH ROW
+    WHEN (
+        COALESCE(NEW.schedule_time, NEW.start_time) IS DISTINCT
+        FROM
+            COALESCE(OLD.schedule_time, OLD.start_time)
+    ) EXECUTE FUNCTION bump_schedule_sequence();
@@ -0,0 +1,42 @@
+-- currency and amount of a paid stream event, shared by revenue queries,
+-- cheering bits are in `cheering` and converted with its own rate,
+-- twitch subscriptions are valued at the list price of their tier in USD
+-- and prime subscriptions are treated as tier 1, bilibili gold coins
+-- are converted to CNY
+CREATE FUNCTION twitch_subscription_price(plan text) RETURNS numeric AS $$
+SELECT
+    CASE
+        plan
+        WHEN '2000' THEN 9.99
+        WHEN '3000' THEN 24.99
+        ELSE 4.99
+    END;
+$$ LANGUAGE sql IMMUTABLE;
+
+CREATE FUNCTION stream_event_symbol(kind stream_event_kind, value jsonb) RETURNS text AS $$
+SELECT
+    CASE
+        WHEN kind = 'twitch_cheering' THEN 'cheering'
+        WHEN kind IN ('twitch_subscription', 'twitch_gift_subscription') THEN 'USD'
+        WHEN kind IN ('bilibili_super_chat', 'bilibili_gift', 'bilibili_guard') THEN 'CNY'
+        ELSE (value -> 'money' ->> 'currency')
+    END;
+$$ LANGUAGE sql IMMUTABLE;
+
+CREATE FUNCTION stream_event_amount(kind stream_event_kind, value jsonb) RETURNS text AS $$
+SELECT
+    CASE
+        WHEN kind = 'twitch_cheering' THEN (value ->> 'bits')
+        WHEN kind = 'twitch_subscription' THEN twitch_subscription_price(value ->> 'plan') :: text
+        WHEN kind = 'twitch_gift_subscription' THEN (
+            twitch_subscription_price(value ->> 'plan') * (value ->> 'count') :: numeric
+        ) :: text
+        WHEN kind = 'bilibili_super_chat' THEN (value ->> 'price')
+        WHEN kind = 'bilibili_gift' THEN round((value ->> 'total_coin') :: numeric / 1000, 3) :: text
+        WHEN kind = 'bilibili_guard' THEN round(
+            (value ->> 'price') :: numeric * (value ->> 'num') :: numeric / 1000,
+            3
+        ) :: text
+        ELSE (value -> 'money' ->> 'amount')
+    END;
+$$ LANGUAGE sql IMMUTABLE;
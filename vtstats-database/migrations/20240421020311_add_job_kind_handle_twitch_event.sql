@@ -0,0 +1,5 @@
+-- twitch eventsub notifications received by the api are handed over
+-- to the worker with this job
+ALTER TYPE job_kind
+ADD
+    VALUE 'handle_twitch_event';
@@ -0,0 +1,53 @@
+-- exchange rate (based on EUR) of a currency in force at given time,
+-- falls back to current rate if the month is missing from the history
+CREATE FUNCTION exchange_rate_at(code text, at timestamptz) RETURNS real AS $$
+SELECT
+    CASE
+        WHEN $1 = 'EUR' THEN 1
+        ELSE COALESCE(
+            (
+                SELECT
+                    rate
+                FROM
+                    exchange_rate_history h
+                WHERE
+                    h.code = $1
+                    AND h.valid_from <= $2
+                    AND (
+                        h.valid_to IS NULL
+                        OR h.valid_to > $2
+                    )
+            ),
+            (
+                SELECT
+                    rate
+                FROM
+                    exchange_rates r
+                WHERE
+                    r.code = $1
+            )
+        )
+    END;
+$$ LANGUAGE sql STABLE;
+
+-- amount of a paid stream event in USD, converted with the exchange rates
+-- in force at the time of event, cheering is counted as 1 USD per 100 bits,
+-- null if the amount or the exchange rate is unknown
+CREATE FUNCTION stream_event_usd(
+    kind stream_event_kind,
+    value jsonb,
+    at timestamptz
+) RETURNS double precision AS $$
+SELECT
+    CASE
+        WHEN amount !~ '^-?[0-9]+(\.[0-9]+)?$' THEN NULL
+        WHEN symbol = 'cheering' THEN amount :: double precision / 100
+        ELSE amount :: double precision / NULLIF(exchange_rate_at(symbol, $3), 0) * exchange_rate_at('USD', $3)
+    END
+FROM
+    (
+        SELECT
+            stream_event_symbol($1, $2) symbol,
+            stream_event_amount($1, $2) amount
+    ) e;
+$$ LANGUAGE sql STABLE;
@@ -0,0 +1,15 @@
+use sqlx::{PgPool, Result};
+
+/// expands keyword with synonyms defined in `pgroonga_synonyms`,
+/// e.g. `hololive` becomes `((hololive) OR (ホロライブ))`
+pub async fn expand_keyword(keyword: &str, pool: &PgPool) -> Result<String> {
+    let query = sqlx::query!(
+        "SELECT pgroonga_query_expand('pgroonga_synonyms', 'term', 'synonyms', $1) as expended",
+        keyword
+    )
+    .fetch_one(pool);
+
+    let expended = crate::otel::execute_query!("SELECT", "pgroonga_query_expand", query)?;
+
+    Ok(expended.expended.unwrap_or_else(|| keyword.to_string()))
+}
@@ -0,0 +1,13 @@
+mod expand_keyword;
+mod search_channels;
+mod search_groups;
+mod search_streams;
+mod search_vtubers;
+mod synonyms;
+
+pub use self::expand_keyword::*;
+pub use self::search_channels::*;
+pub use self::search_groups::*;
+pub use self::search_streams::*;
+pub use self::search_vtubers::*;
+pub use self::synonyms::*;
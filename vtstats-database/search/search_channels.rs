@@ -0,0 +1,35 @@
+use serde::Serialize;
+use sqlx::{PgPool, Result};
+
+use crate::channels::Platform;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelSearchResult {
+    pub channel_id: i32,
+    pub platform: Platform,
+    pub platform_id: String,
+    pub vtuber_id: String,
+}
+
+/// search channels whose platform id (e.g. youtube channel id or twitch
+/// login) starts with given keyword, keyword is not expanded here
+pub async fn search_channels(
+    keyword: &str,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<ChannelSearchResult>> {
+    let query = sqlx::query_as!(
+        ChannelSearchResult,
+        "SELECT channel_id, platform as \"platform: _\", platform_id, vtuber_id \
+        FROM channels \
+        WHERE platform_id &^ $1 \
+        ORDER BY channel_id \
+        LIMIT $2",
+        keyword,
+        limit
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "channels", query)
+}
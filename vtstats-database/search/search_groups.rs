@@ -0,0 +1,43 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use sqlx::{PgPool, Result};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[skip_serializing_none]
+pub struct GroupSearchResult {
+    pub group_id: String,
+    pub native_name: String,
+    pub english_name: Option<String>,
+    pub japanese_name: Option<String>,
+    pub highlighted_native_name: Option<String>,
+    pub highlighted_english_name: Option<String>,
+    pub highlighted_japanese_name: Option<String>,
+    pub score: f64,
+}
+
+pub async fn search_groups(
+    keyword: &str,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<GroupSearchResult>> {
+    let query = sqlx::query_as!(
+        GroupSearchResult,
+        "SELECT group_id, native_name, english_name, japanese_name, \
+        pgroonga_highlight_html(native_name, pgroonga_query_extract_keywords($1)) highlighted_native_name, \
+        pgroonga_highlight_html(english_name, pgroonga_query_extract_keywords($1)) highlighted_english_name, \
+        pgroonga_highlight_html(japanese_name, pgroonga_query_extract_keywords($1)) highlighted_japanese_name, \
+        pgroonga_score(tableoid, ctid) as \"score!\" \
+        FROM groups \
+        WHERE native_name &@~ $1 \
+        OR english_name &@~ $1 \
+        OR japanese_name &@~ $1 \
+        ORDER BY 8 DESC, group_id \
+        LIMIT $2",
+        keyword,
+        limit
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "groups", query)
+}
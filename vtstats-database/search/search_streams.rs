@@ -0,0 +1,40 @@
+use sqlx::{PgPool, Result};
+
+use crate::streams::Stream;
+
+/// search streams by title, ordered by relevance and then start time
+pub async fn search_streams(keyword: &str, limit: i64, pool: &PgPool) -> Result<Vec<Stream>> {
+    let query = sqlx::query_as!(
+        Stream,
+        "SELECT platform as \"platform: _\", \
+        platform_id, \
+        stream_id, \
+        title, \
+        channel_id, \
+        pgroonga_highlight_html(\
+            title, pgroonga_query_extract_keywords($1)\
+        ) as highlighted_title, \
+        vtuber_id, \
+        thumbnail_url, \
+        schedule_time, \
+        start_time, \
+        end_time, \
+        viewer_max, \
+        viewer_avg, \
+        like_max, \
+        vod_status as \"vod_status: _\", \
+        stream_kind as \"stream_kind: _\", \
+        access as \"access: _\", \
+        updated_at, \
+        status as \"status: _\" \
+        FROM streams \
+        WHERE title &@~ $1 \
+        ORDER BY pgroonga_score(tableoid, ctid) DESC, start_time DESC NULLS LAST \
+        LIMIT $2",
+        keyword,
+        limit
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "streams", query)
+}
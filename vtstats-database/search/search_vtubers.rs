@@ -0,0 +1,80 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use sqlx::{PgPool, Result};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+#[skip_serializing_none]
+pub struct VTuberSearchResult {
+    pub vtuber_id: String,
+    pub native_name: String,
+    pub english_name: Option<String>,
+    pub japanese_name: Option<String>,
+    pub twitter_username: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub highlighted_native_name: Option<String>,
+    pub highlighted_english_name: Option<String>,
+    pub highlighted_japanese_name: Option<String>,
+    pub score: f64,
+}
+
+/// search vtubers by names and twitter username, `keyword` should be
+/// expanded by [`expand_keyword`](super::expand_keyword) beforehand
+pub async fn search_vtubers(
+    keyword: &str,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<VTuberSearchResult>> {
+    let query = sqlx::query_as!(
+        VTuberSearchResult,
+        "SELECT vtuber_id, native_name, english_name, japanese_name, twitter_username, thumbnail_url, \
+        pgroonga_highlight_html(native_name, pgroonga_query_extract_keywords($1)) highlighted_native_name, \
+        pgroonga_highlight_html(english_name, pgroonga_query_extract_keywords($1)) highlighted_english_name, \
+        pgroonga_highlight_html(japanese_name, pgroonga_query_extract_keywords($1)) highlighted_japanese_name, \
+        pgroonga_score(tableoid, ctid) as \"score!\" \
+        FROM vtubers \
+        WHERE native_name &@~ $1 \
+        OR english_name &@~ $1 \
+        OR japanese_name &@~ $1 \
+        OR twitter_username &@~ $1 \
+        ORDER BY 10 DESC, vtuber_id \
+        LIMIT $2",
+        keyword,
+        limit
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "vtubers", query)
+}
+
+#[cfg(test)]
+#[sqlx::test]
+async fn test(pool: PgPool) -> Result<()> {
+    sqlx::query!(
+        r#"
+INSERT INTO vtubers (vtuber_id, native_name, english_name, japanese_name, twitter_username)
+     VALUES ('vtuber1', 'ときのそら', 'Tokino Sora', 'ときのそら', 'tokino_sora'),
+            ('vtuber2', '星街すいせい', 'Hoshimachi Suisei', NULL, 'suisei_hosimati'),
+            ('vtuber3', '白上フブキ', 'Shirakami Fubuki', NULL, 'shirakamifubuki')
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    let results = search_vtubers("suisei", 10, &pool).await?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].vtuber_id, "vtuber2");
+
+    let results = search_vtubers("ときのそら", 10, &pool).await?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].vtuber_id, "vtuber1");
+
+    let results = search_vtubers("shirakamifubuki", 10, &pool).await?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].vtuber_id, "vtuber3");
+
+    let results = search_vtubers("nobody", 10, &pool).await?;
+    assert!(results.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Result};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Synonym {
+    pub term: String,
+    pub synonyms: Vec<String>,
+}
+
+pub async fn list_synonyms(pool: &PgPool) -> Result<Vec<Synonym>> {
+    let query = sqlx::query_as!(
+        Synonym,
+        "SELECT term, synonyms as \"synonyms!: _\" FROM pgroonga_synonyms ORDER BY term"
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "pgroonga_synonyms", query)
+}
+
+pub async fn upsert_synonym(synonym: &Synonym, pool: &PgPool) -> Result<()> {
+    let query = sqlx::query!(
+        "INSERT INTO pgroonga_synonyms (term, synonyms) VALUES ($1, $2) \
+        ON CONFLICT (term) DO UPDATE SET synonyms = excluded.synonyms",
+        synonym.term,
+        &synonym.synonyms,
+    )
+    .execute(pool);
+
+    crate::otel::execute_query!("INSERT", "pgroonga_synonyms", query)?;
+
+    Ok(())
+}
+
+pub async fn delete_synonym(term: &str, pool: &PgPool) -> Result<()> {
+    let query = sqlx::query!("DELETE FROM pgroonga_synonyms WHERE term = $1", term).execute(pool);
+
+    crate::otel::execute_query!("DELETE", "pgroonga_synonyms", query)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[sqlx::test]
+async fn test(pool: PgPool) -> Result<()> {
+    // seeded by migration
+    let synonyms = list_synonyms(&pool).await?;
+    assert_eq!(synonyms.len(), 3);
+
+    upsert_synonym(
+        &Synonym {
+            term: "hololive".into(),
+            synonyms: vec!["hololive".into(), "ホロライブ".into(), "ホロ".into()],
+        },
+        &pool,
+    )
+    .await?;
+    upsert_synonym(
+        &Synonym {
+            term: "vspo".into(),
+            synonyms: vec!["vspo".into(), "ぶいすぽ".into()],
+        },
+        &pool,
+    )
+    .await?;
+    delete_synonym("minecraft", &pool).await?;
+
+    let synonyms = list_synonyms(&pool).await?;
+    assert_eq!(
+        synonyms.iter().map(|s| s.term.as_str()).collect::<Vec<_>>(),
+        vec!["hololive", "nijisanji", "vspo"]
+    );
+    assert_eq!(synonyms[0].synonyms.len(), 3);
+
+    Ok(())
+}
@@ -28,8 +28,8 @@ pub async fn list_youtube_channel_revenue_events(
     let query = sqlx::query_as!(
         ChannelRevenueEvent,
         "SELECT channel_id, time, \
-        (value->>'paid_amount') amount, \
-        (value->>'paid_currency_symbol') symbol \
+        (value->'money'->>'amount') amount, \
+        (value->'money'->>'currency') symbol \
         FROM stream_events LEFT JOIN streams ON streams.stream_id = stream_events.stream_id \
         WHERE time > $1 AND (kind = 'youtube_super_chat' OR kind = 'youtube_super_sticker')",
         start_at
@@ -39,6 +39,8 @@ pub async fn list_youtube_channel_revenue_events(
     crate::otel::execute_query!("SELECT", "stream_events", query)
 }
 
+/// subscriptions are valued at the list price of their tier in USD,
+/// prime subscriptions are treated as tier 1, see `stream_event_amount`
 pub async fn list_twitch_channel_revenue_events(
     start_at: DateTime<Utc>,
     pool: &PgPool,
@@ -46,10 +48,30 @@ pub async fn list_twitch_channel_revenue_events(
     let query = sqlx::query_as!(
         ChannelRevenueEvent,
         "SELECT channel_id, time, \
-        CASE WHEN kind = 'twitch_cheering' THEN 'cheering' ELSE (value->>'currency_code') END symbol, \
-        CASE WHEN kind = 'twitch_cheering' THEN (value->>'bits') ELSE (value->>'amount') END amount \
+        stream_event_symbol(kind, value) symbol, \
+        stream_event_amount(kind, value) amount \
         FROM stream_events LEFT JOIN streams ON streams.stream_id = stream_events.stream_id \
-        WHERE time > $1 AND (kind = 'twitch_cheering' OR kind = 'twitch_hyper_chat')",
+        WHERE time > $1 AND (kind = 'twitch_cheering' OR kind = 'twitch_hyper_chat' \
+        OR kind = 'twitch_subscription' OR kind = 'twitch_gift_subscription')",
+        start_at
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_events", query)
+}
+
+/// amounts of bilibili events are converted from gold coins to CNY
+pub async fn list_bilibili_channel_revenue_events(
+    start_at: DateTime<Utc>,
+    pool: &PgPool,
+) -> Result<Vec<ChannelRevenueEvent>> {
+    let query = sqlx::query_as!(
+        ChannelRevenueEvent,
+        "SELECT channel_id, time, \
+        stream_event_symbol(kind, value) symbol, \
+        stream_event_amount(kind, value) amount \
+        FROM stream_events LEFT JOIN streams ON streams.stream_id = stream_events.stream_id \
+        WHERE time > $1 AND (kind = 'bilibili_super_chat' OR kind = 'bilibili_gift' OR kind = 'bilibili_guard')",
         start_at
     )
     .fetch_all(pool);
@@ -64,18 +86,46 @@ pub async fn list_channel_revenue_events(
     let query = sqlx::query_as!(
         ChannelRevenueEvent,
         "SELECT channel_id, time, \
-        CASE WHEN kind = 'twitch_cheering' THEN 'cheering' \
-        WHEN kind = 'twitch_hyper_chat' THEN (value->>'currency_code') \
-        ELSE (value->>'paid_currency_symbol') END symbol, \
-        CASE WHEN kind = 'twitch_cheering' THEN (value->>'bits') \
-        WHEN kind = 'twitch_hyper_chat' THEN (value->>'amount') \
-        ELSE (value->>'paid_amount') END amount \
+        stream_event_symbol(kind, value) symbol, \
+        stream_event_amount(kind, value) amount \
         FROM stream_events LEFT JOIN streams ON streams.stream_id = stream_events.stream_id \
         WHERE channel_id = $1 AND (kind = 'youtube_super_chat' OR kind = 'youtube_super_sticker' \
-        OR kind = 'twitch_cheering' OR kind = 'twitch_hyper_chat')",
+        OR kind = 'twitch_cheering' OR kind = 'twitch_hyper_chat' \
+        OR kind = 'twitch_subscription' OR kind = 'twitch_gift_subscription' \
+        OR kind = 'bilibili_super_chat' OR kind = 'bilibili_gift' OR kind = 'bilibili_guard')",
         channel_id
     )
     .fetch_all(pool);
 
     crate::otel::execute_query!("SELECT", "stream_events", query)
 }
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    sqlx::query!(
+        "INSERT INTO stream_events (stream_id, time, kind, value) VALUES \
+        (1, to_timestamp(100), 'twitch_subscription', \
+        '{\"author_username\":\"a\",\"plan\":\"Prime\",\"cumulative_months\":\"1\"}'), \
+        (1, to_timestamp(200), 'twitch_subscription', \
+        '{\"author_username\":\"b\",\"plan\":\"3000\",\"cumulative_months\":\"2\"}'), \
+        (1, to_timestamp(300), 'twitch_gift_subscription', \
+        '{\"author_username\":\"c\",\"plan\":\"2000\",\"count\":5}'), \
+        (1, to_timestamp(400), 'bilibili_gift', '{\"total_coin\":1500}')"
+    )
+    .execute(&pool)
+    .await?;
+
+    let mut events = list_channel_revenue_events(1, &pool).await?;
+    events.sort_by_key(|e| e.time);
+
+    assert_eq!(events.len(), 4);
+    assert_eq!(events[0].amount.as_deref(), Some("4.99"));
+    assert_eq!(events[0].symbol.as_deref(), Some("USD"));
+    assert_eq!(events[1].amount.as_deref(), Some("24.99"));
+    assert_eq!(events[2].amount.as_deref(), Some("49.95"));
+    assert_eq!(events[3].amount.as_deref(), Some("1.500"));
+    assert_eq!(events[3].symbol.as_deref(), Some("CNY"));
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Result};
+
+#[derive(Debug, PartialEq)]
+pub struct SupporterRow {
+    /// youtube channel id, twitch login or bilibili uid
+    pub author_id: String,
+    /// the latest name seen
+    pub author_name: Option<String>,
+    /// total amount in USD, converted with the exchange rates of the
+    /// month of each event
+    pub value: f64,
+    /// number of paid events, including ones in unknown currencies
+    pub count: i64,
+    /// distinct youtube badges, e.g. `Member (6 months)`
+    pub badges: Vec<String>,
+    /// longest months subscribed of twitch subscriptions
+    pub cumulative_months: Option<i32>,
+    pub last_supported_at: DateTime<Utc>,
+}
+
+/// list authors of paid events of given channel or stream, ranked by total
+/// amount then count, membership events only contribute badges, authors in
+/// `supporter_opt_outs` of the channel are excluded, gifted youtube
+/// memberships are left out as their price is unknown
+pub async fn list_supporters(
+    channel_id: Option<i32>,
+    stream_id: Option<i32>,
+    start_at: Option<DateTime<Utc>>,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<SupporterRow>> {
+    let query = sqlx::query_as!(
+        SupporterRow,
+        "WITH events AS (\
+            SELECT streams.channel_id, kind, time, value, \
+            COALESCE(value->>'author_channel_id', value->>'author_username', value->>'author_uid') author_id, \
+            kind NOT IN ('youtube_new_member', 'youtube_member_milestone') paid \
+            FROM stream_events JOIN streams ON streams.stream_id = stream_events.stream_id \
+            WHERE (streams.channel_id = $1 OR $1 IS NULL) \
+            AND (stream_events.stream_id = $2 OR $2 IS NULL) \
+            AND (time >= $3 OR $3 IS NULL) \
+            AND (kind = 'youtube_super_chat' OR kind = 'youtube_super_sticker' \
+            OR kind = 'youtube_new_member' OR kind = 'youtube_member_milestone' \
+            OR kind = 'twitch_cheering' OR kind = 'twitch_hyper_chat' \
+            OR kind = 'twitch_subscription' OR kind = 'twitch_gift_subscription' \
+            OR kind = 'bilibili_super_chat' OR kind = 'bilibili_gift' OR kind = 'bilibili_guard')\
+        ) \
+        SELECT author_id as \"author_id!\", \
+        (array_agg(COALESCE(value->>'author_name', value->>'author_username') ORDER BY time DESC) \
+            FILTER (WHERE COALESCE(value->>'author_name', value->>'author_username') IS NOT NULL))[1] author_name, \
+        COALESCE(sum(stream_event_usd(kind, value, time)) FILTER (WHERE paid), 0) as \"value!\", \
+        count(*) FILTER (WHERE paid) as \"count!\", \
+        COALESCE(array_agg(DISTINCT value->>'author_badges') FILTER (WHERE value->>'author_badges' IS NOT NULL), '{}') as \"badges!\", \
+        max(CASE WHEN value->>'cumulative_months' ~ '^[0-9]+$' THEN (value->>'cumulative_months')::int END) cumulative_months, \
+        max(time) FILTER (WHERE paid) as \"last_supported_at!\" \
+        FROM events \
+        WHERE author_id IS NOT NULL \
+        AND NOT EXISTS (\
+            SELECT 1 FROM supporter_opt_outs o \
+            WHERE o.channel_id = events.channel_id AND o.author_id = events.author_id\
+        ) \
+        GROUP BY author_id \
+        HAVING count(*) FILTER (WHERE paid) > 0 \
+        ORDER BY 3 DESC, 4 DESC, author_id \
+        LIMIT $4",
+        channel_id,
+        stream_id,
+        start_at,
+        limit,
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_events", query)
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::TimeZone;
+
+    use super::{add_supporter_opt_out, delete_supporter_opt_out, SupporterOptOut};
+
+    sqlx::query!(
+        "INSERT INTO exchange_rate_history (code, rate, valid_from, valid_to) VALUES \
+        ('USD', 1, to_timestamp(0), to_timestamp(2678400)), \
+        ('JPY', 100, to_timestamp(0), to_timestamp(2678400)), \
+        ('USD', 1, to_timestamp(2678400), NULL), \
+        ('JPY', 200, to_timestamp(2678400), NULL)"
+    )
+    .execute(&pool)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO stream_events (stream_id, time, kind, value) VALUES \
+        (1, to_timestamp(9010), 'youtube_super_chat', \
+        '{\"author_name\":\"a1\",\"author_channel_id\":\"UCa\",\"paid_amount\":\"5.00\",\"paid_currency_symbol\":\"$\",\"paid_color\":\"\",\"money\":{\"amount\":\"5.00\",\"currency\":\"USD\"}}'), \
+        (1, to_timestamp(9020), 'youtube_super_chat', \
+        '{\"author_name\":\"a2\",\"author_channel_id\":\"UCa\",\"paid_amount\":\"500\",\"paid_currency_symbol\":\"¥\",\"paid_color\":\"\",\"money\":{\"amount\":\"500\",\"currency\":\"JPY\"}}'), \
+        (1, to_timestamp(9030), 'youtube_member_milestone', \
+        '{\"author_name\":\"a3\",\"author_badges\":\"Member (1 year)\",\"author_channel_id\":\"UCa\"}'), \
+        (1, to_timestamp(9000), 'twitch_cheering', \
+        '{\"author_username\":\"b\",\"bits\":1000}'), \
+        (1, to_timestamp(9000), 'youtube_super_chat', \
+        '{\"author_name\":\"c\",\"author_channel_id\":\"UCc\",\"paid_amount\":\"1\",\"paid_currency_symbol\":\"?\",\"paid_color\":\"\"}'), \
+        (1, to_timestamp(9000), 'youtube_new_member', \
+        '{\"author_name\":\"d\",\"author_channel_id\":\"UCd\"}'), \
+        (1, to_timestamp(2687400), 'youtube_super_chat', \
+        '{\"author_name\":\"e\",\"author_channel_id\":\"UCe\",\"paid_amount\":\"500\",\"paid_currency_symbol\":\"¥\",\"paid_color\":\"\",\"money\":{\"amount\":\"500\",\"currency\":\"JPY\"}}'), \
+        (1, to_timestamp(9000), 'youtube_membership_gift', \
+        '{\"author_name\":\"f\",\"author_channel_id\":\"UCf\",\"count\":5}')"
+    )
+    .execute(&pool)
+    .await?;
+
+    let time = |secs| Utc.timestamp_opt(secs, 0).single().unwrap();
+
+    let supporters = list_supporters(Some(1), None, None, 10, &pool).await?;
+    assert_eq!(
+        supporters,
+        vec![
+            SupporterRow {
+                author_id: "UCa".into(),
+                author_name: Some("a3".into()),
+                value: 10.0,
+                count: 2,
+                badges: vec!["Member (1 year)".into()],
+                cumulative_months: None,
+                last_supported_at: time(9020),
+            },
+            SupporterRow {
+                author_id: "b".into(),
+                author_name: Some("b".into()),
+                value: 10.0,
+                count: 1,
+                badges: vec![],
+                cumulative_months: None,
+                last_supported_at: time(9000),
+            },
+            SupporterRow {
+                author_id: "UCe".into(),
+                author_name: Some("e".into()),
+                value: 2.5,
+                count: 1,
+                badges: vec![],
+                cumulative_months: None,
+                last_supported_at: time(2687400),
+            },
+            SupporterRow {
+                author_id: "UCc".into(),
+                author_name: Some("c".into()),
+                value: 0.0,
+                count: 1,
+                badges: vec![],
+                cumulative_months: None,
+                last_supported_at: time(9000),
+            },
+        ]
+    );
+
+    let supporters = list_supporters(None, Some(1), None, 1, &pool).await?;
+    assert_eq!(supporters.len(), 1);
+    assert_eq!(supporters[0].author_id, "UCa");
+
+    let supporters = list_supporters(Some(1), None, Some(time(9015)), 10, &pool).await?;
+    assert_eq!(supporters.len(), 2);
+
+    let opt_out = SupporterOptOut {
+        channel_id: 1,
+        author_id: "UCa".into(),
+    };
+    add_supporter_opt_out(&opt_out, &pool).await?;
+    let supporters = list_supporters(Some(1), None, None, 10, &pool).await?;
+    assert_eq!(supporters.len(), 3);
+    assert_eq!(supporters[0].author_id, "b");
+
+    delete_supporter_opt_out(&opt_out, &pool).await?;
+    let supporters = list_supporters(Some(1), None, None, 10, &pool).await?;
+    assert_eq!(supporters.len(), 4);
+
+    Ok(())
+}
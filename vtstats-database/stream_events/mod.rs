@@ -1,5 +1,10 @@
 mod add_stream_events;
 mod list_stream_events;
+mod list_supporters;
+mod money;
+mod paid_event_values;
+mod rank_revenue;
+mod supporter_opt_outs;
 
 use chrono::DateTime;
 use chrono::Utc;
@@ -9,6 +14,11 @@ use sqlx::{postgres::PgRow, types::Json, FromRow, Row};
 
 pub use self::add_stream_events::*;
 pub use self::list_stream_events::*;
+pub use self::list_supporters::*;
+pub use self::money::*;
+pub use self::paid_event_values::*;
+pub use self::rank_revenue::*;
+pub use self::supporter_opt_outs::*;
 
 #[derive(Debug, sqlx::Type, Clone, Copy, Serialize)]
 #[sqlx(type_name = "stream_event_kind", rename_all = "snake_case")]
@@ -20,6 +30,14 @@ pub enum StreamEventKind {
     YoutubeMemberMilestone,
     TwitchCheering,
     TwitchHyperChat,
+    BilibiliSuperChat,
+    BilibiliGift,
+    BilibiliGuard,
+    TwitchSubscription,
+    TwitchGiftSubscription,
+    TwitchRaid,
+    YoutubeMembershipGift,
+    YoutubeMembershipGiftRedemption,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -33,6 +51,9 @@ pub struct YoutubeSuperChat {
     pub paid_amount: String,
     pub paid_currency_symbol: String,
     pub paid_color: String,
+    /// `None` if currency symbol or amount can't be recognized
+    #[serde(default)]
+    pub money: Option<Money>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -46,6 +67,9 @@ pub struct YoutubeSuperSticker {
     pub paid_amount: String,
     pub paid_currency_symbol: String,
     pub paid_color: String,
+    /// `None` if currency symbol or amount can't be recognized
+    #[serde(default)]
+    pub money: Option<Money>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -65,6 +89,25 @@ pub struct YoutubeMemberMilestone {
     pub author_channel_id: String,
 }
 
+/// `count` memberships gifted to other viewers at once
+#[derive(Debug, Serialize, Deserialize)]
+pub struct YoutubeMembershipGift {
+    pub author_name: String,
+    #[serde(default)]
+    pub author_badges: Option<String>,
+    pub author_channel_id: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct YoutubeMembershipGiftRedemption {
+    pub message: String,
+    pub author_name: String,
+    #[serde(default)]
+    pub author_badges: Option<String>,
+    pub author_channel_id: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TwitchCheering {
     pub author_username: String,
@@ -80,9 +123,70 @@ pub struct TwitchHyperChat {
     #[serde(default)]
     pub badges: Option<String>,
     pub message: String,
-    pub currency_code: String,
     pub level: String,
-    pub amount: String,
+    /// `None` for events recorded before amounts were parsed, until
+    /// `backfill_stream_event_money` job fills it
+    #[serde(default)]
+    pub money: Option<Money>,
+}
+
+/// `plan` is one of `Prime`, `1000`, `2000` and `3000`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TwitchSubscription {
+    pub author_username: String,
+    #[serde(default)]
+    pub badges: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+    pub plan: String,
+    pub cumulative_months: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TwitchGiftSubscription {
+    pub author_username: String,
+    #[serde(default)]
+    pub badges: Option<String>,
+    pub plan: String,
+    pub count: i64,
+    #[serde(default)]
+    pub recipient_username: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TwitchRaid {
+    pub from_username: String,
+    pub viewer_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BilibiliSuperChat {
+    pub author_uid: i64,
+    pub author_name: String,
+    pub message: String,
+    /// in CNY
+    pub price: i64,
+}
+
+/// only gifts paid with gold coins are recorded
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BilibiliGift {
+    pub author_uid: i64,
+    pub author_name: String,
+    pub gift_name: String,
+    pub num: i64,
+    /// in gold coins, 1000 coins for 1 CNY
+    pub total_coin: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BilibiliGuard {
+    pub author_uid: i64,
+    pub author_name: String,
+    pub guard_level: i32,
+    pub num: i64,
+    /// price of each month in gold coins, 1000 coins for 1 CNY
+    pub price: i64,
 }
 
 #[derive(Debug, Serialize)]
@@ -94,6 +198,14 @@ pub enum StreamEventValue {
     YoutubeMemberMilestone(YoutubeMemberMilestone),
     TwitchCheering(TwitchCheering),
     TwitchHyperChat(TwitchHyperChat),
+    BilibiliSuperChat(BilibiliSuperChat),
+    BilibiliGift(BilibiliGift),
+    BilibiliGuard(BilibiliGuard),
+    TwitchSubscription(TwitchSubscription),
+    TwitchGiftSubscription(TwitchGiftSubscription),
+    TwitchRaid(TwitchRaid),
+    YoutubeMembershipGift(YoutubeMembershipGift),
+    YoutubeMembershipGiftRedemption(YoutubeMembershipGiftRedemption),
 }
 
 impl StreamEventValue {
@@ -105,6 +217,16 @@ impl StreamEventValue {
             StreamEventValue::YoutubeMemberMilestone(_) => StreamEventKind::YoutubeMemberMilestone,
             StreamEventValue::TwitchCheering(_) => StreamEventKind::TwitchCheering,
             StreamEventValue::TwitchHyperChat(_) => StreamEventKind::TwitchHyperChat,
+            StreamEventValue::BilibiliSuperChat(_) => StreamEventKind::BilibiliSuperChat,
+            StreamEventValue::BilibiliGift(_) => StreamEventKind::BilibiliGift,
+            StreamEventValue::BilibiliGuard(_) => StreamEventKind::BilibiliGuard,
+            StreamEventValue::TwitchSubscription(_) => StreamEventKind::TwitchSubscription,
+            StreamEventValue::TwitchGiftSubscription(_) => StreamEventKind::TwitchGiftSubscription,
+            StreamEventValue::TwitchRaid(_) => StreamEventKind::TwitchRaid,
+            StreamEventValue::YoutubeMembershipGift(_) => StreamEventKind::YoutubeMembershipGift,
+            StreamEventValue::YoutubeMembershipGiftRedemption(_) => {
+                StreamEventKind::YoutubeMembershipGiftRedemption
+            }
         }
     }
 }
@@ -139,6 +261,32 @@ impl FromRow<'_, PgRow> for StreamEvent {
             StreamEventKind::TwitchHyperChat => {
                 StreamEventValue::TwitchHyperChat(row.try_get::<Json<_>, _>("value")?.0)
             }
+            StreamEventKind::BilibiliSuperChat => {
+                StreamEventValue::BilibiliSuperChat(row.try_get::<Json<_>, _>("value")?.0)
+            }
+            StreamEventKind::BilibiliGift => {
+                StreamEventValue::BilibiliGift(row.try_get::<Json<_>, _>("value")?.0)
+            }
+            StreamEventKind::BilibiliGuard => {
+                StreamEventValue::BilibiliGuard(row.try_get::<Json<_>, _>("value")?.0)
+            }
+            StreamEventKind::TwitchSubscription => {
+                StreamEventValue::TwitchSubscription(row.try_get::<Json<_>, _>("value")?.0)
+            }
+            StreamEventKind::TwitchGiftSubscription => {
+                StreamEventValue::TwitchGiftSubscription(row.try_get::<Json<_>, _>("value")?.0)
+            }
+            StreamEventKind::TwitchRaid => {
+                StreamEventValue::TwitchRaid(row.try_get::<Json<_>, _>("value")?.0)
+            }
+            StreamEventKind::YoutubeMembershipGift => {
+                StreamEventValue::YoutubeMembershipGift(row.try_get::<Json<_>, _>("value")?.0)
+            }
+            StreamEventKind::YoutubeMembershipGiftRedemption => {
+                StreamEventValue::YoutubeMembershipGiftRedemption(
+                    row.try_get::<Json<_>, _>("value")?.0,
+                )
+            }
         };
 
         Ok(StreamEvent { time, kind, value })
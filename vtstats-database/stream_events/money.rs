@@ -0,0 +1,161 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use vtstats_utils::currency::{CurrencyCode, CurrencySymbol};
+
+/// amount in major units with ISO 4217 currency code,
+/// amount is stored as string to keep its precision
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Money {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+    pub currency: CurrencyCode,
+    /// currency is guessed from a symbol shared by several ones, e.g. `$`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub ambiguous: bool,
+}
+
+impl Money {
+    /// e.g. amount `200` with exponent `2` is `2.00`
+    pub fn from_minor_units(amount: i64, exponent: u32, currency: CurrencyCode) -> Option<Self> {
+        Some(Money {
+            amount: Decimal::try_new(amount, exponent).ok()?,
+            currency,
+            ambiguous: false,
+        })
+    }
+
+    /// parses locale-formatted amount, e.g. `1,000.50`, `1.000,50` or `1 000`
+    pub fn from_localized(amount: &str, currency: CurrencySymbol) -> Option<Self> {
+        Some(Money {
+            amount: parse_localized_amount(amount, currency.code.exponent())?,
+            currency: currency.code,
+            ambiguous: currency.ambiguous,
+        })
+    }
+}
+
+/// the last separator is treated as group separator only if it's followed
+/// by three digits, no other kind of separator shows up before it, and the
+/// currency doesn't have three decimal digits or the same separator shows
+/// up before it, e.g. `JPY 1.000` and `KWD 1,000,000`, otherwise it's
+/// a decimal separator, e.g. `KWD 1.500`
+fn parse_localized_amount(amount: &str, exponent: u32) -> Option<Decimal> {
+    let amount: String = amount
+        .chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != '\'')
+        .collect();
+
+    let (integer, fraction) = match amount.rfind(['.', ',']) {
+        Some(i) => {
+            let (integer, fraction) = (&amount[..i], &amount[i + 1..]);
+            let separator = if amount.as_bytes()[i] == b'.' {
+                '.'
+            } else {
+                ','
+            };
+            let other = if separator == '.' { ',' } else { '.' };
+            if fraction.len() == 3
+                && !integer.contains(other)
+                && (exponent != 3 || integer.contains(separator))
+            {
+                (amount.as_str(), "")
+            } else if integer.contains(separator) {
+                return None;
+            } else {
+                (integer, fraction)
+            }
+        }
+        None => (amount.as_str(), ""),
+    };
+
+    let integer = integer.replace(['.', ','], "");
+
+    if integer.is_empty()
+        || !integer.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    if fraction.is_empty() {
+        integer.parse().ok()
+    } else {
+        format!("{integer}.{fraction}").parse().ok()
+    }
+}
+
+#[test]
+fn test_parse_localized_amount() {
+    let parse = |s: &str| parse_localized_amount(s, 2).map(|d| d.to_string());
+
+    assert_eq!(parse("5"), Some("5".into()));
+    assert_eq!(parse("5.00"), Some("5.00".into()));
+    assert_eq!(parse("1,000"), Some("1000".into()));
+    assert_eq!(parse("1.000"), Some("1000".into()));
+    assert_eq!(parse("1,000.50"), Some("1000.50".into()));
+    assert_eq!(parse("1.000,50"), Some("1000.50".into()));
+    assert_eq!(parse("1 000,5"), Some("1000.5".into()));
+    assert_eq!(parse("1\u{a0}234\u{a0}567"), Some("1234567".into()));
+    assert_eq!(parse("12'345.00"), Some("12345.00".into()));
+    assert_eq!(parse("1,000,000"), Some("1000000".into()));
+    assert_eq!(parse("1,000.500"), Some("1000.500".into()));
+    assert_eq!(parse("99.9999"), Some("99.9999".into()));
+    assert_eq!(parse("10.0.00"), None);
+    assert_eq!(parse(""), None);
+    assert_eq!(parse(",50"), None);
+    assert_eq!(parse("abc"), None);
+
+    // three decimal digits, e.g. KWD
+    let parse = |s: &str| parse_localized_amount(s, 3).map(|d| d.to_string());
+
+    assert_eq!(parse("1.500"), Some("1.500".into()));
+    assert_eq!(parse("1,500"), Some("1.500".into()));
+    assert_eq!(parse("1,000,000"), Some("1000000".into()));
+    assert_eq!(parse("1,000.500"), Some("1000.500".into()));
+    assert_eq!(parse("1.000,500"), Some("1000.500".into()));
+}
+
+#[test]
+fn test_money() {
+    let (Some(usd), Some(jpy), Some(bhd)) = (
+        CurrencyCode::new("USD"),
+        CurrencyCode::new("JPY"),
+        CurrencyCode::new("BHD"),
+    ) else {
+        panic!("invalid currency codes");
+    };
+    let currency = |code, ambiguous| CurrencySymbol { code, ambiguous };
+
+    let money = Money::from_minor_units(200, 2, usd);
+    assert_eq!(
+        money.as_ref().map(|m| m.amount.to_string()),
+        Some("2.00".into())
+    );
+    assert_eq!(
+        money.and_then(|m| serde_json::to_value(m).ok()),
+        Some(serde_json::json!({ "amount": "2.00", "currency": "USD" }))
+    );
+
+    assert_eq!(
+        Money::from_minor_units(500, 0, jpy),
+        Some(Money {
+            amount: Decimal::from(500),
+            currency: jpy,
+            ambiguous: false,
+        })
+    );
+
+    assert_eq!(
+        Money::from_localized("1.500", currency(bhd, false)).map(|m| m.amount.to_string()),
+        Some("1.500".into())
+    );
+    assert_eq!(
+        Money::from_localized("1.500", currency(jpy, false)).map(|m| m.amount.to_string()),
+        Some("1500".into())
+    );
+
+    assert_eq!(
+        Money::from_localized("5", currency(usd, true)).and_then(|m| serde_json::to_value(m).ok()),
+        Some(serde_json::json!({ "amount": "5", "currency": "USD", "ambiguous": true }))
+    );
+}
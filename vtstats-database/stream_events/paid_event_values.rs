@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{PgPool, Result};
+
+use super::StreamEventKind;
+
+/// youtube super chats, stickers and twitch hyper chats in raw json,
+/// so that values recorded in old formats can be read as well
+#[derive(Debug)]
+pub struct PaidEventValue {
+    pub stream_id: i32,
+    pub time: DateTime<Utc>,
+    pub kind: StreamEventKind,
+    pub value: Value,
+}
+
+/// lists paid events of the next `limit` streams after given stream id
+pub async fn list_paid_event_values(
+    after_stream_id: i32,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<PaidEventValue>> {
+    let query = sqlx::query_as!(
+        PaidEventValue,
+        "SELECT stream_id, time, kind as \"kind: _\", value FROM stream_events \
+        WHERE kind IN ('youtube_super_chat', 'youtube_super_sticker', 'twitch_hyper_chat') \
+        AND stream_id IN (\
+            SELECT DISTINCT stream_id FROM stream_events \
+            WHERE stream_id > $1 \
+            AND kind IN ('youtube_super_chat', 'youtube_super_sticker', 'twitch_hyper_chat') \
+            ORDER BY stream_id LIMIT $2\
+        )",
+        after_stream_id,
+        limit,
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_events", query)
+}
+
+/// replaces value of each event, events are matched by their old value
+pub async fn update_paid_event_values(
+    events: &[(PaidEventValue, Value)],
+    pool: &PgPool,
+) -> Result<()> {
+    let stream_ids: Vec<_> = events.iter().map(|(e, _)| e.stream_id).collect();
+    let times: Vec<_> = events.iter().map(|(e, _)| e.time).collect();
+    let old_values: Vec<_> = events.iter().map(|(e, _)| e.value.clone()).collect();
+    let new_values: Vec<_> = events.iter().map(|(_, v)| v.clone()).collect();
+
+    let query = sqlx::query!(
+        "UPDATE stream_events e SET value = u.new_value \
+        FROM unnest($1::int[], $2::timestamptz[], $3::jsonb[], $4::jsonb[]) \
+        AS u(stream_id, time, old_value, new_value) \
+        WHERE e.stream_id = u.stream_id AND e.time = u.time AND e.value = u.old_value",
+        &stream_ids,
+        &times,
+        &old_values,
+        &new_values,
+    )
+    .execute(pool);
+
+    crate::otel::execute_query!("UPDATE", "stream_events", query)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    sqlx::query!(
+        "INSERT INTO stream_events (stream_id, time, kind, value) VALUES \
+        (1, to_timestamp(100), 'twitch_hyper_chat', '{\"amount\":\"200\",\"currency_code\":\"USD\"}'), \
+        (1, to_timestamp(200), 'twitch_cheering', '{\"bits\":\"100\"}')"
+    )
+    .execute(&pool)
+    .await?;
+
+    let events = list_paid_event_values(0, 10, &pool).await?;
+    assert_eq!(events.len(), 1);
+    assert!(list_paid_event_values(1, 10, &pool).await?.is_empty());
+
+    let value = serde_json::json!({ "money": { "amount": "2.00", "currency": "USD" } });
+    let events: Vec<_> = events.into_iter().map(|e| (e, value.clone())).collect();
+    update_paid_event_values(&events, &pool).await?;
+
+    let events = list_paid_event_values(0, 10, &pool).await?;
+    assert_eq!(events[0].value, value);
+
+    Ok(())
+}
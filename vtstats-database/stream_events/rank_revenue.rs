@@ -0,0 +1,194 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Result};
+
+use crate::channels::Platform;
+
+#[derive(Debug, PartialEq)]
+pub struct StreamRevenue {
+    pub stream_id: i32,
+    /// total amount in USD
+    pub value: f64,
+}
+
+/// ranks streams started in given time window by revenue in USD, optionally
+/// filtered by platform and group (including nested groups), events are
+/// converted with the exchange rates of their month, see `stream_event_usd`
+pub async fn rank_streams_by_revenue(
+    platform: Option<Platform>,
+    group_id: Option<&str>,
+    start_at: DateTime<Utc>,
+    end_at: DateTime<Utc>,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<StreamRevenue>> {
+    let query = sqlx::query_as!(
+        StreamRevenue,
+        "WITH RECURSIVE members(id) AS (\
+            SELECT unnest(children) FROM groups WHERE group_id = $2 \
+            UNION SELECT unnest(g.children) FROM groups g JOIN members m ON g.group_id = m.id\
+        ) \
+        SELECT stream_events.stream_id, sum(stream_event_usd(kind, value, time)) as \"value!\" \
+        FROM stream_events JOIN streams ON streams.stream_id = stream_events.stream_id \
+        WHERE (streams.platform = $1 OR $1 IS NULL) \
+        AND (streams.vtuber_id IN (SELECT id FROM members) OR $2 IS NULL) \
+        AND streams.start_time >= $3 AND streams.start_time < $4 \
+        AND (kind = 'youtube_super_chat' OR kind = 'youtube_super_sticker' \
+        OR kind = 'twitch_cheering' OR kind = 'twitch_hyper_chat' \
+        OR kind = 'twitch_subscription' OR kind = 'twitch_gift_subscription' \
+        OR kind = 'bilibili_super_chat' OR kind = 'bilibili_gift' OR kind = 'bilibili_guard') \
+        GROUP BY stream_events.stream_id \
+        HAVING sum(stream_event_usd(kind, value, time)) IS NOT NULL \
+        ORDER BY 2 DESC, 1 \
+        LIMIT $5",
+        platform as _,
+        group_id,
+        start_at,
+        end_at,
+        limit,
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_events", query)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ChannelRevenue {
+    pub channel_id: i32,
+    /// total amount in USD
+    pub value: f64,
+    /// total amount in USD before `since`
+    pub previous_value: f64,
+}
+
+/// ranks channels of active vtubers by revenue in USD since given time,
+/// optionally filtered by platform and group (including nested groups),
+/// events are converted with the exchange rates of their month, see
+/// `stream_event_usd`
+pub async fn rank_channels_by_revenue(
+    platform: Option<Platform>,
+    group_id: Option<&str>,
+    since: DateTime<Utc>,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<ChannelRevenue>> {
+    let query = sqlx::query_as!(
+        ChannelRevenue,
+        "WITH RECURSIVE members(id) AS (\
+            SELECT unnest(children) FROM groups WHERE group_id = $2 \
+            UNION SELECT unnest(g.children) FROM groups g JOIN members m ON g.group_id = m.id\
+        ), \
+        events AS (\
+            SELECT streams.channel_id, e.time, stream_event_usd(e.kind, e.value, e.time) usd \
+            FROM stream_events e JOIN streams ON streams.stream_id = e.stream_id \
+            JOIN channels c ON c.channel_id = streams.channel_id \
+            WHERE (c.platform = $1 OR $1 IS NULL) \
+            AND (c.vtuber_id IN (SELECT id FROM members) OR $2 IS NULL) \
+            AND c.vtuber_id IN \
+            (SELECT vtuber_id FROM vtubers WHERE retired_at IS NULL OR retired_at + '2 week' >= NOW()) \
+            AND (e.kind = 'youtube_super_chat' OR e.kind = 'youtube_super_sticker' \
+            OR e.kind = 'twitch_cheering' OR e.kind = 'twitch_hyper_chat' \
+            OR e.kind = 'twitch_subscription' OR e.kind = 'twitch_gift_subscription' \
+            OR e.kind = 'bilibili_super_chat' OR e.kind = 'bilibili_gift' OR e.kind = 'bilibili_guard')\
+        ), \
+        top AS (\
+            SELECT channel_id, sum(usd) delta FROM events \
+            WHERE time >= $3 GROUP BY channel_id \
+            HAVING sum(usd) IS NOT NULL \
+            ORDER BY 2 DESC, 1 LIMIT $4\
+        ) \
+        SELECT top.channel_id as \"channel_id!\", \
+        COALESCE(sum(events.usd), 0) as \"value!\", \
+        COALESCE(sum(events.usd) FILTER (WHERE events.time < $3), 0) as \"previous_value!\" \
+        FROM top JOIN events ON events.channel_id = top.channel_id \
+        GROUP BY top.channel_id, top.delta \
+        ORDER BY top.delta DESC, top.channel_id",
+        platform as _,
+        group_id,
+        since,
+        limit,
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_events", query)
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::TimeZone;
+
+    sqlx::query!(
+        "INSERT INTO exchange_rate_history (code, rate, valid_from, valid_to) VALUES \
+        ('USD', 1, to_timestamp(0), to_timestamp(2678400)), \
+        ('JPY', 100, to_timestamp(0), to_timestamp(2678400)), \
+        ('USD', 1, to_timestamp(2678400), NULL), \
+        ('JPY', 200, to_timestamp(2678400), NULL)"
+    )
+    .execute(&pool)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO streams (stream_id, platform, platform_id, title, channel_id, status, vtuber_id, start_time) VALUES \
+        (2, 'youtube', 'id2', 'title2', 1, 'ended', 'vtuber1', to_timestamp(1000)), \
+        (3, 'youtube', 'id3', 'title3', 1, 'ended', 'vtuber1', to_timestamp(2680000))"
+    )
+    .execute(&pool)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO stream_events (stream_id, time, kind, value) VALUES \
+        (2, to_timestamp(1000), 'youtube_super_chat', \
+        '{\"author_name\":\"a\",\"author_channel_id\":\"UCa\",\"paid_amount\":\"1000\",\"paid_currency_symbol\":\"¥\",\"paid_color\":\"\",\"money\":{\"amount\":\"1000\",\"currency\":\"JPY\"}}'), \
+        (2, to_timestamp(1000), 'youtube_super_chat', \
+        '{\"author_name\":\"b\",\"author_channel_id\":\"UCb\",\"paid_amount\":\"1\",\"paid_currency_symbol\":\"?\",\"paid_color\":\"\"}'), \
+        (3, to_timestamp(2680000), 'youtube_super_chat', \
+        '{\"author_name\":\"a\",\"author_channel_id\":\"UCa\",\"paid_amount\":\"1000\",\"paid_currency_symbol\":\"¥\",\"paid_color\":\"\",\"money\":{\"amount\":\"1000\",\"currency\":\"JPY\"}}'), \
+        (3, to_timestamp(2680000), 'youtube_new_member', \
+        '{\"author_name\":\"c\",\"author_channel_id\":\"UCc\"}')"
+    )
+    .execute(&pool)
+    .await?;
+
+    let time = |secs| Utc.timestamp_opt(secs, 0).single().unwrap();
+
+    let streams = rank_streams_by_revenue(None, None, time(0), time(2700000), 10, &pool).await?;
+    assert_eq!(
+        streams,
+        vec![
+            StreamRevenue {
+                stream_id: 2,
+                value: 10.0
+            },
+            StreamRevenue {
+                stream_id: 3,
+                value: 5.0
+            },
+        ]
+    );
+
+    let streams = rank_streams_by_revenue(
+        Some(Platform::Twitch),
+        None,
+        time(0),
+        time(2700000),
+        10,
+        &pool,
+    )
+    .await?;
+    assert!(streams.is_empty());
+
+    let channels = rank_channels_by_revenue(None, None, time(2678400), 10, &pool).await?;
+    assert_eq!(
+        channels,
+        vec![ChannelRevenue {
+            channel_id: 1,
+            value: 15.0,
+            previous_value: 10.0,
+        }]
+    );
+
+    let channels = rank_channels_by_revenue(None, None, time(2690000), 10, &pool).await?;
+    assert!(channels.is_empty());
+
+    Ok(())
+}
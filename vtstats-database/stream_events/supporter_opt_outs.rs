@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Result};
+
+/// supporter who asked not to be listed in the rankings of a channel
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SupporterOptOut {
+    pub channel_id: i32,
+    /// youtube channel id, twitch login or bilibili uid
+    pub author_id: String,
+}
+
+pub async fn list_supporter_opt_outs(pool: &PgPool) -> Result<Vec<SupporterOptOut>> {
+    let query = sqlx::query_as!(
+        SupporterOptOut,
+        "SELECT channel_id, author_id FROM supporter_opt_outs ORDER BY channel_id, author_id"
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "supporter_opt_outs", query)
+}
+
+pub async fn add_supporter_opt_out(opt_out: &SupporterOptOut, pool: &PgPool) -> Result<()> {
+    let query = sqlx::query!(
+        "INSERT INTO supporter_opt_outs (channel_id, author_id) VALUES ($1, $2) \
+        ON CONFLICT DO NOTHING",
+        opt_out.channel_id,
+        opt_out.author_id,
+    )
+    .execute(pool);
+
+    crate::otel::execute_query!("INSERT", "supporter_opt_outs", query)?;
+
+    Ok(())
+}
+
+pub async fn delete_supporter_opt_out(opt_out: &SupporterOptOut, pool: &PgPool) -> Result<()> {
+    let query = sqlx::query!(
+        "DELETE FROM supporter_opt_outs WHERE channel_id = $1 AND author_id = $2",
+        opt_out.channel_id,
+        opt_out.author_id,
+    )
+    .execute(pool);
+
+    crate::otel::execute_query!("DELETE", "supporter_opt_outs", query)?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use sqlx::{postgres::PgQueryResult, PgPool, Postgres, QueryBuilder, Result};
+
+pub struct AddStreamChatLanguagesQuery {
+    pub stream_id: i32,
+    pub rows: Vec<AddStreamChatLanguageRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddStreamChatLanguageRow {
+    /// ISO 639-3 code or `und`
+    pub language: String,
+    pub count: i32,
+}
+
+impl AddStreamChatLanguagesQuery {
+    pub async fn execute(self, pool: &PgPool) -> Result<PgQueryResult> {
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO stream_chat_languages AS s (stream_id, language, count) ",
+        );
+
+        query_builder.push_values(self.rows.iter(), |mut b, row| {
+            b.push_bind(self.stream_id)
+                .push_bind(&row.language)
+                .push_bind(row.count);
+        });
+
+        query_builder.push(
+            "ON CONFLICT (stream_id, language) DO UPDATE \
+            SET count = excluded.count + s.count",
+        );
+
+        let query = query_builder.build().execute(pool);
+
+        crate::otel::execute_query!("INSERT", "stream_chat_languages", query)
+    }
+}
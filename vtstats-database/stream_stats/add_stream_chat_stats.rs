@@ -10,25 +10,31 @@ pub struct AddStreamChatStatsRow {
     pub time: DateTime<Utc>,
     pub count: i32,
     pub from_member_count: i32,
+    /// unique chatters of the whole bucket so far, unlike other counts
+    /// it's not added up when the bucket is inserted again
+    pub chatter_count: i32,
 }
 
 impl AddStreamChatStatsQuery {
     pub async fn execute(self, pool: &PgPool) -> Result<PgQueryResult> {
         let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
-            "INSERT INTO stream_chat_stats AS s (stream_id, time, count, from_member_count) ",
+            "INSERT INTO stream_chat_stats AS s \
+            (stream_id, time, count, from_member_count, chatter_count) ",
         );
 
         query_builder.push_values(self.rows.iter(), |mut b, row| {
             b.push_bind(self.stream_id)
                 .push_bind(row.time)
                 .push_bind(row.count)
-                .push_bind(row.from_member_count);
+                .push_bind(row.from_member_count)
+                .push_bind(row.chatter_count);
         });
 
         query_builder.push(
             "ON CONFLICT (stream_id, time) DO UPDATE \
             SET count = excluded.count + s.count, \
-            from_member_count = excluded.from_member_count + s.from_member_count",
+            from_member_count = excluded.from_member_count + s.from_member_count, \
+            chatter_count = GREATEST(excluded.chatter_count, s.chatter_count)",
         );
 
         let query = query_builder.build().execute(pool);
@@ -51,16 +57,19 @@ async fn test(pool: PgPool) -> Result<()> {
                 time,
                 count: 70,
                 from_member_count: 30,
+                chatter_count: 20,
             },
             AddStreamChatStatsRow {
                 time: time + Duration::seconds(15),
                 count: 40,
                 from_member_count: 20,
+                chatter_count: 20,
             },
             AddStreamChatStatsRow {
                 time: time + Duration::seconds(30),
                 count: 35,
                 from_member_count: 15,
+                chatter_count: 20,
             },
         ],
     }
@@ -81,11 +90,13 @@ async fn test(pool: PgPool) -> Result<()> {
                 time: time + Duration::seconds(15),
                 count: 30,
                 from_member_count: 10,
+                chatter_count: 15,
             },
             AddStreamChatStatsRow {
                 time: time + Duration::seconds(30),
                 count: 35,
                 from_member_count: 15,
+                chatter_count: 20,
             },
         ],
     }
@@ -99,6 +110,7 @@ async fn test(pool: PgPool) -> Result<()> {
     assert_eq!(stats.len(), 3);
     assert!(stats.iter().all(|s| s.count == 70));
     assert!(stats.iter().all(|s| s.from_member_count == 30));
+    assert!(stats.iter().all(|s| s.chatter_count == 20));
     assert!(stats.iter().all(|s| s.time >= time));
 
     Ok(())
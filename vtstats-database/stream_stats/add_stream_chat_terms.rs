@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, PgPool, Postgres, QueryBuilder, Result};
+
+use super::ChatTermKind;
+
+pub struct AddStreamChatTermsQuery {
+    pub stream_id: i32,
+    pub rows: Vec<AddStreamChatTermRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddStreamChatTermRow {
+    pub time: DateTime<Utc>,
+    pub kind: ChatTermKind,
+    pub term: String,
+    pub count: i32,
+}
+
+impl AddStreamChatTermsQuery {
+    pub async fn execute(self, pool: &PgPool) -> Result<PgQueryResult> {
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO stream_chat_terms AS s (stream_id, time, kind, term, count) ",
+        );
+
+        query_builder.push_values(self.rows.iter(), |mut b, row| {
+            b.push_bind(self.stream_id)
+                .push_bind(row.time)
+                .push_bind(row.kind)
+                .push_bind(&row.term)
+                .push_bind(row.count);
+        });
+
+        query_builder.push(
+            "ON CONFLICT (stream_id, kind, term, time) DO UPDATE \
+            SET count = excluded.count + s.count",
+        );
+
+        let query = query_builder.build().execute(pool);
+
+        crate::otel::execute_query!("INSERT", "stream_chat_terms", query)
+    }
+}
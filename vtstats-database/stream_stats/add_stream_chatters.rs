@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgQueryResult, PgPool, Postgres, QueryBuilder, Result};
+
+pub struct AddStreamChattersQuery {
+    pub stream_id: i32,
+    pub rows: Vec<AddStreamChatterRow>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AddStreamChatterRow {
+    /// youtube channel id, twitch login or bilibili uid
+    pub author_id: String,
+    pub author_name: String,
+    pub message_count: i32,
+    pub first_message_at: DateTime<Utc>,
+    pub last_message_at: DateTime<Utc>,
+}
+
+impl AddStreamChattersQuery {
+    pub async fn execute(self, pool: &PgPool) -> Result<PgQueryResult> {
+        let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO stream_chatters AS s \
+            (stream_id, author_id, author_name, message_count, first_message_at, last_message_at) ",
+        );
+
+        query_builder.push_values(self.rows.iter(), |mut b, row| {
+            b.push_bind(self.stream_id)
+                .push_bind(&row.author_id)
+                .push_bind(&row.author_name)
+                .push_bind(row.message_count)
+                .push_bind(row.first_message_at)
+                .push_bind(row.last_message_at);
+        });
+
+        query_builder.push(
+            "ON CONFLICT (stream_id, author_id) DO UPDATE \
+            SET author_name = excluded.author_name, \
+            message_count = excluded.message_count + s.message_count, \
+            first_message_at = LEAST(excluded.first_message_at, s.first_message_at), \
+            last_message_at = GREATEST(excluded.last_message_at, s.last_message_at)",
+        );
+
+        let query = query_builder.build().execute(pool);
+
+        crate::otel::execute_query!("INSERT", "stream_chatters", query)
+    }
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::{Duration, TimeZone};
+
+    let time = Utc.timestamp_opt(9000, 0).single().unwrap();
+
+    let result = AddStreamChattersQuery {
+        stream_id: 1,
+        rows: vec![
+            AddStreamChatterRow {
+                author_id: "a".into(),
+                author_name: "A".into(),
+                message_count: 3,
+                first_message_at: time,
+                last_message_at: time + Duration::seconds(30),
+            },
+            AddStreamChatterRow {
+                author_id: "b".into(),
+                author_name: "B".into(),
+                message_count: 1,
+                first_message_at: time + Duration::seconds(15),
+                last_message_at: time + Duration::seconds(15),
+            },
+        ],
+    }
+    .execute(&pool)
+    .await?;
+    assert_eq!(result.rows_affected(), 2);
+
+    let result = AddStreamChattersQuery {
+        stream_id: 1,
+        rows: vec![AddStreamChatterRow {
+            author_id: "a".into(),
+            author_name: "A".into(),
+            message_count: 2,
+            first_message_at: time + Duration::seconds(45),
+            last_message_at: time + Duration::seconds(60),
+        }],
+    }
+    .execute(&pool)
+    .await?;
+    assert_eq!(result.rows_affected(), 1);
+
+    let chatters = sqlx::query!("SELECT * FROM stream_chatters ORDER BY author_id")
+        .fetch_all(&pool)
+        .await?;
+    assert_eq!(chatters.len(), 2);
+    assert_eq!(chatters[0].author_name, "A");
+    assert_eq!(chatters[0].message_count, 5);
+    assert_eq!(chatters[0].first_message_at, time);
+    assert_eq!(chatters[0].last_message_at, time + Duration::seconds(60));
+    assert_eq!(chatters[1].message_count, 1);
+
+    Ok(())
+}
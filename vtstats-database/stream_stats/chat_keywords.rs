@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Result};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatKeyword {
+    pub keyword: String,
+    /// matched case-insensitively against chat messages
+    pub patterns: Vec<String>,
+}
+
+pub async fn list_chat_keywords(pool: &PgPool) -> Result<Vec<ChatKeyword>> {
+    let query = sqlx::query_as!(
+        ChatKeyword,
+        "SELECT keyword, patterns FROM chat_keywords ORDER BY keyword"
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "chat_keywords", query)
+}
+
+pub async fn upsert_chat_keyword(keyword: &ChatKeyword, pool: &PgPool) -> Result<()> {
+    let query = sqlx::query!(
+        "INSERT INTO chat_keywords (keyword, patterns) VALUES ($1, $2) \
+        ON CONFLICT (keyword) DO UPDATE SET patterns = excluded.patterns",
+        keyword.keyword,
+        &keyword.patterns,
+    )
+    .execute(pool);
+
+    crate::otel::execute_query!("INSERT", "chat_keywords", query)?;
+
+    Ok(())
+}
+
+pub async fn delete_chat_keyword(keyword: &str, pool: &PgPool) -> Result<()> {
+    let query = sqlx::query!("DELETE FROM chat_keywords WHERE keyword = $1", keyword).execute(pool);
+
+    crate::otel::execute_query!("DELETE", "chat_keywords", query)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[sqlx::test]
+async fn test(pool: PgPool) -> Result<()> {
+    // seeded by migration
+    let keywords = list_chat_keywords(&pool).await?;
+    assert_eq!(keywords.len(), 2);
+
+    upsert_chat_keyword(
+        &ChatKeyword {
+            keyword: "草".into(),
+            patterns: vec!["草".into(), "kusa".into(), "ｗｗ".into()],
+        },
+        &pool,
+    )
+    .await?;
+    upsert_chat_keyword(
+        &ChatKeyword {
+            keyword: "song request".into(),
+            patterns: vec!["song request".into(), "歌枠".into()],
+        },
+        &pool,
+    )
+    .await?;
+    delete_chat_keyword("lol", &pool).await?;
+
+    let keywords = list_chat_keywords(&pool).await?;
+    assert_eq!(
+        keywords,
+        vec![
+            ChatKeyword {
+                keyword: "song request".into(),
+                patterns: vec!["song request".into(), "歌枠".into()],
+            },
+            ChatKeyword {
+                keyword: "草".into(),
+                patterns: vec!["草".into(), "kusa".into(), "ｗｗ".into()],
+            },
+        ]
+    );
+
+    Ok(())
+}
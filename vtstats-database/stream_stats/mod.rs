@@ -1,9 +1,23 @@
+mod add_stream_chat_languages;
 mod add_stream_chat_stats;
+mod add_stream_chat_terms;
+mod add_stream_chatters;
 mod add_stream_viewer_stats;
+mod chat_keywords;
+mod stream_audience;
+mod stream_chat_languages;
 mod stream_chat_stats;
+mod stream_chat_terms;
 mod stream_viewer_stats;
 
+pub use self::add_stream_chat_languages::*;
 pub use self::add_stream_chat_stats::*;
+pub use self::add_stream_chat_terms::*;
+pub use self::add_stream_chatters::*;
 pub use self::add_stream_viewer_stats::*;
+pub use self::chat_keywords::*;
+pub use self::stream_audience::*;
+pub use self::stream_chat_languages::*;
 pub use self::stream_chat_stats::*;
+pub use self::stream_chat_terms::*;
 pub use self::stream_viewer_stats::*;
@@ -0,0 +1,114 @@
+use chrono::{serde::ts_milliseconds, DateTime, Utc};
+use serde::Serialize;
+use sqlx::{PgPool, Result};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamAudience {
+    pub chatter_count: i64,
+    pub message_count: i64,
+    /// chatters who never chatted in earlier streams of the same channel
+    pub first_time_chatter_count: i64,
+}
+
+pub async fn stream_audience(stream_id: i32, pool: &PgPool) -> Result<StreamAudience> {
+    let query = sqlx::query_as!(
+        StreamAudience,
+        r#"
+ SELECT count(*) AS "chatter_count!",
+        coalesce(sum(c.message_count), 0)::int8 AS "message_count!",
+        count(*) FILTER (
+            WHERE NOT EXISTS (
+                SELECT 1
+                  FROM stream_chatters p
+                  JOIN streams ps ON ps.stream_id = p.stream_id
+                 WHERE p.author_id = c.author_id
+                   AND ps.channel_id = s.channel_id
+                   AND ps.start_time < s.start_time
+            )
+        ) AS "first_time_chatter_count!"
+   FROM stream_chatters c
+   JOIN streams s ON s.stream_id = c.stream_id
+  WHERE c.stream_id = $1
+        "#,
+        stream_id,
+    )
+    .fetch_one(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_chatters", query)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamChatter {
+    pub author_id: String,
+    pub author_name: String,
+    pub message_count: i32,
+    #[serde(with = "ts_milliseconds")]
+    pub first_message_at: DateTime<Utc>,
+    #[serde(with = "ts_milliseconds")]
+    pub last_message_at: DateTime<Utc>,
+}
+
+/// lists chatters of given stream, most active first
+pub async fn list_stream_chatters(
+    stream_id: i32,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<StreamChatter>> {
+    let query = sqlx::query_as!(
+        StreamChatter,
+        r#"
+ SELECT author_id, author_name, message_count, first_message_at, last_message_at
+   FROM stream_chatters
+  WHERE stream_id = $1
+  ORDER BY message_count DESC, author_id
+  LIMIT $2
+        "#,
+        stream_id,
+        limit,
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_chatters", query)
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    sqlx::query!(
+        r#"
+INSERT INTO streams (stream_id, platform, platform_id, title, channel_id, status, vtuber_id, start_time)
+     VALUES (2, 'youtube', 'id2', 'title2', 1, 'ended', 'vtuber1', to_timestamp(0)),
+            (3, 'youtube', 'id3', 'title3', 1, 'ended', 'vtuber1', to_timestamp(3600))
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    sqlx::query!(
+        r#"
+INSERT INTO stream_chatters (stream_id, author_id, author_name, message_count, first_message_at, last_message_at)
+     VALUES (2, 'a', 'a', 1, to_timestamp(0), to_timestamp(0)),
+            (3, 'a', 'a', 2, to_timestamp(3600), to_timestamp(3600)),
+            (3, 'b', 'b', 5, to_timestamp(3600), to_timestamp(3600))
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    let audience = stream_audience(3, &pool).await?;
+    assert_eq!(audience.chatter_count, 2);
+    assert_eq!(audience.message_count, 7);
+    assert_eq!(audience.first_time_chatter_count, 1);
+
+    let audience = stream_audience(1, &pool).await?;
+    assert_eq!(audience.chatter_count, 0);
+    assert_eq!(audience.message_count, 0);
+
+    let chatters = list_stream_chatters(3, 1, &pool).await?;
+    assert_eq!(chatters.len(), 1);
+    assert_eq!(chatters[0].author_id, "b");
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use sqlx::{PgPool, Result};
+
+/// returns language and message count of given stream, most used first
+pub async fn stream_chat_languages(stream_id: i32, pool: &PgPool) -> Result<Vec<(String, i64)>> {
+    let query = sqlx::query!(
+        r#"
+ SELECT language, count::bigint AS "count!"
+   FROM stream_chat_languages
+  WHERE stream_id = $1
+  ORDER BY count DESC, language
+        "#,
+        stream_id,
+    )
+    .map(|row| (row.language, row.count))
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_chat_languages", query)
+}
+
+/// returns language and message count of all streams of given channel,
+/// most used first
+pub async fn channel_chat_languages(channel_id: i32, pool: &PgPool) -> Result<Vec<(String, i64)>> {
+    let query = sqlx::query!(
+        r#"
+ SELECT l.language, sum(l.count)::bigint AS "count!"
+   FROM stream_chat_languages l
+   JOIN streams s ON s.stream_id = l.stream_id
+  WHERE s.channel_id = $1
+  GROUP BY l.language
+  ORDER BY 2 DESC, l.language
+        "#,
+        channel_id,
+    )
+    .map(|row| (row.language, row.count))
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_chat_languages", query)
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use super::{AddStreamChatLanguageRow, AddStreamChatLanguagesQuery};
+
+    AddStreamChatLanguagesQuery {
+        stream_id: 1,
+        rows: vec![
+            AddStreamChatLanguageRow {
+                language: "jpn".into(),
+                count: 5,
+            },
+            AddStreamChatLanguageRow {
+                language: "eng".into(),
+                count: 3,
+            },
+        ],
+    }
+    .execute(&pool)
+    .await?;
+
+    AddStreamChatLanguagesQuery {
+        stream_id: 1,
+        rows: vec![
+            AddStreamChatLanguageRow {
+                language: "eng".into(),
+                count: 4,
+            },
+            AddStreamChatLanguageRow {
+                language: "und".into(),
+                count: 1,
+            },
+        ],
+    }
+    .execute(&pool)
+    .await?;
+
+    let expected = vec![
+        ("eng".to_string(), 7),
+        ("jpn".to_string(), 5),
+        ("und".to_string(), 1),
+    ];
+
+    assert_eq!(stream_chat_languages(1, &pool).await?, expected);
+    assert_eq!(channel_chat_languages(1, &pool).await?, expected);
+    assert!(channel_chat_languages(2, &pool).await?.is_empty());
+
+    Ok(())
+}
@@ -1,18 +1,62 @@
 use sqlx::{PgPool, Result};
 
-pub async fn stream_chat_stats(stream_id: i32, pool: &PgPool) -> Result<Vec<(i64, i32, i32)>> {
+/// returns time, message count, message count from members,
+/// unique chatters and chatters sending their first message in the stream
+pub async fn stream_chat_stats(
+    stream_id: i32,
+    pool: &PgPool,
+) -> Result<Vec<(i64, i32, i32, i32, i32)>> {
     let query = sqlx::query!(
         r#"
- SELECT time ts, count v1, from_member_count v2
-   FROM stream_chat_stats
-  WHERE stream_id = $1
+   WITH new_chatters AS (
+            SELECT date_bin('15 seconds', first_message_at, TIMESTAMPTZ 'epoch') AS time,
+                   count(*)::int AS count
+              FROM stream_chatters
+             WHERE stream_id = $1
+          GROUP BY 1
+        )
+ SELECT s.time ts, s.count v1, s.from_member_count v2, s.chatter_count v3,
+        coalesce(n.count, 0) AS "v4!"
+   FROM stream_chat_stats s
+   LEFT JOIN new_chatters n ON n.time = s.time
+  WHERE s.stream_id = $1
         "#,
         stream_id,
     )
-    .map(|row| (row.ts.timestamp_millis(), row.v1, row.v2))
+    .map(|row| (row.ts.timestamp_millis(), row.v1, row.v2, row.v3, row.v4))
     .fetch_all(pool);
 
     crate::otel::execute_query!("SELECT", "stream_chat_stats", query)
 }
 
-// TODO: add unit tests
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    sqlx::query!(
+        r#"
+INSERT INTO stream_chat_stats (stream_id, time, count, from_member_count, chatter_count)
+     VALUES (1, to_timestamp(900), 10, 2, 4),
+            (1, to_timestamp(915), 5, 1, 3)
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    sqlx::query!(
+        r#"
+INSERT INTO stream_chatters (stream_id, author_id, author_name, message_count, first_message_at, last_message_at)
+     VALUES (1, 'a', 'a', 6, to_timestamp(901), to_timestamp(920)),
+            (1, 'b', 'b', 5, to_timestamp(905), to_timestamp(916)),
+            (1, 'c', 'c', 4, to_timestamp(917), to_timestamp(918))
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    let mut stats = stream_chat_stats(1, &pool).await?;
+    stats.sort();
+
+    assert_eq!(stats, vec![(900_000, 10, 2, 4, 2), (915_000, 5, 1, 3, 1)]);
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, Result};
+
+#[derive(
+    Debug, sqlx::Type, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[sqlx(type_name = "chat_term_kind", rename_all = "snake_case")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChatTermKind {
+    /// youtube custom emoji shortcut or twitch emote name
+    Emote,
+    /// keyword of `chat_keywords`
+    Keyword,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct StreamChatTerm {
+    pub time: i64,
+    pub kind: ChatTermKind,
+    pub term: String,
+    pub count: i32,
+    /// sum of counts of the term in whole stream
+    pub total: i64,
+}
+
+/// returns term counts of the `limit` most frequent terms of given stream,
+/// optionally filtered by kind, ordered by total count then time
+pub async fn stream_chat_terms(
+    stream_id: i32,
+    kind: Option<ChatTermKind>,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<StreamChatTerm>> {
+    let query = sqlx::query!(
+        r#"
+   WITH top AS (
+ SELECT kind, term, sum(count) AS total
+   FROM stream_chat_terms
+  WHERE stream_id = $1
+    AND (kind = $2 OR $2 IS NULL)
+  GROUP BY kind, term
+  ORDER BY total DESC, term, kind
+  LIMIT $3
+)
+ SELECT s.time, s.kind AS "kind: ChatTermKind", s.term, s.count, top.total AS "total!"
+   FROM top
+   JOIN stream_chat_terms s
+     ON s.stream_id = $1
+    AND s.kind = top.kind
+    AND s.term = top.term
+  ORDER BY top.total DESC, s.term, s.kind, s.time
+        "#,
+        stream_id,
+        kind as _,
+        limit,
+    )
+    .map(|row| StreamChatTerm {
+        time: row.time.timestamp_millis(),
+        kind: row.kind,
+        term: row.term,
+        count: row.count,
+        total: row.total,
+    })
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_chat_terms", query)
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use super::{AddStreamChatTermRow, AddStreamChatTermsQuery};
+
+    let time = Utc.timestamp_opt(900, 0).single().unwrap();
+
+    AddStreamChatTermsQuery {
+        stream_id: 1,
+        rows: vec![
+            AddStreamChatTermRow {
+                time,
+                kind: ChatTermKind::Emote,
+                term: ":_kusa:".into(),
+                count: 3,
+            },
+            AddStreamChatTermRow {
+                time,
+                kind: ChatTermKind::Keyword,
+                term: "草".into(),
+                count: 2,
+            },
+        ],
+    }
+    .execute(&pool)
+    .await?;
+
+    AddStreamChatTermsQuery {
+        stream_id: 1,
+        rows: vec![
+            AddStreamChatTermRow {
+                time,
+                kind: ChatTermKind::Keyword,
+                term: "草".into(),
+                count: 1,
+            },
+            AddStreamChatTermRow {
+                time: time + Duration::seconds(15),
+                kind: ChatTermKind::Keyword,
+                term: "草".into(),
+                count: 4,
+            },
+        ],
+    }
+    .execute(&pool)
+    .await?;
+
+    AddStreamChatTermsQuery {
+        stream_id: 1,
+        rows: vec![AddStreamChatTermRow {
+            time,
+            kind: ChatTermKind::Keyword,
+            term: "lol".into(),
+            count: 1,
+        }],
+    }
+    .execute(&pool)
+    .await?;
+
+    let terms = stream_chat_terms(1, None, 2, &pool).await?;
+    assert_eq!(
+        terms,
+        vec![
+            StreamChatTerm {
+                time: 900_000,
+                kind: ChatTermKind::Keyword,
+                term: "草".into(),
+                count: 3,
+                total: 7,
+            },
+            StreamChatTerm {
+                time: 915_000,
+                kind: ChatTermKind::Keyword,
+                term: "草".into(),
+                count: 4,
+                total: 7,
+            },
+            StreamChatTerm {
+                time: 900_000,
+                kind: ChatTermKind::Emote,
+                term: ":_kusa:".into(),
+                count: 3,
+                total: 3,
+            },
+        ]
+    );
+
+    let terms = stream_chat_terms(1, Some(ChatTermKind::Keyword), 10, &pool).await?;
+    assert_eq!(terms.len(), 3);
+    assert_eq!(terms[2].term, "lol");
+
+    Ok(())
+}
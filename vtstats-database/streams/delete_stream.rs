@@ -16,6 +16,30 @@ pub async fn delete_stream(stream_id: i32, pool: &PgPool) -> Result<()> {
 
     crate::otel::execute_query!("DELETE", "stream_chat_stats", query)?;
 
+    let query = sqlx::query!(
+        "DELETE from stream_chatters WHERE stream_id = $1",
+        stream_id
+    )
+    .execute(&mut *tx);
+
+    crate::otel::execute_query!("DELETE", "stream_chatters", query)?;
+
+    let query = sqlx::query!(
+        "DELETE from stream_chat_terms WHERE stream_id = $1",
+        stream_id
+    )
+    .execute(&mut *tx);
+
+    crate::otel::execute_query!("DELETE", "stream_chat_terms", query)?;
+
+    let query = sqlx::query!(
+        "DELETE from stream_chat_languages WHERE stream_id = $1",
+        stream_id
+    )
+    .execute(&mut *tx);
+
+    crate::otel::execute_query!("DELETE", "stream_chat_languages", query)?;
+
     let query = sqlx::query!(
         "DELETE from stream_viewer_stats WHERE stream_id = $1",
         stream_id
@@ -24,6 +48,42 @@ pub async fn delete_stream(stream_id: i32, pool: &PgPool) -> Result<()> {
 
     crate::otel::execute_query!("DELETE", "stream_viewer_stats", query)?;
 
+    let query = sqlx::query!(
+        "DELETE from stream_categories WHERE stream_id = $1",
+        stream_id
+    )
+    .execute(&mut *tx);
+
+    crate::otel::execute_query!("DELETE", "stream_categories", query)?;
+
+    let query = sqlx::query!(
+        "DELETE from stream_vod_history WHERE stream_id = $1",
+        stream_id
+    )
+    .execute(&mut *tx);
+
+    crate::otel::execute_query!("DELETE", "stream_vod_history", query)?;
+
+    let query = sqlx::query!(
+        "DELETE from stream_timeline WHERE stream_id = $1",
+        stream_id
+    )
+    .execute(&mut *tx);
+
+    crate::otel::execute_query!("DELETE", "stream_timeline", query)?;
+
+    let query = sqlx::query!(
+        "INSERT INTO deleted_streams \
+        (stream_id, channel_id, vtuber_id, platform, platform_id, title, schedule_time, start_time, schedule_sequence) \
+        SELECT stream_id, channel_id, vtuber_id, platform, platform_id, title, schedule_time, start_time, schedule_sequence \
+        FROM streams WHERE stream_id = $1 \
+        ON CONFLICT (stream_id) DO UPDATE SET deleted_at = NOW(), schedule_sequence = excluded.schedule_sequence",
+        stream_id
+    )
+    .execute(&mut *tx);
+
+    crate::otel::execute_query!("INSERT", "deleted_streams", query)?;
+
     let query =
         sqlx::query!("DELETE from streams WHERE stream_id = $1", stream_id).execute(&mut *tx);
 
@@ -19,3 +19,16 @@ pub async fn find_stream(stream_id: i32, pool: &PgPool) -> Result<Option<FindStr
 
     crate::otel::execute_query!("SELECT", "streams", query)
 }
+
+/// returns id of the live stream of given channel, if any
+pub async fn find_live_stream_id(channel_id: i32, pool: &PgPool) -> Result<Option<i32>> {
+    let query = sqlx::query_scalar!(
+        "SELECT stream_id FROM streams \
+        WHERE channel_id = $1 AND status = 'live' \
+        ORDER BY start_time DESC NULLS LAST LIMIT 1",
+        channel_id
+    )
+    .fetch_optional(pool);
+
+    crate::otel::execute_query!("SELECT", "streams", query)
+}
@@ -19,6 +19,9 @@ pub async fn get_stream_by_id(stream_id: i32, pool: &PgPool) -> Result<Option<St
         viewer_max, \
         viewer_avg, \
         like_max, \
+        vod_status as \"vod_status: _\", \
+        stream_kind as \"stream_kind: _\", \
+        access as \"access: _\", \
         updated_at, \
         status as \"status: _\" \
         FROM streams \
@@ -29,3 +32,34 @@ pub async fn get_stream_by_id(stream_id: i32, pool: &PgPool) -> Result<Option<St
 
     crate::otel::execute_query!("SELECT", "streams", query)
 }
+
+pub async fn list_streams_by_ids(stream_ids: &[i32], pool: &PgPool) -> Result<Vec<Stream>> {
+    let query = sqlx::query_as!(
+        Stream,
+        "SELECT platform as \"platform: _\", \
+        platform_id, \
+        stream_id, \
+        channel_id, \
+        title, \
+        null as highlighted_title, \
+        vtuber_id, \
+        thumbnail_url, \
+        schedule_time, \
+        start_time, \
+        end_time, \
+        viewer_max, \
+        viewer_avg, \
+        like_max, \
+        vod_status as \"vod_status: _\", \
+        stream_kind as \"stream_kind: _\", \
+        access as \"access: _\", \
+        updated_at, \
+        status as \"status: _\" \
+        FROM streams \
+        WHERE stream_id = ANY($1)",
+        stream_ids
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "streams", query)
+}
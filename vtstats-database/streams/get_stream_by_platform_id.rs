@@ -25,6 +25,9 @@ pub async fn get_stream_by_platform_id(
         viewer_max, \
         viewer_avg, \
         like_max, \
+        vod_status as \"vod_status: _\", \
+        stream_kind as \"stream_kind: _\", \
+        access as \"access: _\", \
         updated_at, \
         status as \"status: _\" \
         FROM streams \
@@ -0,0 +1,182 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Result};
+
+use crate::channels::Platform;
+
+use super::StreamStatus;
+
+pub struct CalendarStream {
+    pub stream_id: i32,
+    pub platform: Platform,
+    pub platform_id: String,
+    pub title: String,
+    pub schedule_time: Option<DateTime<Utc>>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+    /// increased each time the scheduled (or start) time changes
+    pub schedule_sequence: i32,
+}
+
+/// list scheduled and live streams of given channels or vtubers,
+/// which are scheduled (or started) after `since`, and streams
+/// which ended after `since`
+pub async fn list_calendar_streams(
+    channel_ids: &[i32],
+    vtuber_ids: &[String],
+    since: DateTime<Utc>,
+    pool: &PgPool,
+) -> Result<Vec<CalendarStream>> {
+    let query = sqlx::query_as!(
+        CalendarStream,
+        "SELECT stream_id, \
+        platform as \"platform: _\", \
+        platform_id, \
+        title, \
+        schedule_time, \
+        start_time, \
+        end_time, \
+        updated_at, \
+        schedule_sequence \
+        FROM streams \
+        WHERE (channel_id = ANY($1) OR vtuber_id = ANY($2)) \
+        AND (((status = $3 OR status = $4) AND COALESCE(schedule_time, start_time) > $5) \
+        OR (status = $6 AND end_time > $5)) \
+        ORDER BY COALESCE(schedule_time, start_time) ASC \
+        LIMIT 500",
+        channel_ids,
+        vtuber_ids,
+        StreamStatus::Scheduled as _,
+        StreamStatus::Live as _,
+        since,
+        StreamStatus::Ended as _,
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "streams", query)
+}
+
+pub struct DeletedStream {
+    pub stream_id: i32,
+    pub channel_id: i32,
+    pub vtuber_id: String,
+    pub platform: Platform,
+    pub platform_id: String,
+    pub title: String,
+    pub schedule_time: Option<DateTime<Utc>>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub deleted_at: DateTime<Utc>,
+    pub schedule_sequence: i32,
+}
+
+/// list streams of given channels or vtubers which were deleted after `since`
+pub async fn list_deleted_streams(
+    channel_ids: &[i32],
+    vtuber_ids: &[String],
+    since: DateTime<Utc>,
+    pool: &PgPool,
+) -> Result<Vec<DeletedStream>> {
+    let query = sqlx::query_as!(
+        DeletedStream,
+        "SELECT stream_id, channel_id, vtuber_id, platform as \"platform: _\", platform_id, \
+        title, schedule_time, start_time, deleted_at, schedule_sequence \
+        FROM deleted_streams \
+        WHERE (channel_id = ANY($1) OR vtuber_id = ANY($2)) \
+        AND deleted_at > $3 \
+        ORDER BY deleted_at ASC \
+        LIMIT 500",
+        channel_ids,
+        vtuber_ids,
+        since,
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "deleted_streams", query)
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::{Duration, TimeZone};
+
+    let now = Utc::now();
+
+    sqlx::query!(
+        r#"
+INSERT INTO streams (stream_id, vtuber_id, title, channel_id, platform_id, platform, schedule_time, start_time, status)
+     VALUES (1, 'vtuber1', 'title1', 1, 'id1', 'youtube', $1, NULL, 'scheduled'),
+            (2, 'vtuber2', 'title2', 2, 'id2', 'youtube', NULL, $2, 'live'),
+            (3, 'vtuber1', 'title3', 1, 'id3', 'youtube', $1, $2, 'ended'),
+            (4, 'vtuber1', 'title4', 1, 'id4', 'youtube', $3, NULL, 'scheduled'),
+            (5, 'vtuber3', 'title5', 3, 'id5', 'youtube', $1, NULL, 'scheduled')
+        "#,
+        now + Duration::hours(1),
+        now - Duration::hours(1),
+        Utc.timestamp_opt(1000, 0).single().unwrap(),
+    )
+    .execute(&pool)
+    .await?;
+
+    sqlx::query!(
+        r#"
+INSERT INTO streams (stream_id, vtuber_id, title, channel_id, platform_id, platform, start_time, end_time, status)
+     VALUES (6, 'vtuber1', 'title6', 1, 'id6', 'youtube', $1, $2, 'ended'),
+            (7, 'vtuber1', 'title7', 1, 'id7', 'youtube', $3, $3, 'ended')
+        "#,
+        now - Duration::hours(3),
+        now - Duration::hours(2),
+        Utc.timestamp_opt(1000, 0).single().unwrap(),
+    )
+    .execute(&pool)
+    .await?;
+
+    let since = now - Duration::days(1);
+
+    let streams = list_calendar_streams(&[1], &["vtuber2".into()], since, &pool).await?;
+    assert_eq!(
+        streams.iter().map(|s| s.stream_id).collect::<Vec<_>>(),
+        vec![6, 2, 1]
+    );
+    assert_eq!(
+        streams[0].end_time.map(|t| t.timestamp()),
+        Some((now - Duration::hours(2)).timestamp())
+    );
+
+    assert!(streams.iter().all(|s| s.schedule_sequence == 0));
+
+    // only rescheduling bumps the sequence
+    sqlx::query!(
+        "UPDATE streams SET schedule_time = $1 WHERE stream_id = 1",
+        now + Duration::hours(2),
+    )
+    .execute(&pool)
+    .await?;
+    sqlx::query!("UPDATE streams SET title = 'title1' WHERE stream_id = 1")
+        .execute(&pool)
+        .await?;
+
+    let streams = list_calendar_streams(&[1], &[], since, &pool).await?;
+    assert_eq!(streams[1].stream_id, 1);
+    assert_eq!(streams[1].schedule_sequence, 1);
+
+    super::delete_stream(1, &pool).await?;
+
+    let streams = list_calendar_streams(&[1], &[], since, &pool).await?;
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].stream_id, 6);
+
+    let deleted = list_deleted_streams(&[1], &[], since, &pool).await?;
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0].stream_id, 1);
+    assert_eq!(deleted[0].title, "title1");
+    assert_eq!(deleted[0].schedule_sequence, 1);
+    assert_eq!(
+        deleted[0].schedule_time.map(|t| t.timestamp()),
+        Some((now + Duration::hours(2)).timestamp())
+    );
+
+    let deleted = list_deleted_streams(&[3], &["vtuber3".into()], since, &pool).await?;
+    assert!(deleted.is_empty());
+
+    Ok(())
+}
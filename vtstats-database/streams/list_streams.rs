@@ -7,6 +7,8 @@ use serde_with::skip_serializing_none;
 
 use crate::channels::Platform;
 
+use super::{StreamAccess, StreamKind, StreamKindFilter, VodStatus};
+
 type UtcTime = DateTime<Utc>;
 
 #[skip_serializing_none]
@@ -33,6 +35,9 @@ pub struct Stream {
     #[serde(with = "ts_milliseconds")]
     pub updated_at: UtcTime,
     pub status: StreamStatus,
+    pub vod_status: Option<VodStatus>,
+    pub stream_kind: StreamKind,
+    pub access: StreamAccess,
 }
 
 #[derive(Debug, sqlx::Type, Serialize, PartialEq, Eq, Clone, Copy)]
@@ -87,6 +92,7 @@ pub async fn filter_streams_order_by_schedule_time_asc(
     status: StreamStatus,
     start_at: Option<DateTime<Utc>>,
     end_at: Option<DateTime<Utc>>,
+    filter: StreamKindFilter<'_>,
     pool: PgPool,
 ) -> Result<Vec<Stream>> {
     let query = sqlx::query_as!(
@@ -105,6 +111,9 @@ pub async fn filter_streams_order_by_schedule_time_asc(
         viewer_max, \
         viewer_avg, \
         like_max, \
+        vod_status as \"vod_status: _\", \
+        stream_kind as \"stream_kind: _\", \
+        access as \"access: _\", \
         updated_at, \
         status as \"status: _\" \
         FROM streams \
@@ -112,12 +121,16 @@ pub async fn filter_streams_order_by_schedule_time_asc(
         AND status = $2 \
         AND (schedule_time > $3 OR $3 IS NULL) \
         AND (schedule_time < $4 OR $4 IS NULL) \
+        AND (stream_kind = ANY($5) OR cardinality($5::stream_kind[]) = 0) \
+        AND (access = ANY($6) OR cardinality($6::stream_access[]) = 0) \
         ORDER BY schedule_time ASC \
         LIMIT 24",
         channel_ids,
         status as _,
         start_at,
-        end_at
+        end_at,
+        filter.kinds as _,
+        filter.access as _,
     )
     .fetch_all(&pool);
 
@@ -129,19 +142,12 @@ pub async fn filter_streams_order_by_start_time_desc(
     status: StreamStatus,
     start_at: Option<DateTime<Utc>>,
     end_at: Option<DateTime<Utc>>,
+    filter: StreamKindFilter<'_>,
     keyword: Option<&str>,
     pool: PgPool,
 ) -> Result<Vec<Stream>> {
     if let Some(keyword) = keyword {
-        let query = sqlx::query!(
-            "SELECT pgroonga_query_expand('pgroonga_synonyms', 'term', 'synonyms', $1) as expended",
-            keyword
-        )
-        .fetch_one(&pool);
-
-        let expended = crate::otel::execute_query!("SELECT", "pgroonga_query_expand", query)?;
-
-        let keyword = expended.expended.as_deref().unwrap_or(keyword);
+        let keyword = crate::search::expand_keyword(keyword, &pool).await?;
 
         let query = sqlx::query_as!(
             Stream,
@@ -161,6 +167,9 @@ pub async fn filter_streams_order_by_start_time_desc(
             viewer_max, \
             viewer_avg, \
             like_max, \
+            vod_status as \"vod_status: _\", \
+            stream_kind as \"stream_kind: _\", \
+            access as \"access: _\", \
             updated_at, \
             status as \"status: _\" \
             FROM streams \
@@ -168,6 +177,8 @@ pub async fn filter_streams_order_by_start_time_desc(
             AND status = $2 \
             AND (start_time > $3 OR $3 IS NULL) \
             AND (start_time < $4 OR $4 IS NULL) \
+            AND (stream_kind = ANY($6) OR cardinality($6::stream_kind[]) = 0) \
+            AND (access = ANY($7) OR cardinality($7::stream_access[]) = 0) \
             AND title &@~ $5 \
             ORDER BY start_time DESC \
             LIMIT 24",
@@ -175,7 +186,9 @@ pub async fn filter_streams_order_by_start_time_desc(
             status as _,
             start_at,
             end_at,
-            keyword
+            keyword,
+            filter.kinds as _,
+            filter.access as _,
         )
         .fetch_all(&pool);
 
@@ -197,6 +210,9 @@ pub async fn filter_streams_order_by_start_time_desc(
             viewer_max, \
             viewer_avg, \
             like_max, \
+            vod_status as \"vod_status: _\", \
+            stream_kind as \"stream_kind: _\", \
+            access as \"access: _\", \
             updated_at, \
             status as \"status: _\" \
             FROM streams \
@@ -204,12 +220,16 @@ pub async fn filter_streams_order_by_start_time_desc(
             AND status = $2 \
             AND (start_time > $3 OR $3 IS NULL) \
             AND (start_time < $4 OR $4 IS NULL) \
+            AND (stream_kind = ANY($5) OR cardinality($5::stream_kind[]) = 0) \
+            AND (access = ANY($6) OR cardinality($6::stream_access[]) = 0) \
             ORDER BY start_time DESC \
             LIMIT 24",
             channel_ids,
             status as _,
             start_at,
-            end_at
+            end_at,
+            filter.kinds as _,
+            filter.access as _,
         )
         .fetch_all(&pool);
 
@@ -271,6 +291,9 @@ impl<'q> ListYouTubeStreamsQuery<'q> {
               viewer_max, \
               viewer_avg, \
               like_max, \
+              vod_status, \
+              stream_kind, \
+              access, \
               updated_at, \
               status \
          FROM streams s\
@@ -531,5 +554,37 @@ INSERT INTO streams (stream_id, vtuber_id, title, channel_id, platform_id, platf
         2
     );
 
+    sqlx::query!("UPDATE streams SET stream_kind = 'premiere' WHERE stream_id = 2")
+        .execute(&pool)
+        .await?;
+
+    let streams = filter_streams_order_by_start_time_desc(
+        &[1, 2],
+        StreamStatus::Live,
+        None,
+        None,
+        StreamKindFilter {
+            kinds: &[StreamKind::Live, StreamKind::Vertical],
+            ..Default::default()
+        },
+        None,
+        pool.clone(),
+    )
+    .await?;
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].stream_id, 1);
+
+    let streams = filter_streams_order_by_start_time_desc(
+        &[1, 2],
+        StreamStatus::Live,
+        None,
+        None,
+        StreamKindFilter::default(),
+        None,
+        pool,
+    )
+    .await?;
+    assert_eq!(streams.len(), 2);
+
     Ok(())
 }
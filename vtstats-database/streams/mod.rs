@@ -3,22 +3,34 @@ mod end_stream;
 mod find_stream;
 mod get_stream_by_id;
 mod get_stream_by_platform_id;
+mod list_calendar_streams;
 mod list_streams;
+mod rank_streams;
+mod scheduled_stream;
 mod start_stream;
+mod stream_kind;
+mod stream_timeline;
 mod stream_times;
 mod update_stream_title;
 mod upsert_stream;
+mod vod_status;
 
 pub use self::delete_stream::*;
 pub use self::end_stream::*;
 pub use self::find_stream::*;
 pub use self::get_stream_by_id::*;
 pub use self::get_stream_by_platform_id::*;
+pub use self::list_calendar_streams::*;
 pub use self::list_streams::*;
+pub use self::rank_streams::*;
+pub use self::scheduled_stream::*;
 pub use self::start_stream::*;
+pub use self::stream_kind::*;
+pub use self::stream_timeline::*;
 pub use self::stream_times::*;
 pub use self::update_stream_title::*;
 pub use self::upsert_stream::*;
+pub use self::vod_status::*;
 
 use crate::channels::Platform;
 use sqlx::{PgPool, Result};
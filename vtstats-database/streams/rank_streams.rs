@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sqlx::{PgPool, Result};
+
+use crate::channels::Platform;
+
+use super::Stream;
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StreamRankingColumn {
+    ViewerMax,
+    ViewerAvg,
+}
+
+/// list streams started in given time window, ordered by viewer
+/// stats descending and optionally filtered by platform and group
+pub struct RankStreamsQuery<'q> {
+    pub order_by: StreamRankingColumn,
+    pub platform: Option<Platform>,
+    pub group_id: Option<&'q str>,
+    pub start_at: DateTime<Utc>,
+    pub end_at: DateTime<Utc>,
+    pub limit: i64,
+}
+
+impl<'q> RankStreamsQuery<'q> {
+    pub async fn execute(self, pool: &PgPool) -> Result<Vec<Stream>> {
+        let query = sqlx::query_as!(
+            Stream,
+            "WITH RECURSIVE members(id) AS (\
+                SELECT unnest(children) FROM groups WHERE group_id = $2 \
+                UNION SELECT unnest(g.children) FROM groups g JOIN members m ON g.group_id = m.id\
+            ) \
+            SELECT platform as \"platform: _\", \
+            platform_id, \
+            stream_id, \
+            title, \
+            channel_id, \
+            null as highlighted_title, \
+            vtuber_id, \
+            thumbnail_url, \
+            schedule_time, \
+            start_time, \
+            end_time, \
+            viewer_max, \
+            viewer_avg, \
+            like_max, \
+            vod_status as \"vod_status: _\", \
+            stream_kind as \"stream_kind: _\", \
+            access as \"access: _\", \
+            updated_at, \
+            status as \"status: _\" \
+            FROM streams \
+            WHERE (platform = $1 OR $1 IS NULL) \
+            AND (vtuber_id IN (SELECT id FROM members) OR $2 IS NULL) \
+            AND start_time >= $3 \
+            AND start_time < $4 \
+            ORDER BY CASE WHEN $5 THEN viewer_max ELSE viewer_avg END DESC NULLS LAST \
+            LIMIT $6",
+            self.platform as _,                              // $1
+            self.group_id,                                   // $2
+            self.start_at,                                   // $3
+            self.end_at,                                     // $4
+            self.order_by == StreamRankingColumn::ViewerMax, // $5
+            self.limit,                                      // $6
+        )
+        .fetch_all(pool);
+
+        crate::otel::execute_query!("SELECT", "streams", query)
+    }
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::TimeZone;
+
+    sqlx::query!(
+        r#"
+INSERT INTO streams (stream_id, vtuber_id, title, channel_id, platform_id, platform, start_time, status, viewer_max, viewer_avg)
+     VALUES (1, 'vtuber1', 'title1', 1, 'id1', 'youtube', to_timestamp(1000), 'ended', 100, 80),
+            (2, 'vtuber2', 'title2', 2, 'id2', 'youtube', to_timestamp(2000), 'ended', 300, 50),
+            (3, 'vtuber3', 'title3', 3, 'id3', 'youtube', to_timestamp(3000), 'ended', 200, 150),
+            (4, 'vtuber1', 'title4', 1, 'id4', 'youtube', to_timestamp(9000), 'ended', 900, 900),
+            (5, 'vtuber2', 'title5', 2, 'id5', 'youtube', to_timestamp(2500), 'live', NULL, NULL)
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    sqlx::query!(
+        r#"
+INSERT INTO groups (group_id, native_name, children)
+     VALUES ('group1', 'group1', ARRAY['vtuber1', 'group2']),
+            ('group2', 'group2', ARRAY['vtuber3'])
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    let start_at = Utc.timestamp_opt(0, 0).single().unwrap();
+    let end_at = Utc.timestamp_opt(5000, 0).single().unwrap();
+
+    let streams = RankStreamsQuery {
+        order_by: StreamRankingColumn::ViewerMax,
+        platform: None,
+        group_id: None,
+        start_at,
+        end_at,
+        limit: 3,
+    }
+    .execute(&pool)
+    .await?;
+    assert_eq!(
+        streams.iter().map(|s| s.stream_id).collect::<Vec<_>>(),
+        vec![2, 3, 1]
+    );
+
+    let streams = RankStreamsQuery {
+        order_by: StreamRankingColumn::ViewerAvg,
+        platform: Some(Platform::Youtube),
+        group_id: Some("group1"),
+        start_at,
+        end_at,
+        limit: 10,
+    }
+    .execute(&pool)
+    .await?;
+    assert_eq!(
+        streams.iter().map(|s| s.stream_id).collect::<Vec<_>>(),
+        vec![3, 1]
+    );
+
+    let streams = RankStreamsQuery {
+        order_by: StreamRankingColumn::ViewerAvg,
+        platform: Some(Platform::Twitch),
+        group_id: None,
+        start_at,
+        end_at,
+        limit: 10,
+    }
+    .execute(&pool)
+    .await?;
+    assert!(streams.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Result};
+
+pub struct ScheduledStream {
+    pub stream_id: i32,
+    pub platform_id: String,
+    pub schedule_time: Option<DateTime<Utc>>,
+}
+
+pub async fn list_scheduled_streams_by_channel(
+    channel_id: i32,
+    pool: &PgPool,
+) -> Result<Vec<ScheduledStream>> {
+    let query = sqlx::query_as!(
+        ScheduledStream,
+        "SELECT stream_id, platform_id, schedule_time FROM streams \
+        WHERE channel_id = $1 AND status = 'scheduled'",
+        channel_id
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "streams", query)
+}
+
+/// links the scheduled stream of given channel, which is closest to
+/// `start_time` within 2 hours, to the broadcast that just went online.
+/// returns `None` if there's no such stream or the broadcast is known.
+pub async fn claim_scheduled_stream(
+    channel_id: i32,
+    platform_stream_id: &str,
+    start_time: DateTime<Utc>,
+    pool: &PgPool,
+) -> Result<Option<i32>> {
+    let query = sqlx::query_scalar!(
+        r#"
+ UPDATE streams
+    SET platform_id = $2,
+        updated_at  = NOW()
+  WHERE stream_id = (
+            SELECT stream_id
+              FROM streams
+             WHERE channel_id = $1
+               AND status = 'scheduled'
+               AND schedule_time BETWEEN $3::timestamptz - INTERVAL '2 hours' AND $3::timestamptz + INTERVAL '2 hours'
+          ORDER BY abs(extract(epoch FROM schedule_time - $3))
+             LIMIT 1
+        )
+    AND NOT EXISTS (
+            SELECT 1 FROM streams s WHERE s.platform = streams.platform AND s.platform_id = $2
+        )
+RETURNING stream_id
+        "#,
+        channel_id,         // $1
+        platform_stream_id, // $2
+        start_time,         // $3
+    )
+    .fetch_optional(pool);
+
+    crate::otel::execute_query!("UPDATE", "streams", query)
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::{Duration, TimeZone};
+
+    sqlx::query!(
+        r#"
+INSERT INTO streams (stream_id, vtuber_id, title, channel_id, platform_id, platform, schedule_time, status)
+     VALUES (1, 'vtuber1', 'title1', 1, 'segment1', 'twitch', to_timestamp(36000), 'scheduled'),
+            (2, 'vtuber1', 'title2', 1, 'segment2', 'twitch', to_timestamp(39600), 'scheduled'),
+            (3, 'vtuber1', 'title3', 1, 'segment3', 'twitch', to_timestamp(86400), 'scheduled'),
+            (4, 'vtuber1', 'title4', 1, 'broadcast4', 'twitch', to_timestamp(0), 'ended')
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    assert_eq!(list_scheduled_streams_by_channel(1, &pool).await?.len(), 3);
+    assert!(list_scheduled_streams_by_channel(2, &pool)
+        .await?
+        .is_empty());
+
+    let time = Utc.timestamp_opt(38000, 0).single().unwrap();
+
+    // closest one is picked
+    assert_eq!(
+        claim_scheduled_stream(1, "broadcast5", time, &pool).await?,
+        Some(2)
+    );
+    // broadcast is already known
+    assert_eq!(
+        claim_scheduled_stream(1, "broadcast5", time, &pool).await?,
+        None
+    );
+    assert_eq!(
+        claim_scheduled_stream(1, "broadcast4", time, &pool).await?,
+        None
+    );
+    // too far away from schedule time
+    assert_eq!(
+        claim_scheduled_stream(1, "broadcast6", time + Duration::hours(6), &pool).await?,
+        None
+    );
+
+    let platform_id = sqlx::query_scalar!("SELECT platform_id FROM streams WHERE stream_id = 2")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(platform_id, "broadcast5");
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use serde::{de::IntoDeserializer, Deserialize, Serialize};
+use sqlx::postgres::{PgHasArrayType, PgTypeInfo};
+
+#[derive(Debug, sqlx::Type, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+#[sqlx(type_name = "stream_kind", rename_all = "lowercase")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StreamKind {
+    #[default]
+    Live,
+    Premiere,
+    /// vertical live, which is listed in shorts feed
+    Vertical,
+}
+
+#[derive(Debug, sqlx::Type, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+#[sqlx(type_name = "stream_access", rename_all = "snake_case")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StreamAccess {
+    #[default]
+    Public,
+    Unlisted,
+    MembersOnly,
+}
+
+impl PgHasArrayType for StreamKind {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_stream_kind")
+    }
+}
+
+impl PgHasArrayType for StreamAccess {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_stream_access")
+    }
+}
+
+// parsing from comma-separated query string, e.g. `LIVE,VERTICAL`
+impl FromStr for StreamKind {
+    type Err = serde::de::value::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+
+impl FromStr for StreamAccess {
+    type Err = serde::de::value::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(s.into_deserializer())
+    }
+}
+
+/// restricts listed streams to given kinds and access levels,
+/// empty slice means no restriction
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StreamKindFilter<'a> {
+    pub kinds: &'a [StreamKind],
+    pub access: &'a [StreamAccess],
+}
@@ -0,0 +1,123 @@
+use chrono::{serde::ts_milliseconds, DateTime, Utc};
+use serde::Serialize;
+use sqlx::{PgPool, Result};
+
+/// title and category of a stream since given time, until the next entry
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamTimelineEntry {
+    #[serde(with = "ts_milliseconds")]
+    pub time: DateTime<Utc>,
+    pub title: String,
+    pub category_id: String,
+    pub category_name: String,
+    pub language: String,
+    pub content_labels: Vec<String>,
+}
+
+pub struct AddStreamTimelineQuery<'q> {
+    pub stream_id: i32,
+    pub time: DateTime<Utc>,
+    pub title: &'q str,
+    pub category_id: &'q str,
+    pub category_name: &'q str,
+    pub language: &'q str,
+    pub content_labels: &'q [String],
+}
+
+impl<'q> AddStreamTimelineQuery<'q> {
+    pub async fn execute(self, pool: &PgPool) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO stream_timeline AS t (stream_id, time, title, category_id, category_name, language, content_labels)
+     VALUES ($1, $2, $3, $4, $5, $6, $7)
+ON CONFLICT (stream_id, time) DO UPDATE
+        SET title          = $3,
+            category_id    = $4,
+            category_name  = $5,
+            language       = $6,
+            content_labels = $7
+            "#,
+            self.stream_id,
+            self.time,
+            self.title,
+            self.category_id,
+            self.category_name,
+            self.language,
+            self.content_labels,
+        )
+        .execute(pool);
+
+        crate::otel::execute_query!("INSERT", "stream_timeline", query)?;
+
+        Ok(())
+    }
+}
+
+pub async fn list_stream_timeline(
+    stream_id: i32,
+    pool: &PgPool,
+) -> Result<Vec<StreamTimelineEntry>> {
+    let query = sqlx::query_as!(
+        StreamTimelineEntry,
+        "SELECT time, title, category_id, category_name, language, content_labels \
+        FROM stream_timeline WHERE stream_id = $1 ORDER BY time",
+        stream_id
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "stream_timeline", query)
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::{Duration, TimeZone};
+
+    use super::find_live_stream_id;
+
+    sqlx::query!(
+        r#"
+INSERT INTO streams (stream_id, vtuber_id, title, channel_id, platform_id, platform, status)
+     VALUES (1, 'vtuber1', 'title1', 1, 'id1', 'twitch', 'live'),
+            (2, 'vtuber1', 'title2', 1, 'id2', 'twitch', 'ended')
+        "#
+    )
+    .execute(&pool)
+    .await?;
+
+    assert_eq!(find_live_stream_id(1, &pool).await?, Some(1));
+    assert_eq!(find_live_stream_id(2, &pool).await?, None);
+
+    let time = Utc.timestamp_opt(1700000000, 0).single().unwrap();
+    let labels = vec!["MatureGame".to_string()];
+
+    for (offset, title, category) in [(0, "title1", "Just Chatting"), (30, "title2", "Minecraft")] {
+        AddStreamTimelineQuery {
+            stream_id: 1,
+            time: time + Duration::minutes(offset),
+            title,
+            category_id: "0",
+            category_name: category,
+            language: "en",
+            content_labels: &labels,
+        }
+        .execute(&pool)
+        .await?;
+    }
+
+    let timeline = list_stream_timeline(1, &pool).await?;
+    assert_eq!(timeline.len(), 2);
+    assert_eq!(timeline[0].time, time);
+    assert_eq!(timeline[0].category_name, "Just Chatting");
+    assert_eq!(timeline[1].title, "title2");
+    assert_eq!(timeline[1].content_labels, labels);
+
+    assert!(list_stream_timeline(2, &pool).await?.is_empty());
+
+    super::delete_stream(1, &pool).await?;
+
+    assert!(list_stream_timeline(1, &pool).await?.is_empty());
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use sqlx::{PgPool, Result};
 
 use crate::channels::Platform;
 
-use super::StreamStatus;
+use super::{StreamAccess, StreamKind, StreamStatus};
 
 /// insert or update a stream row
 #[derive(Default)]
@@ -19,6 +19,8 @@ pub struct UpsertStreamQuery<'q> {
     pub schedule_time: Option<DateTime<Utc>>,
     pub start_time: Option<DateTime<Utc>>,
     pub end_time: Option<DateTime<Utc>>,
+    pub stream_kind: Option<StreamKind>,
+    pub access: Option<StreamAccess>,
 }
 
 impl<'q> UpsertStreamQuery<'q> {
@@ -35,16 +37,20 @@ INSERT INTO streams AS t (
                 schedule_time,
                 start_time,
                 end_time,
-                vtuber_id
+                vtuber_id,
+                stream_kind,
+                access
             )
-     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, COALESCE($11, 'live'::stream_kind), COALESCE($12, 'public'::stream_access))
 ON CONFLICT (platform, platform_id) DO UPDATE
         SET title          = COALESCE($4, t.title),
             status         = COALESCE($5, t.status),
             thumbnail_url  = COALESCE($6, t.thumbnail_url),
             schedule_time  = COALESCE($7, t.schedule_time),
             start_time     = COALESCE($8, t.start_time),
-            end_time       = COALESCE($9, t.end_time)
+            end_time       = COALESCE($9, t.end_time),
+            stream_kind    = COALESCE($11, t.stream_kind),
+            access         = COALESCE($12, t.access)
   RETURNING stream_id
             "#,
             self.platform as _,      // $1
@@ -57,6 +63,8 @@ ON CONFLICT (platform, platform_id) DO UPDATE
             self.start_time,         // $8
             self.end_time,           // $9
             self.vtuber_id,          // $10
+            self.stream_kind as _,   // $11
+            self.access as _,        // $12
         )
         .fetch_one(pool);
 
@@ -88,6 +96,7 @@ async fn test(pool: PgPool) -> Result<()> {
             status: StreamStatus::Live,
             thumbnail_url: Some("http://bing.com".into()),
             start_time: Some(time),
+            stream_kind: Some(StreamKind::Premiere),
             ..Default::default()
         }
         .execute(&pool)
@@ -149,13 +158,18 @@ async fn test(pool: PgPool) -> Result<()> {
         .execute(&pool)
         .await?;
 
-        let rows = sqlx::query!(r#"SELECT start_time FROM streams WHERE channel_id = 1"#)
-            .fetch_all(&pool)
-            .await?;
+        let rows = sqlx::query!(
+            r#"SELECT start_time, stream_kind AS "stream_kind: StreamKind", access AS "access: StreamAccess" FROM streams WHERE channel_id = 1"#
+        )
+        .fetch_all(&pool)
+        .await?;
 
         assert_eq!(stream_id, 1);
         assert_eq!(rows.len(), 1);
         assert_eq!(rows[0].start_time, Some(time));
+        // classification is kept if not given
+        assert_eq!(rows[0].stream_kind, StreamKind::Premiere);
+        assert_eq!(rows[0].access, StreamAccess::Public);
     }
 
     Ok(())
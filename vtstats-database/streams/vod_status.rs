@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{PgPool, Result};
+
+use crate::channels::Platform;
+
+#[derive(Debug, sqlx::Type, Serialize, PartialEq, Eq, Clone, Copy)]
+#[sqlx(type_name = "vod_status", rename_all = "snake_case")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VodStatus {
+    Available,
+    MembersOnly,
+    Private,
+    /// no vod was kept, on twitch it also covers vods which were deleted
+    /// or made private, as they are all missing from helix alike
+    Unarchived,
+    Deleted,
+}
+
+pub struct VodCheck {
+    pub stream_id: i32,
+    pub platform: Platform,
+    pub platform_id: String,
+    pub channel_platform_id: String,
+}
+
+/// list ended youtube and twitch streams whose vod should be checked again,
+/// streams are checked hourly in the first day after they ended, every 6
+/// hours in the first week, daily in the first 30 days and never after
+pub async fn list_vod_checks(
+    now: DateTime<Utc>,
+    limit: i64,
+    pool: &PgPool,
+) -> Result<Vec<VodCheck>> {
+    let query = sqlx::query_as!(
+        VodCheck,
+        r#"
+  SELECT s.stream_id,
+         s.platform AS "platform: _",
+         s.platform_id,
+         c.platform_id AS channel_platform_id
+    FROM streams s
+    JOIN channels c ON c.channel_id = s.channel_id
+   WHERE s.status = 'ended'
+     AND s.platform IN ('youtube', 'twitch')
+     AND s.end_time > $1::timestamptz - INTERVAL '30 days'
+     AND (
+             s.vod_checked_at IS NULL
+          OR s.vod_checked_at <= $1::timestamptz - CASE
+                 WHEN s.end_time > $1::timestamptz - INTERVAL '1 day' THEN INTERVAL '1 hour'
+                 WHEN s.end_time > $1::timestamptz - INTERVAL '7 days' THEN INTERVAL '6 hours'
+                 ELSE INTERVAL '1 day'
+             END
+         )
+ORDER BY s.vod_checked_at NULLS FIRST, s.end_time DESC
+   LIMIT $2
+        "#,
+        now,
+        limit,
+    )
+    .fetch_all(pool);
+
+    crate::otel::execute_query!("SELECT", "streams", query)
+}
+
+/// updates vod status of given stream, and records the transition if changed
+pub async fn update_vod_status(
+    stream_id: i32,
+    status: VodStatus,
+    time: DateTime<Utc>,
+    pool: &PgPool,
+) -> Result<()> {
+    let mut tx = pool.begin().await?;
+
+    let query = sqlx::query_scalar!(
+        r#"SELECT vod_status AS "vod_status: VodStatus" FROM streams WHERE stream_id = $1 FOR UPDATE"#,
+        stream_id
+    )
+    .fetch_optional(&mut *tx);
+
+    let previous = crate::otel::execute_query!("SELECT", "streams", query)?.flatten();
+
+    if previous != Some(status) {
+        let query = sqlx::query!(
+            "INSERT INTO stream_vod_history (stream_id, time, status) VALUES ($1, $2, $3) \
+            ON CONFLICT (stream_id, time) DO UPDATE SET status = $3",
+            stream_id,
+            time,
+            status as _,
+        )
+        .execute(&mut *tx);
+
+        crate::otel::execute_query!("INSERT", "stream_vod_history", query)?;
+    }
+
+    let query = sqlx::query!(
+        "UPDATE streams SET vod_status = $1, vod_checked_at = $2 WHERE stream_id = $3",
+        status as _,
+        time,
+        stream_id,
+    )
+    .execute(&mut *tx);
+
+    crate::otel::execute_query!("UPDATE", "streams", query)?;
+
+    tx.commit().await
+}
+
+#[cfg(test)]
+#[sqlx::test(fixtures("channels"))]
+async fn test(pool: PgPool) -> Result<()> {
+    use chrono::{Duration, TimeZone};
+
+    let now = Utc.timestamp_opt(1_700_000_000, 0).single().unwrap();
+
+    sqlx::query!(
+        r#"
+INSERT INTO streams (stream_id, vtuber_id, title, channel_id, platform_id, platform, end_time, status)
+     VALUES (1, 'vtuber1', 'title1', 1, 'id1', 'youtube', $1, 'ended'),
+            (2, 'vtuber1', 'title2', 1, 'id2', 'youtube', $2, 'ended'),
+            (3, 'vtuber1', 'title3', 1, 'id3', 'youtube', $3, 'ended'),
+            (4, 'vtuber1', 'title4', 1, 'id4', 'bilibili', $1, 'ended'),
+            (5, 'vtuber1', 'title5', 1, 'id5', 'youtube', NULL, 'live')
+        "#,
+        now - Duration::hours(2),
+        now - Duration::days(3),
+        now - Duration::days(40),
+    )
+    .execute(&pool)
+    .await?;
+
+    let ids = |checks: Vec<VodCheck>| checks.iter().map(|c| c.stream_id).collect::<Vec<_>>();
+
+    assert_eq!(ids(list_vod_checks(now, 10, &pool).await?), vec![1, 2]);
+
+    update_vod_status(1, VodStatus::Available, now, &pool).await?;
+    update_vod_status(2, VodStatus::Available, now, &pool).await?;
+
+    assert!(list_vod_checks(now, 10, &pool).await?.is_empty());
+
+    let later = now + Duration::hours(1);
+    assert_eq!(ids(list_vod_checks(later, 10, &pool).await?), vec![1]);
+
+    update_vod_status(1, VodStatus::Available, later, &pool).await?;
+
+    // least recently checked first
+    let later = now + Duration::hours(6);
+    assert_eq!(ids(list_vod_checks(later, 10, &pool).await?), vec![2, 1]);
+
+    update_vod_status(1, VodStatus::Unarchived, later, &pool).await?;
+
+    let history = sqlx::query!(
+        r#"SELECT time, status AS "status: VodStatus" FROM stream_vod_history WHERE stream_id = 1 ORDER BY time"#
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].status, VodStatus::Available);
+    assert_eq!(history[1].time, later);
+    assert_eq!(history[1].status, VodStatus::Unarchived);
+
+    let stream = super::get_stream_by_id(1, &pool).await?.unwrap();
+    assert_eq!(stream.vod_status, Some(VodStatus::Unarchived));
+
+    super::delete_stream(1, &pool).await?;
+
+    Ok(())
+}
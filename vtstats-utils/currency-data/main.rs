@@ -0,0 +1,111 @@
+//! Generates `vtstats-utils/currency_data.rs` from currency symbols in
+//! CLDR, shipped with `icu_experimental_data`, and the ISO 4217 list of
+//! `iso-codes`:
+//!
+//! ```sh
+//! cargo run -- /usr/share/iso-codes/json/iso_4217.json > ../currency_data.rs
+//! ```
+
+extern crate alloc;
+
+use std::collections::{BTreeSet, HashMap};
+
+use icu_experimental::dimension::provider::currency::{
+    CurrencyEssentialsV1Marker, PlaceholderValue,
+};
+use icu_provider::{datagen::IterableDataProvider, DataProvider, DataRequest};
+use serde::Deserialize;
+
+struct Baked;
+
+const _: () = {
+    mod icu {
+        pub use icu_experimental as experimental;
+        pub use icu_locid_transform as locid_transform;
+    }
+    icu_experimental_data::make_provider!(Baked);
+    icu_experimental_data::impl_currency_essentials_v1!(Baked);
+    icu_experimental_data::impliterable_currency_essentials_v1!(Baked);
+};
+
+#[derive(Deserialize)]
+struct Iso4217 {
+    #[serde(rename = "4217")]
+    currencies: Vec<Currency>,
+}
+
+#[derive(Deserialize)]
+struct Currency {
+    alpha_3: String,
+}
+
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "/usr/share/iso-codes/json/iso_4217.json".into());
+    let iso: Iso4217 = serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap();
+    let codes: BTreeSet<String> = iso.currencies.into_iter().map(|c| c.alpha_3).collect();
+
+    // number of locales using the symbol as standard and as narrow
+    // symbol of the currency
+    let mut symbols = HashMap::<(String, String), (usize, usize)>::new();
+
+    for locale in Baked.supported_locales().unwrap() {
+        let res = DataProvider::<CurrencyEssentialsV1Marker>::load(
+            &Baked,
+            DataRequest {
+                locale: &locale,
+                metadata: Default::default(),
+            },
+        )
+        .unwrap();
+        let data = res.take_payload().unwrap();
+        let data = data.get();
+
+        for (code, config) in data.pattern_config_map.iter_copied_values() {
+            let code = code.try_into_tinystr().unwrap().to_string();
+            if !codes.contains(&code) {
+                continue;
+            }
+
+            let symbol = |value: Option<PlaceholderValue>| match value {
+                Some(PlaceholderValue::Index(i)) => data
+                    .placeholders
+                    .get(i as usize)
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty() && !codes.contains(s)),
+                _ => None,
+            };
+
+            if let Some(short) = symbol(config.short_placeholder_value) {
+                symbols.entry((short, code.clone())).or_default().0 += 1;
+            }
+            if let Some(narrow) = symbol(config.narrow_placeholder_value) {
+                symbols.entry((narrow, code.clone())).or_default().1 += 1;
+            }
+        }
+    }
+
+    // sorted by symbol for binary search, the currency which uses it as
+    // standard symbol in most locales goes first
+    let mut symbols: Vec<_> = symbols.into_iter().collect();
+    symbols.sort_by(|((s1, c1), n1), ((s2, c2), n2)| s1.cmp(s2).then(n2.cmp(n1)).then(c1.cmp(c2)));
+
+    println!("// @generated by `vtstats-utils/currency-data`, do not edit");
+    println!();
+    println!("/// ISO 4217 currency codes, sorted");
+    println!("pub(crate) const CODES: &[&str] = &[");
+    for code in &codes {
+        println!("    {code:?},");
+    }
+    println!("];");
+    println!();
+    println!("/// standard and narrow symbols from CLDR, sorted by symbol, a symbol");
+    println!("/// shared by several currencies is listed once per currency, the one");
+    println!("/// using it as standard symbol in most locales goes first");
+    println!("pub(crate) const SYMBOLS: &[(&str, &str)] = &[");
+    for ((symbol, code), _) in &symbols {
+        println!("    ({symbol:?}, {code:?}),");
+    }
+    println!("];");
+}
@@ -1,22 +1,153 @@
-pub fn currency_symbol_to_code(i: &str) -> Option<&str> {
-    match i {
-        "$" => Some("USD"),
-        "€" => Some("EUR"),
-        "¥" => Some("JPY"),
-        "£" => Some("GBP"),
-        "A$" => Some("AUD"),
-        "CA$" => Some("CAD"),
-        "HK$" => Some("HKD"),
-        "NZ$" => Some("NZD"),
-        "₩" => Some("KRW"),
-        "MX$" => Some("MXN"),
-        "₹" => Some("INR"),
-        "R$" => Some("BRL"),
-        "NT$" => Some("TWD"),
-        "₪" => Some("ILS"),
-        "₱" => Some("PHP"),
-        "F CFA " => Some("XAF"),
-        i if i.len() == 3 && i.bytes().all(|c| c.is_ascii_uppercase()) => Some(i),
-        _ => None,
+use std::{collections::BTreeSet, fmt, sync::Mutex};
+
+use metrics::increment_counter;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::currency_data::{CODES, SYMBOLS};
+
+/// ISO 4217 currency code, e.g. `USD`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CurrencyCode([u8; 3]);
+
+impl CurrencyCode {
+    /// accepts codes listed in ISO 4217 only
+    pub fn new(code: &str) -> Option<Self> {
+        CODES.binary_search(&code).ok()?;
+
+        Some(CurrencyCode(code.as_bytes().try_into().ok()?))
     }
+
+    pub fn as_str(&self) -> &str {
+        // always listed in ISO 4217, see `new`
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// number of digits after the decimal separator, aka minor unit
+    pub fn exponent(&self) -> u32 {
+        match self.as_str() {
+            "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF"
+            | "UGX" | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+            "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+            "CLF" | "UYW" => 4,
+            _ => 2,
+        }
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for CurrencyCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencyCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+
+        CurrencyCode::new(&code)
+            .ok_or_else(|| de::Error::custom(format!("invalid currency code {code:?}")))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencySymbol {
+    pub code: CurrencyCode,
+    /// symbol is shared by several currencies, `code` is the most common one
+    pub ambiguous: bool,
+}
+
+/// resolves currency symbol or ISO 4217 code, e.g. `SGD`
+pub fn resolve_currency_symbol(symbol: &str) -> Option<CurrencySymbol> {
+    let symbol = symbol.trim();
+
+    let start = SYMBOLS.partition_point(|(s, _)| *s < symbol);
+    let mut codes = SYMBOLS[start..]
+        .iter()
+        .take_while(|(s, _)| *s == symbol)
+        .map(|(_, c)| *c);
+
+    let Some(code) = codes.next() else {
+        return Some(CurrencySymbol {
+            code: CurrencyCode::new(symbol)?,
+            ambiguous: false,
+        });
+    };
+
+    Some(CurrencySymbol {
+        code: CurrencyCode::new(code)?,
+        ambiguous: codes.next().is_some(),
+    })
+}
+
+/// distinct symbols labelled in `currency_unresolved_symbols_total`,
+/// further symbols are labelled as `other`
+const MAX_UNRESOLVED_SYMBOLS: usize = 100;
+
+static UNRESOLVED_SYMBOLS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// counts a symbol which `resolve_currency_symbol` doesn't know in
+/// `currency_unresolved_symbols_total`, labelled with the symbol itself,
+/// truncated to 8 characters
+pub fn record_unresolved_symbol(symbol: &str) {
+    let symbol: String = symbol.trim().chars().take(8).collect();
+
+    let label = match UNRESOLVED_SYMBOLS.lock() {
+        Ok(mut symbols) if symbols.contains(&symbol) || symbols.len() < MAX_UNRESOLVED_SYMBOLS => {
+            symbols.insert(symbol.clone());
+            symbol
+        }
+        _ => "other".to_string(),
+    };
+
+    increment_counter!("currency_unresolved_symbols_total", "symbol" => label);
+}
+
+pub fn currency_symbol_to_code(i: &str) -> Option<CurrencyCode> {
+    resolve_currency_symbol(i).map(|s| s.code)
+}
+
+#[test]
+fn test_resolve_currency_symbol() {
+    let resolve = |s: &str| resolve_currency_symbol(s).map(|s| (s.code.to_string(), s.ambiguous));
+
+    assert_eq!(resolve("SGD"), Some(("SGD".into(), false)));
+    assert_eq!(resolve("CHF"), Some(("CHF".into(), false)));
+    assert_eq!(resolve("CA$"), Some(("CAD".into(), false)));
+    assert_eq!(resolve("₫"), Some(("VND".into(), false)));
+    assert_eq!(resolve("RM"), Some(("MYR".into(), false)));
+    assert_eq!(resolve("zł"), Some(("PLN".into(), false)));
+    assert_eq!(resolve("FCFA "), Some(("XAF".into(), false)));
+    assert_eq!(resolve("$"), Some(("USD".into(), true)));
+    assert_eq!(resolve("kr"), Some(("NOK".into(), true)));
+    assert_eq!(resolve("CFA"), Some(("XOF".into(), false)));
+    assert_eq!(resolve("¥"), Some(("JPY".into(), true)));
+    assert_eq!(resolve("XYZ"), None);
+    assert_eq!(resolve("Abc"), None);
+    assert_eq!(resolve("?"), None);
+    assert_eq!(resolve(""), None);
+}
+
+#[test]
+fn test_currency_code() {
+    let code = |s: &str| CurrencyCode::new(s);
+
+    assert_eq!(code("USD").map(|c| c.to_string()), Some("USD".into()));
+    assert_eq!(code("usd"), None);
+    assert_eq!(code("US"), None);
+    assert_eq!(code("USDT"), None);
+    assert_eq!(code("XYZ"), None);
+    assert_eq!(code("JPY").map(|c| c.exponent()), Some(0));
+    assert_eq!(code("KWD").map(|c| c.exponent()), Some(3));
+    assert_eq!(code("EUR").map(|c| c.exponent()), Some(2));
+    assert_eq!(
+        serde_json::from_str::<CurrencyCode>("\"BHD\"").ok(),
+        code("BHD")
+    );
+    assert!(serde_json::from_str::<CurrencyCode>("\"$\"").is_err());
 }
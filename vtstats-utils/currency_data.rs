@@ -0,0 +1,995 @@
+// @generated by `vtstats-utils/currency-data`, do not edit
+
+/// ISO 4217 currency codes, sorted
+pub(crate) const CODES: &[&str] = &[
+    "AED",
+    "AFN",
+    "ALL",
+    "AMD",
+    "ANG",
+    "AOA",
+    "ARS",
+    "AUD",
+    "AWG",
+    "AZN",
+    "BAM",
+    "BBD",
+    "BDT",
+    "BGN",
+    "BHD",
+    "BIF",
+    "BMD",
+    "BND",
+    "BOB",
+    "BOV",
+    "BRL",
+    "BSD",
+    "BTN",
+    "BWP",
+    "BYN",
+    "BZD",
+    "CAD",
+    "CDF",
+    "CHE",
+    "CHF",
+    "CHW",
+    "CLF",
+    "CLP",
+    "CNY",
+    "COP",
+    "COU",
+    "CRC",
+    "CUC",
+    "CUP",
+    "CVE",
+    "CZK",
+    "DJF",
+    "DKK",
+    "DOP",
+    "DZD",
+    "EGP",
+    "ERN",
+    "ETB",
+    "EUR",
+    "FJD",
+    "FKP",
+    "GBP",
+    "GEL",
+    "GHS",
+    "GIP",
+    "GMD",
+    "GNF",
+    "GTQ",
+    "GYD",
+    "HKD",
+    "HNL",
+    "HRK",
+    "HTG",
+    "HUF",
+    "IDR",
+    "ILS",
+    "INR",
+    "IQD",
+    "IRR",
+    "ISK",
+    "JMD",
+    "JOD",
+    "JPY",
+    "KES",
+    "KGS",
+    "KHR",
+    "KMF",
+    "KPW",
+    "KRW",
+    "KWD",
+    "KYD",
+    "KZT",
+    "LAK",
+    "LBP",
+    "LKR",
+    "LRD",
+    "LSL",
+    "LYD",
+    "MAD",
+    "MDL",
+    "MGA",
+    "MKD",
+    "MMK",
+    "MNT",
+    "MOP",
+    "MRU",
+    "MUR",
+    "MVR",
+    "MWK",
+    "MXN",
+    "MXV",
+    "MYR",
+    "MZN",
+    "NAD",
+    "NGN",
+    "NIO",
+    "NOK",
+    "NPR",
+    "NZD",
+    "OMR",
+    "PAB",
+    "PEN",
+    "PGK",
+    "PHP",
+    "PKR",
+    "PLN",
+    "PYG",
+    "QAR",
+    "RON",
+    "RSD",
+    "RUB",
+    "RWF",
+    "SAR",
+    "SBD",
+    "SCR",
+    "SDG",
+    "SEK",
+    "SGD",
+    "SHP",
+    "SLE",
+    "SLL",
+    "SOS",
+    "SRD",
+    "SSP",
+    "STN",
+    "SVC",
+    "SYP",
+    "SZL",
+    "THB",
+    "TJS",
+    "TMT",
+    "TND",
+    "TOP",
+    "TRY",
+    "TTD",
+    "TWD",
+    "TZS",
+    "UAH",
+    "UGX",
+    "USD",
+    "USN",
+    "UYI",
+    "UYU",
+    "UYW",
+    "UZS",
+    "VED",
+    "VES",
+    "VND",
+    "VUV",
+    "WST",
+    "XAF",
+    "XAG",
+    "XAU",
+    "XBA",
+    "XBB",
+    "XBC",
+    "XBD",
+    "XCD",
+    "XDR",
+    "XOF",
+    "XPD",
+    "XPF",
+    "XPT",
+    "XSU",
+    "XTS",
+    "XUA",
+    "XXX",
+    "YER",
+    "ZAR",
+    "ZMW",
+    "ZWL",
+];
+
+/// standard and narrow symbols from CLDR, sorted by symbol, a symbol
+/// shared by several currencies is listed once per currency, the one
+/// using it as standard symbol in most locales goes first
+pub(crate) const SYMBOLS: &[(&str, &str)] = &[
+    ("$", "USD"),
+    ("$", "XCD"),
+    ("$", "AUD"),
+    ("$", "NZD"),
+    ("$", "SGD"),
+    ("$", "NAD"),
+    ("$", "COP"),
+    ("$", "LRD"),
+    ("$", "BZD"),
+    ("$", "CAD"),
+    ("$", "BND"),
+    ("$", "ARS"),
+    ("$", "SRD"),
+    ("$", "CLP"),
+    ("$", "GYD"),
+    ("$", "KYD"),
+    ("$", "MXN"),
+    ("$", "BBD"),
+    ("$", "BMD"),
+    ("$", "CUP"),
+    ("$", "FJD"),
+    ("$", "JMD"),
+    ("$", "SBD"),
+    ("$", "TTD"),
+    ("$", "UYU"),
+    ("$", "BSD"),
+    ("$", "TWD"),
+    ("$", "CUC"),
+    ("$", "DOP"),
+    ("$", "HKD"),
+    ("$ HK", "HKD"),
+    ("$ SU", "USD"),
+    ("$ T", "TOP"),
+    ("$ ZN", "NZD"),
+    ("$ ߝߖ", "FJD"),
+    ("$A", "AUD"),
+    ("$AR", "ARS"),
+    ("$AU", "AUD"),
+    ("$BM", "BMD"),
+    ("$BN", "BND"),
+    ("$BZ", "BZD"),
+    ("$C", "NIO"),
+    ("$CA", "CAD"),
+    ("$CL", "CLP"),
+    ("$CO", "COP"),
+    ("$EC", "XCD"),
+    ("$FJ", "FJD"),
+    ("$HK", "HKD"),
+    ("$LR", "LRD"),
+    ("$MN", "CUP"),
+    ("$MX", "MXN"),
+    ("$NA", "NAD"),
+    ("$NZ", "NZD"),
+    ("$SB", "SBD"),
+    ("$SG", "SGD"),
+    ("$SR", "SRD"),
+    ("$T", "TOP"),
+    ("$TT", "TTD"),
+    ("$U", "UYU"),
+    ("$US", "USD"),
+    ("$UY", "UYU"),
+    ("$WS", "WST"),
+    ("$\u{a0}AU", "AUD"),
+    ("$\u{a0}HK", "HKD"),
+    ("$\u{a0}NZ", "NZD"),
+    ("$\u{a0}SG", "SGD"),
+    ("$\u{a0}US", "USD"),
+    ("$ߐ", "AUD"),
+    ("$ߣߖ\u{7ed}", "NZD"),
+    ("$ߥߛ", "WST"),
+    ("A$", "AUD"),
+    ("AAS", "XOF"),
+    ("ALBL", "ALL"),
+    ("ALJD", "DZD"),
+    ("ANGK", "AOA"),
+    ("AR$", "ARS"),
+    ("ARBF", "AWG"),
+    ("ARJP", "ARS"),
+    ("ARMD", "AMD"),
+    ("AU$", "AUD"),
+    ("AUD$", "AUD"),
+    ("AWGA", "AFN"),
+    ("AZRM", "AZN"),
+    ("Af", "AFN"),
+    ("Afl", "AWG"),
+    ("Afl.", "AWG"),
+    ("Ar", "MGA"),
+    ("B$", "BSD"),
+    ("B/.", "PAB"),
+    ("BB$", "BBD"),
+    ("BBG", "BBD"),
+    ("BD$", "BMD"),
+    ("BGDT", "BDT"),
+    ("BHBM", "BAM"),
+    ("BHMD", "BSD"),
+    ("BLGL", "BGN"),
+    ("BLRR", "BYN"),
+    ("BLWB", "BOB"),
+    ("BLZD", "BZD"),
+    ("BM$", "BMD"),
+    ("BN$", "BND"),
+    ("BR$", "BRL"),
+    ("BRBD", "BBD"),
+    ("BRDF", "BIF"),
+    ("BRMD", "BMD"),
+    ("BRND", "BND"),
+    ("BRTP", "GBP"),
+    ("BS$", "BSD"),
+    ("BTNN", "BTN"),
+    ("BTSP", "BWP"),
+    ("BUB", "BOB"),
+    ("BZ$", "BZD"),
+    ("Bds$", "BBD"),
+    ("Br", "ETB"),
+    ("Br", "BYN"),
+    ("Br.", "BYN"),
+    ("Bs", "BOB"),
+    ("Bs.S", "VES"),
+    ("C$", "CAD"),
+    ("C$", "NIO"),
+    ("CA$", "CAD"),
+    ("CBAP", "CUP"),
+    ("CF", "KMF"),
+    ("CFA", "XOF"),
+    ("CFP", "XPF"),
+    ("CFPF", "XPF"),
+    ("CHKK", "CZK"),
+    ("CHNY", "CNY"),
+    ("CI$", "KYD"),
+    ("CL$", "CLP"),
+    ("CN¥", "CNY"),
+    ("CO$", "COP"),
+    ("CU$", "CUP"),
+    ("CUC$", "CUC"),
+    ("Cub$", "CUC"),
+    ("D", "GMD"),
+    ("DA", "DZD"),
+    ("DARF", "XAF"),
+    ("DBB", "BBD"),
+    ("DBM", "BMD"),
+    ("DBZ", "BZD"),
+    ("DIP", "XDR"),
+    ("DJBF", "DJF"),
+    ("DNMK", "DKK"),
+    ("DO$", "DOP"),
+    ("DOMP", "DOP"),
+    ("DT", "TND"),
+    ("Db", "STN"),
+    ("Dkr", "DKK"),
+    ("E", "SZL"),
+    ("EC$", "XCD"),
+    ("EGPP", "EGP"),
+    ("EG£", "EGP"),
+    ("ENDR", "IDR"),
+    ("ERNR", "IRR"),
+    ("ERTN", "ERN"),
+    ("ETPB", "ETB"),
+    ("E£", "EGP"),
+    ("F$", "FJD"),
+    ("F.G.", "GNF"),
+    ("F.Rw", "RWF"),
+    ("FBu", "BIF"),
+    ("FC", "CDF"),
+    ("FC", "KMF"),
+    ("FCF", "XAF"),
+    ("FCFA", "XAF"),
+    ("FCFP", "XPF"),
+    ("FG", "GNF"),
+    ("FJ$", "FJD"),
+    ("FJC", "FJD"),
+    ("FK£", "FKP"),
+    ("FR", "RWF"),
+    ("Fdj", "DJF"),
+    ("Fl£", "FKP"),
+    ("Ft", "HUF"),
+    ("F\u{202f}CFA", "XOF"),
+    ("G", "HTG"),
+    ("G$", "GYD"),
+    ("GBRP", "GIP"),
+    ("GB£", "GBP"),
+    ("GHC", "GHS"),
+    ("GHS.", "GHS"),
+    ("GH₵", "GHS"),
+    ("GIIP", "GIP"),
+    ("GMBD", "GMD"),
+    ("GNAS", "GHS"),
+    ("GT Q", "GTQ"),
+    ("GTMK", "GTQ"),
+    ("GWNF", "GNF"),
+    ("GY$", "GYD"),
+    ("Gs", "PYG"),
+    ("Gs.", "PYG"),
+    ("HGKD", "HKD"),
+    ("HGRF", "HUF"),
+    ("HK$", "HKD"),
+    ("HKS", "HKD"),
+    ("HNDL", "HNL"),
+    ("HNDR", "INR"),
+    ("HRKS", "HRK"),
+    ("HTNG", "HTG"),
+    ("ISLK", "ISK"),
+    ("Ikr", "ISK"),
+    ("Irak dinaa", "IQD"),
+    ("J$", "JMD"),
+    ("JM$", "JMD"),
+    ("JMKD", "JMD"),
+    ("JP¥", "JPY"),
+    ("JRJL", "GEL"),
+    ("K", "MMK"),
+    ("K", "ZMW"),
+    ("K", "PGK"),
+    ("KA$", "CAD"),
+    ("KBKP", "CUC"),
+    ("KGSS", "KGS"),
+    ("KINS", "KES"),
+    ("KLBP", "COP"),
+    ("KM", "BAM"),
+    ("KMBR", "KHR"),
+    ("KMID", "KYD"),
+    ("KMRF", "KMF"),
+    ("KN$", "CAD"),
+    ("KNGF", "CDF"),
+    ("KRK", "CRC"),
+    ("KRSK", "HRK"),
+    ("KWRE", "CVE"),
+    ("KY$", "KYD"),
+    ("KZKT", "KZT"),
+    ("Kn", "HRK"),
+    ("Kr", "ISK"),
+    ("Kr", "SEK"),
+    ("Kr", "DKK"),
+    ("Kr", "NOK"),
+    ("Ksh", "KES"),
+    ("Kw", "ZMW"),
+    ("Kw", "GTQ"),
+    ("Kz", "AOA"),
+    ("Kč", "CZK"),
+    ("L", "MDL"),
+    ("L", "HNL"),
+    ("L", "RON"),
+    ("L$", "LRD"),
+    ("LBNP", "LBP"),
+    ("LBRD", "LRD"),
+    ("LBYD", "LYD"),
+    ("LE", "EGP"),
+    ("LS", "SYP"),
+    ("LSTL", "LSL"),
+    ("LTKK", "LAK"),
+    ("Le", "SLE"),
+    ("Lekë", "ALL"),
+    ("Lp", "HNL"),
+    ("L£", "LBP"),
+    ("M", "LSL"),
+    ("MK", "MWK"),
+    ("MKDD", "MKD"),
+    ("MKNP", "MOP"),
+    ("MKS$", "MXN"),
+    ("MLDL", "MDL"),
+    ("MLDR", "MVR"),
+    ("MLGA", "MGA"),
+    ("MLRG", "MYR"),
+    ("MLWK", "MWK"),
+    ("MNGT", "MNT"),
+    ("MNMK", "MMK"),
+    ("MOP$", "MOP"),
+    ("MRKD", "MAD"),
+    ("MRTU", "MRU"),
+    ("MSRP", "EGP"),
+    ("MTn", "MZN"),
+    ("MURR", "MUR"),
+    ("MX$", "MXN"),
+    ("MZBM", "MZN"),
+    ("N$", "NAD"),
+    ("NAf", "ANG"),
+    ("NAf.", "ANG"),
+    ("NGN.", "NGN"),
+    ("NIS", "ILS"),
+    ("NJRN", "NGN"),
+    ("NKGC", "NIO"),
+    ("NKr", "NOK"),
+    ("NLAG", "ANG"),
+    ("NMBD", "NAD"),
+    ("NPLR", "NPR"),
+    ("NRWK", "NOK"),
+    ("NT$", "TWD"),
+    ("NTWD", "TWD"),
+    ("NZ$", "NZD"),
+    ("NZD$", "NZD"),
+    ("Nfk", "ERN"),
+    ("Nkr", "NOK"),
+    ("OZBS", "UZS"),
+    ("P", "BWP"),
+    ("P.", "BYN"),
+    ("PKRS", "PKR"),
+    ("PLNZ", "PLN"),
+    ("PLPP", "PHP"),
+    ("PNMB", "PAB"),
+    ("PRGG", "PYG"),
+    ("PRSL", "PEN"),
+    ("Q", "GTQ"),
+    ("R", "ZAR"),
+    ("R$", "BRL"),
+    ("RACF", "XOF"),
+    ("RD$", "DOP"),
+    ("RF", "RWF"),
+    ("RKB$", "XCD"),
+    ("RM", "MYR"),
+    ("RMNL", "RON"),
+    ("RUSR", "RUB"),
+    ("RWDF", "RWF"),
+    ("Rf", "MVR"),
+    ("Rp", "IDR"),
+    ("Rs", "PKR"),
+    ("Rs", "MUR"),
+    ("Rs", "INR"),
+    ("Rs", "SCR"),
+    ("Rs", "LKR"),
+    ("Rs", "NPR"),
+    ("Rs.", "LKR"),
+    ("S", "SOS"),
+    ("S$", "SGD"),
+    ("S$", "SBD"),
+    ("S/", "PEN"),
+    ("SB$", "SBD"),
+    ("SCLR", "SCR"),
+    ("SCRu", "SCR"),
+    ("SDNP", "SDG"),
+    ("SD£", "SSP"),
+    ("SGPD", "SGD"),
+    ("SHKW", "KPW"),
+    ("SHLP", "SHP"),
+    ("SI$", "SBD"),
+    ("SLNL", "SLE"),
+    ("SR", "SCR"),
+    ("SR$", "SRD"),
+    ("SRBD", "RSD"),
+    ("SRLL", "SLL"),
+    ("SRLR", "LKR"),
+    ("SRND", "SRD"),
+    ("STD", "STN"),
+    ("STPD", "STN"),
+    ("SURP", "SYP"),
+    ("SWDK", "SEK"),
+    ("SWZF", "CHF"),
+    ("SWZL", "SZL"),
+    ("S£", "SYP"),
+    ("SÓMS", "SOS"),
+    ("T$", "TOP"),
+    ("TDTD", "TTD"),
+    ("TK", "BDT"),
+    ("TNSD", "TND"),
+    ("TNZS", "TZS"),
+    ("TRKL", "TRY"),
+    ("TSh", "TZS"),
+    ("TT$", "TTD"),
+    ("Tk", "BDT"),
+    ("TÁIB", "THB"),
+    ("UK£", "GBP"),
+    ("UM", "MRU"),
+    ("UP", "UYW"),
+    ("US $", "USD"),
+    ("US$", "USD"),
+    ("USh", "UGX"),
+    ("UY$", "UYU"),
+    ("VT", "VUV"),
+    ("Vote $", "CLP"),
+    ("Vote Ft", "HUF"),
+    ("Vote MDL", "MDL"),
+    ("Vote lei", "RON"),
+    ("WNZB", "VES"),
+    ("WS$", "WST"),
+    ("WTND", "VND"),
+    ("XXXX", "XXX"),
+    ("YKNH", "UAH"),
+    ("YRGP", "UYU"),
+    ("YUGS", "UGX"),
+    ("ZAPR", "ZAR"),
+    ("ZBKW", "KRW"),
+    ("ZK", "ZMW"),
+    ("ZMBK", "ZMW"),
+    ("ZRSP", "SSP"),
+    ("Zk", "ZMW"),
+    ("Zł", "PLN"),
+    ("den", "MKD"),
+    ("din.", "RSD"),
+    ("kn", "HRK"),
+    ("kr", "NOK"),
+    ("kr", "SEK"),
+    ("kr", "DKK"),
+    ("kr", "ISK"),
+    ("kr.", "DKK"),
+    ("kr.", "ISK"),
+    ("kr.", "NOK"),
+    ("kr.", "SEK"),
+    ("lei", "RON"),
+    ("lej", "RON"),
+    ("leu", "RON"),
+    ("ley", "RON"),
+    ("p.", "BYN"),
+    ("r.", "BYN"),
+    ("rb", "RUB"),
+    ("soʻm", "UZS"),
+    ("zl", "PLN"),
+    ("zł", "PLN"),
+    ("£", "GBP"),
+    ("£", "SSP"),
+    ("£", "SHP"),
+    ("£", "GIP"),
+    ("£", "FKP"),
+    ("£", "SYP"),
+    ("£", "EGP"),
+    ("£ E", "EGP"),
+    ("£ RU", "GBP"),
+    ("£E", "EGP"),
+    ("£FK", "FKP"),
+    ("£GB", "GBP"),
+    ("£GI", "GIP"),
+    ("£L", "LBP"),
+    ("£LB", "LBP"),
+    ("¤", "XXX"),
+    ("¥", "JPY"),
+    ("¥", "CNY"),
+    ("¥CN", "CNY"),
+    ("Íkr", "ISK"),
+    ("КМ", "BAM"),
+    ("Кч", "CZK"),
+    ("ЛСЛ", "LSL"),
+    ("ТМТ", "TMT"),
+    ("Тл", "TRY"),
+    ("ден.", "MKD"),
+    ("дин.", "RSD"),
+    ("зл", "PLN"),
+    ("кр", "SEK"),
+    ("лв.", "BGN"),
+    ("р", "BYN"),
+    ("р.", "BYN"),
+    ("руб", "RUB"),
+    ("руб.", "RUB"),
+    ("сом", "KGS"),
+    ("сом.", "TJS"),
+    ("сўм", "UZS"),
+    ("щ.д.", "USD"),
+    ("֏", "AMD"),
+    ("؋", "AFN"),
+    ("أ.م.", "MRU"),
+    ("ج.س.", "SDG"),
+    ("ج.م.\u{200f}", "EGP"),
+    ("د.أ.\u{200f}", "JOD"),
+    ("د.إ.\u{200f}", "AED"),
+    ("د.ب.\u{200f}", "BHD"),
+    ("د.ت.\u{200f}", "TND"),
+    ("د.ج.\u{200f}", "DZD"),
+    ("د.ع.\u{200f}", "IQD"),
+    ("د.ك.\u{200f}", "KWD"),
+    ("د.ل.\u{200f}", "LYD"),
+    ("د.م.\u{200f}", "MAD"),
+    ("ر.إ.", "IRR"),
+    ("ر.س.\u{200f}", "SAR"),
+    ("ر.ع.\u{200f}", "OMR"),
+    ("ر.ق.\u{200f}", "QAR"),
+    ("ر.ي.\u{200f}", "YER"),
+    ("ریال", "IRR"),
+    ("فرانک\u{202f}CFA", "XOF"),
+    ("ل.س.\u{200f}", "SYP"),
+    ("ل.ل.\u{200f}", "LBP"),
+    ("ߊߐߘ", "AED"),
+    ("ߊߖߣ", "AZN"),
+    ("ߊߖ\u{7ed}ߔ", "ARS"),
+    ("ߊߙ", "MGA"),
+    ("ߊߝߊ", "AFN"),
+    ("ߊߞߘ$", "USD"),
+    ("ߊߟߘ", "DZD"),
+    ("ߊߟߟ", "ALL"),
+    ("ߊߡߘ", "AMD"),
+    ("ߊߥߖ\u{7ed}", "AWG"),
+    ("ߊ\u{7f2}ߕߝ", "ANG"),
+    ("ߊ\u{7f2}ߜ\u{7ed}ߎ", "AOA"),
+    ("ߋߗߓ", "ETB"),
+    ("ߋߙߝ", "ERN"),
+    ("ߌߎߙ", "RUB"),
+    ("ߌߙߙ", "IRR"),
+    ("ߌߛߞ", "ISK"),
+    ("ߌߞߘ", "IQD"),
+    ("ߌߞߤ", "UAH"),
+    ("ߍߛߞ", "CVE"),
+    ("ߍ\u{7f2}ߘߙ", "IDR"),
+    ("ߎߗ\u{7ed}ߛ", "UZS"),
+    ("ߎߜ\u{7ed}ߛ", "UGX"),
+    ("ߎ\u{7f3}ߔ", "UYU"),
+    ("ߏߡߙ", "OMR"),
+    ("ߓߌߙ", "BYN"),
+    ("ߓߍߡ", "BAM"),
+    ("ߓߏߓ", "BOB"),
+    ("ߓߓߘ", "BBD"),
+    ("ߓߕߒ", "BTN"),
+    ("ߓߘߕ", "BDT"),
+    ("ߓߙߝ", "BIF"),
+    ("ߓߛߘ", "BSD"),
+    ("ߓߜ\u{7ed}ߟ", "BGN"),
+    ("ߓߡߘ", "BMD"),
+    ("ߓߣߘ", "BND"),
+    ("ߓߤߘ", "BHD"),
+    ("ߓߥߔ", "BWP"),
+    ("ߔ", "BWP"),
+    ("ߔߊߓ", "PAB"),
+    ("ߔߋߛ", "PEN"),
+    ("ߔߖߞ", "PGK"),
+    ("ߔߙߜ\u{7ed}", "PYG"),
+    ("ߔߞߙ", "PKR"),
+    ("ߔߟߗ\u{7ed}", "PLN"),
+    ("ߕߎߟ", "TRY"),
+    ("ߕߏߔ", "TOP"),
+    ("ߕߕߘ", "TTD"),
+    ("ߕߖߛ", "TJS"),
+    ("ߕߖ\u{7ed}ߛ", "TZS"),
+    ("ߕߘߓ", "THB"),
+    ("ߕߡߕ", "TMT"),
+    ("ߕߣߘ", "TND"),
+    ("ߕߥߘ", "TWD"),
+    ("ߖߐߘ", "JOD"),
+    ("ߖߓߝ", "DJF"),
+    ("ߖߡߘ", "JMD"),
+    ("ߖߣ", "NGN"),
+    ("ߖ\u{7ed}ߐߟ", "GEL"),
+    ("ߖ\u{7ed}ߓߔ£", "GIP"),
+    ("ߖ\u{7ed}ߓߥ", "ZMW"),
+    ("ߖ\u{7ed}ߞ", "ZMW"),
+    ("ߖ\u{7ed}ߥߟ", "ZWL"),
+    ("ߗߞߞ", "CZK"),
+    ("ߗ\u{7ed}ߔߦ", "JPY"),
+    ("ߘߏߔ", "DOP"),
+    ("ߘߞߞ", "DKK"),
+    ("ߙߎߟ", "RON"),
+    ("ߙߛ", "MUR"),
+    ("ߙߛߘ", "RSD"),
+    ("ߙߥߝ", "RWF"),
+    ("ߛ", "NAD"),
+    ("ߛߊߙ", "SAR"),
+    ("ߛߌߔ", "SYP"),
+    ("ߛߎߘ", "SRD"),
+    ("ߛߓ", "STN"),
+    ("ߛߓߘ", "SBD"),
+    ("ߛߔߘ", "STN"),
+    ("ߛߖ\u{7ed}ߟ", "SZL"),
+    ("ߛߘߜ\u{7ed}", "SDG"),
+    ("ߛߙߔ", "SLE"),
+    ("ߛߛߔ", "SSP"),
+    ("ߛߛߥ", "SCR"),
+    ("ߛߜ\u{7ed}ߘ", "SGD"),
+    ("ߛߟߔ", "CLP"),
+    ("ߛߟߘ", "XCD"),
+    ("ߛߟߟ", "SLL"),
+    ("ߛߡߛ", "SOS"),
+    ("ߛߣߦ", "CNY"),
+    ("ߛߤߔ", "SHP"),
+    ("ߛߥߝ", "CHF"),
+    ("ߛߥߞ", "SEK"),
+    ("ߜ\u{7ed}ߎ\u{7f3}ߘ", "GYD"),
+    ("ߜ\u{7ed}ߓߘ", "GMD"),
+    ("ߜ\u{7ed}ߕߞ", "GTQ"),
+    ("ߜ\u{7ed}ߛߘ", "GHS"),
+    ("ߝߙ", "RWF"),
+    ("ߝߛ", "KMF"),
+    ("ߝߛߝ", "XAF"),
+    ("ߝߞߔ", "FKP"),
+    ("ߝ\u{7ed}ߋߓ", "VES"),
+    ("ߝ\u{7ed}ߕߝ\u{7ed}", "VUV"),
+    ("ߞߊߙ", "QAR"),
+    ("ߞߋߛ", "KES"),
+    ("ߞߌߘ", "KYD"),
+    ("ߞߎ\u{7f3}ߔ", "CUP"),
+    ("ߞߎ\u{7f3}ߞ", "CUC"),
+    ("ߞߐߔ", "COP"),
+    ("ߞߓߙ", "KHR"),
+    ("ߞߖ\u{7ed}", "AOA"),
+    ("ߞߗ\u{7ed}ߕ", "KZT"),
+    ("ߞߙߞ", "CRC"),
+    ("ߞߛߞ", "HRK"),
+    ("ߞߜ\u{7ed}ߛ", "KGS"),
+    ("ߞߝ", "CDF"),
+    ("ߞߞߥ", "KPW"),
+    ("ߞߡߝ", "KMF"),
+    ("ߞߥߘ", "KWD"),
+    ("ߟߊߞ", "LAK"),
+    ("ߟߓ£", "LBP"),
+    ("ߟߓߘ", "LYD"),
+    ("ߟߛߟ", "LSL"),
+    ("ߟߞߙ", "LKR"),
+    ("ߟ߾", "LRD"),
+    ("ߡߎߜ\u{7ed}", "MRU"),
+    ("ߡߏߔ", "MOP"),
+    ("ߡߖ\u{7ed}ߡ", "MZN"),
+    ("ߡߘߙ", "MGA"),
+    ("ߡߘߟ", "MDL"),
+    ("ߡߘߤ", "MAD"),
+    ("ߡߙߔ", "MUR"),
+    ("ߡߛߔ", "EGP"),
+    ("ߡߝ\u{7ed}ߙ", "MVR"),
+    ("ߡߞ$", "MXN"),
+    ("ߡߞߘ", "MKD"),
+    ("ߡߟߞ", "MWK"),
+    ("ߡߡߞ", "MMK"),
+    ("ߡߣߕ", "MNT"),
+    ("ߡߦߙ", "MYR"),
+    ("ߣߌߏ", "NIO"),
+    ("ߣߐߞ", "NOK"),
+    ("ߣߔߙ", "NPR"),
+    ("ߣߡߘ", "NAD"),
+    ("ߤߕߜ\u{7ed}", "HTG"),
+    ("ߤߘߟ", "HNL"),
+    ("ߤߜ\u{7ed}ߝ", "HUF"),
+    ("ߤߞߘ", "HKD"),
+    ("ߥߝߙ", "ZAR"),
+    ("ߦߡߙ", "YER"),
+    ("߾", "XOF"),
+    ("߾ ߝߛߝ", "XPF"),
+    ("߿", "GNF"),
+    ("अ.एम.आर", "OMR"),
+    ("अल", "ALL"),
+    ("आइ.किउ.दि", "IQD"),
+    ("आई.एस.क\u{947}", "ISK"),
+    ("आर", "ZAR"),
+    ("आर.अ.एन", "RON"),
+    ("आर.एफ", "RWF"),
+    ("आर.एस", "MUR"),
+    ("आर.एस.दि", "RSD"),
+    ("इ.आर.एन", "ERN"),
+    ("इ.ति.बि", "ETB"),
+    ("इ.सि $", "XCD"),
+    ("इउ.ए.ऐत\u{94d}स", "UAH"),
+    ("इउ.जि.एक\u{94d}स", "UGX"),
+    ("इउ.ज\u{947}त.एस", "UZS"),
+    ("इउ.वाई.इउ", "UYU"),
+    ("ई.जि.पि", "EGP"),
+    ("ए.अ.ए", "AOA"),
+    ("ए.आर", "MGA"),
+    ("ए.आर.एस", "ARS"),
+    ("ए.इ.दि", "AED"),
+    ("ए.एन.जि", "ANG"),
+    ("ए.एम.दि", "AMD"),
+    ("ए.ज\u{947}त.एन", "AZN"),
+    ("ए.दब\u{94d}ल\u{94d}य\u{941}.जि", "AWG"),
+    ("एन.अ.क\u{947}", "NOK"),
+    ("एन.आई.अ", "NIO"),
+    ("एन.ए.दि", "NAD"),
+    ("एन.जि.एन", "NGN"),
+    ("एन.ति$", "TWD"),
+    ("एफ.क\u{947}.पि", "FKP"),
+    ("एफ.जि", "GNF"),
+    ("एफ.ति", "HUF"),
+    ("एम.अ.पि", "MOP"),
+    ("एम.आर.इउ", "MRU"),
+    ("एम.इउ.आर", "MUR"),
+    ("एम.ए.दि", "MAD"),
+    ("एम.एक\u{94d}स $", "MXN"),
+    ("एम.एन.ति", "MNT"),
+    ("एम.क\u{947}.दि", "MKD"),
+    ("एम.जि.ए", "MGA"),
+    ("एम.ज\u{947}त.एन", "MZN"),
+    ("एम.डि.एल", "MDL"),
+    ("एम.दब\u{94d}ल\u{94d}य\u{941}.क\u{947}", "MWK"),
+    ("एल.आर.दि", "LRD"),
+    ("एल.एस.एल", "LSL"),
+    ("एल.बि.पि", "LBP"),
+    ("एल.वाई.दि", "LYD"),
+    ("एल£", "LBP"),
+    ("एस.अ.एस", "SOS"),
+    ("एस.आर.डि", "SRD"),
+    ("एस.इ.क\u{947}", "SEK"),
+    ("एस.ए.आर", "SAR"),
+    ("एस.एल.इ", "SLE"),
+    ("एस.एल.एल", "SLL"),
+    ("एस.एस.पि", "SSP"),
+    ("एस.ऐत\u{94d}स.पि", "SHP"),
+    ("एस.ज\u{947}त.एल", "SZL"),
+    ("एस.ति.एन", "STN"),
+    ("एस.दि.जि", "SDG"),
+    ("एस.वाई.पि", "SYP"),
+    ("एस.सि.आर", "SCR"),
+    ("ऐत\u{94d}स.आर.क\u{947}", "HRK"),
+    ("ऐत\u{94d}स.इउ.एफ", "HUF"),
+    ("ऐत\u{94d}स.एन.एल", "HNL"),
+    ("ऐत\u{94d}स.क\u{947}$", "HKD"),
+    ("ऐत\u{94d}स.ति.जि", "HTG"),
+    ("किउ.ए.आर", "QAR"),
+    ("क\u{947}.आर", "DKK"),
+    ("क\u{947}.आर", "ISK"),
+    ("क\u{947}.आर", "NOK"),
+    ("क\u{947}.आर", "SEK"),
+    ("क\u{947}.इ.एस", "KES"),
+    ("क\u{947}.एन", "HRK"),
+    ("क\u{947}.एम", "BAM"),
+    ("क\u{947}.एम.एफ", "KMF"),
+    ("क\u{947}.जि.एस", "KGS"),
+    ("क\u{947}.ज\u{947}त", "AOA"),
+    ("क\u{947}.ज\u{947}त.ति", "KZT"),
+    ("क\u{947}.दब\u{94d}ल\u{94d}य\u{941}.दि", "KWD"),
+    ("क\u{947}.पि.दब\u{94d}ल\u{94d}य\u{941}", "KPW"),
+    ("क\u{947}.वाई.दि", "KYD"),
+    ("जि.आइ.पि", "GIP"),
+    ("जि.इ.एल", "GEL"),
+    ("जि.एन.एफ", "GNF"),
+    ("जि.एम.दि", "GMD"),
+    ("जि.ऐत\u{94d}स.एस", "GHS"),
+    ("जि.ऐत\u{94d}स₵", "GHS"),
+    ("जि.ति.किउ", "GTQ"),
+    ("जि.वाई.दि", "GYD"),
+    ("ज\u{947}.अ.दि", "JOD"),
+    ("ज\u{947}.एम.दि", "JMD"),
+    ("ज\u{947}त.ए.आर", "ZAR"),
+    ("ज\u{947}त.क\u{947}", "ZMW"),
+    ("डि.अ.पि", "DOP"),
+    ("ति.आर.वाई", "TRY"),
+    ("ति.एन.दि", "TND"),
+    ("ति.एम.ति", "TMT"),
+    ("ति.ज\u{947}.एस", "TJS"),
+    ("ति.ज\u{947}त.एस", "TZS"),
+    ("ति.ति.डि", "TTD"),
+    ("दि.क\u{947}.क\u{947}", "DKK"),
+    ("दि.ज\u{947}.एफ", "DJF"),
+    ("दि.ज\u{947}त.दि", "DZD"),
+    ("दि.बि", "STN"),
+    ("न\u{947}र\u{942}", "NPR"),
+    ("पि", "BWP"),
+    ("पि.आई.जि", "PYG"),
+    ("पि.इ.एन", "PEN"),
+    ("पि.ए.बि", "PAB"),
+    ("पि.एल.एन", "PLN"),
+    ("बि.अ.बि", "BOB"),
+    ("बि.आइ.एफ", "BIF"),
+    ("बि.ए.एम", "BAM"),
+    ("बि.एम.डि", "BMD"),
+    ("बि.एस", "BOB"),
+    ("बि.एस.दि", "BSD"),
+    ("बि.ऐत\u{94d}स.दि", "BHD"),
+    ("बि.जि.एन", "BGN"),
+    ("बि.ज\u{947}द.डि", "BZD"),
+    ("बि.दब\u{94d}ल\u{94d}य\u{941}.पि", "BWP"),
+    ("बि.बि.दि", "BBD"),
+    ("बि.वाई.एन", "BYN"),
+    ("भि.इ.एस", "VES"),
+    ("र\u{942}", "NPR"),
+    ("र\u{942}ब", "RUB"),
+    ("रॉन", "RON"),
+    ("ल\u{947}ई", "RON"),
+    ("वाई.इ.आर", "YER"),
+    ("सि $", "NIO"),
+    ("सि.अ.पि", "COP"),
+    ("सि.आर.सि", "CRC"),
+    ("सि.इउ.पि", "CUP"),
+    ("सि.इउ.सि", "CUC"),
+    ("सि.ए $", "CAD"),
+    ("सि.एफ", "KMF"),
+    ("सि.एल.पि", "CLP"),
+    ("सि.ऐत\u{94d}स.एफ", "CHF"),
+    ("सि.ज\u{947}द.क\u{947}", "CZK"),
+    ("सि.दि.एफ", "CDF"),
+    ("सि.भि.इ", "CVE"),
+    ("सिएन¥", "CNY"),
+    ("৳", "BDT"),
+    ("ર\u{ac2}.", "MUR"),
+    ("ಲ\u{cc0}", "RON"),
+    ("ර\u{dd4}.", "LKR"),
+    ("ස\u{dd2}ෆ\u{dca}එ", "XOF"),
+    ("฿", "THB"),
+    ("ლ", "GEL"),
+    ("ብር", "ETB"),
+    ("ឡ\u{17bc}ទ\u{17b8}", "LSL"),
+    ("៛", "KHR"),
+    ("\u{200b}", "CVE"),
+    ("\u{200e}CN¥\u{200e}", "CNY"),
+    ("₡", "CRC"),
+    ("₦", "NGN"),
+    ("₩", "KRW"),
+    ("₩", "KPW"),
+    ("₪", "ILS"),
+    ("₫", "VND"),
+    ("€", "EUR"),
+    ("₭", "LAK"),
+    ("₮", "MNT"),
+    ("₱", "PHP"),
+    ("₱", "CUP"),
+    ("₲", "PYG"),
+    ("₴", "UAH"),
+    ("₵", "GHS"),
+    ("₸", "KZT"),
+    ("₹", "INR"),
+    ("₺", "TRY"),
+    ("₼", "AZN"),
+    ("₽", "RUB"),
+    ("₾", "GEL"),
+    ("⃀", "KGS"),
+    ("レイ", "RON"),
+    ("元", "CNY"),
+    ("￥", "CNY"),
+    ("￥", "JPY"),
+    ("￦", "KRW"),
+    ("𞤅𞤊𞤀", "XOF"),
+    ("𞤆𞤆𞤖", "PHP"),
+    ("𞤊𞤅𞤊𞤀", "XAF"),
+    ("𞤊𞤘", "GNF"),
+    ("𞤐𞤐𞤘", "NGN"),
+    ("𞤑𞤆𞤘", "PGK"),
+];
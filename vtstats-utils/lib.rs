@@ -1,4 +1,6 @@
 pub mod currency;
+#[rustfmt::skip]
+mod currency_data;
 pub mod metrics;
 pub mod panic;
 pub mod reqwest;
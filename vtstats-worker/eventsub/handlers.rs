@@ -0,0 +1,262 @@
+use chrono::{DateTime, Utc};
+use integration_s3::upload_file;
+use reqwest::Client;
+use tracing::Span;
+use vtstats_database::{
+    categories::{assign_stream_category, UpsertCategoryQuery},
+    channels::{get_active_channel_by_platform_id, Platform},
+    jobs::queue_collect_twitch_stream_metadata,
+    streams::{
+        claim_scheduled_stream, end_twitch_stream, find_live_stream_id, update_stream_title,
+        AddStreamTimelineQuery, StreamStatus, UpsertStreamQuery,
+    },
+    PgPool,
+};
+
+use integration_twitch::{gql::stream_metadata, ChannelUpdateEvent, Event};
+
+/// handles twitch eventsub event, no matter which transport it comes from
+pub async fn handle_event(event: Event, pool: &PgPool) -> anyhow::Result<()> {
+    match event {
+        Event::ChannelUpdateEvent(event) => {
+            tracing::info!("twitch channel.update: {:?}", event);
+
+            handle_channel_update(event, pool).await
+        }
+        Event::StreamOnlineEvent(event) => {
+            tracing::info!("twitch stream.online: {:?}", event);
+
+            handle_stream_online(
+                event.broadcaster_user_id,
+                event.broadcaster_user_login,
+                event.id,
+                event.started_at,
+                pool,
+            )
+            .await
+        }
+        Event::StreamOfflineEvent(event) => {
+            tracing::info!("twitch stream.offline: {:?}", event);
+
+            handle_stream_offline(
+                event.broadcaster_user_id,
+                event.broadcaster_user_login,
+                pool,
+            )
+            .await
+        }
+    }
+}
+
+async fn handle_stream_online(
+    platform_channel_id: String,
+    platform_channel_login: String,
+    platform_stream_id: String,
+    stream_start_time: DateTime<Utc>,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    let client = vtstats_utils::reqwest::new()?;
+
+    let channel =
+        get_active_channel_by_platform_id(Platform::Twitch, &platform_channel_id, pool).await?;
+
+    let Some(channel) = channel else {
+        tracing::warn!("Cannot find twitch channel of #{}", platform_channel_login);
+        return Ok(());
+    };
+
+    let metadata = stream_metadata(&platform_channel_login, &client).await?;
+
+    let Some(platform_stream) = metadata.data.user.stream else {
+        return Ok(());
+    };
+
+    if platform_stream.id != platform_stream_id {
+        return Ok(());
+    }
+
+    let title = metadata
+        .data
+        .user
+        .last_broadcast
+        .title
+        .filter(|_| matches!(metadata.data.user.last_broadcast.id, Some(id) if id == platform_stream.id))
+        .unwrap_or_else(|| format!("Twitch stream #{}", platform_channel_login));
+
+    // stream scheduled in twitch schedule now becomes this broadcast
+    if let Some(stream_id) = claim_scheduled_stream(
+        channel.channel_id,
+        &platform_stream_id,
+        stream_start_time,
+        pool,
+    )
+    .await?
+    {
+        tracing::info!(
+            "twitch broadcast {platform_stream_id} claimed scheduled stream {stream_id}"
+        );
+    }
+
+    let stream_id = UpsertStreamQuery {
+        vtuber_id: &channel.vtuber_id,
+        platform: Platform::Twitch,
+        platform_stream_id: &platform_stream_id,
+        channel_id: channel.channel_id,
+        title: &title,
+        status: StreamStatus::Live,
+        thumbnail_url: Some(format!(
+            "https://static-cdn.jtvnw.net/previews-ttv/live_user_{}-1280x720.jpg",
+            platform_channel_login
+        )),
+        schedule_time: None,
+        start_time: Some(stream_start_time),
+        end_time: None,
+        stream_kind: None,
+        access: None,
+    }
+    .execute(pool)
+    .await?;
+
+    Span::current().record("stream_id", stream_id);
+
+    let (category_id, category_name) = match &platform_stream.game {
+        Some(game) => (game.id.as_str(), game.name.as_str()),
+        None => ("", ""),
+    };
+
+    // initial entry, later changes come from channel.update,
+    // language and labels aren't known until then
+    AddStreamTimelineQuery {
+        stream_id,
+        time: stream_start_time,
+        title: &title,
+        category_id,
+        category_name,
+        language: "",
+        content_labels: &[],
+    }
+    .execute(pool)
+    .await?;
+
+    if let Some(game) = platform_stream.game {
+        let category_id = UpsertCategoryQuery {
+            platform: Platform::Twitch,
+            platform_category_id: &game.id,
+            name: &game.name,
+        }
+        .execute(pool)
+        .await?;
+
+        assign_stream_category(stream_id, category_id, stream_start_time, pool).await?;
+    }
+
+    queue_collect_twitch_stream_metadata(Utc::now(), stream_id, pool).await?;
+
+    Ok(())
+}
+
+/// records title and category changes of the live stream, updates
+/// made while channel is offline are ignored
+async fn handle_channel_update(event: ChannelUpdateEvent, pool: &PgPool) -> anyhow::Result<()> {
+    let channel =
+        get_active_channel_by_platform_id(Platform::Twitch, &event.broadcaster_user_id, pool)
+            .await?;
+
+    let Some(channel) = channel else {
+        tracing::warn!(
+            "Cannot find twitch channel of #{}",
+            event.broadcaster_user_login
+        );
+        return Ok(());
+    };
+
+    let Some(stream_id) = find_live_stream_id(channel.channel_id, pool).await? else {
+        return Ok(());
+    };
+
+    Span::current().record("stream_id", stream_id);
+
+    let now = Utc::now();
+
+    AddStreamTimelineQuery {
+        stream_id,
+        time: now,
+        title: &event.title,
+        category_id: &event.category_id,
+        category_name: &event.category_name,
+        language: &event.language,
+        content_labels: &event.content_classification_labels,
+    }
+    .execute(pool)
+    .await?;
+
+    if !event.category_id.is_empty() {
+        let category_id = UpsertCategoryQuery {
+            platform: Platform::Twitch,
+            platform_category_id: &event.category_id,
+            name: &event.category_name,
+        }
+        .execute(pool)
+        .await?;
+
+        assign_stream_category(stream_id, category_id, now, pool).await?;
+    }
+
+    update_stream_title(stream_id, event.title, pool).await?;
+
+    Ok(())
+}
+
+async fn handle_stream_offline(
+    platform_channel_id: String,
+    platform_channel_login: String,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    let client = vtstats_utils::reqwest::new()?;
+
+    let channel =
+        get_active_channel_by_platform_id(Platform::Twitch, &platform_channel_id, pool).await?;
+
+    let Some(channel) = channel else {
+        tracing::warn!("Cannot find twitch channel of #{}", platform_channel_login);
+        return Ok(());
+    };
+
+    let thumbnail_url = match get_thumbnail_url(&platform_channel_login, &client).await {
+        Ok(url) => Some(url),
+        Err(err) => {
+            tracing::warn!("Failed to get thumbnail url of #{}", platform_channel_login);
+            tracing::warn!("{err:?}");
+            None
+        }
+    };
+
+    end_twitch_stream(channel.channel_id, thumbnail_url, pool).await?;
+
+    Ok(())
+}
+
+async fn get_thumbnail_url(
+    platform_channel_login: &str,
+    client: &Client,
+) -> anyhow::Result<String> {
+    let res = client
+        .get(format!(
+            "https://static-cdn.jtvnw.net/previews-ttv/live_user_{}-1280x720.jpg",
+            platform_channel_login
+        ))
+        .send()
+        .await?;
+
+    let bytes = res.bytes().await?;
+
+    let now = Utc::now().timestamp();
+
+    upload_file(
+        &format!("twitch-{}-{}.jpg", platform_channel_login, now),
+        bytes,
+        "image/jpeg",
+        client,
+    )
+    .await
+}
@@ -0,0 +1,311 @@
+//! Receives twitch eventsub events over websocket transport, for
+//! deployments which don't have a public callback url for webhook.
+//!
+//! Enabled by setting `TWITCH_EVENTSUB_TRANSPORT` to `websocket`.
+
+use std::{collections::HashSet, time::Duration};
+
+use reqwest::Client;
+use tokio::sync::Notify;
+
+use integration_twitch::{
+    create_channel_update_subscription, create_stream_offline_subscription,
+    create_stream_online_subscription, delete_subscription, get_user_access_token,
+    list_subscriptions, websocket_transport, CreateSubscriptionResponse, EventSubWebSocket,
+    Revocation, Subscription, WebSocketMessage, EVENTSUB_WEBSOCKET_URL,
+};
+use vtstats_database::{
+    channels::{
+        get_active_channel_by_platform_id, list_active_channels_by_platform, Channel, Platform,
+    },
+    PgPool,
+};
+
+mod handlers;
+
+pub use handlers::handle_event;
+
+static RESUBSCRIBE: Notify = Notify::const_new();
+
+pub fn is_enabled() -> bool {
+    matches!(std::env::var("TWITCH_EVENTSUB_TRANSPORT"), Ok(t) if t == "websocket")
+}
+
+/// asks the running session to subscribe channels which became active
+/// and unsubscribe channels which were retired since it was welcomed
+pub fn resubscribe() {
+    RESUBSCRIBE.notify_one();
+}
+
+/// runs forever, reconnects after connection lost
+pub async fn run(pool: PgPool, client: Client) {
+    loop {
+        if let Err(err) = session(&pool, &client).await {
+            tracing::error!(exception.stacktrace = ?err, message= %err);
+        }
+
+        tracing::warn!("Reconnecting to twitch eventsub websocket...");
+        tokio::time::sleep(Duration::from_secs(10)).await;
+    }
+}
+
+async fn session(pool: &PgPool, client: &Client) -> anyhow::Result<()> {
+    let (mut ws, session) = EventSubWebSocket::connect(EVENTSUB_WEBSOCKET_URL).await?;
+
+    let token = get_user_access_token(client).await?.access_token;
+
+    let mut subscribed = HashSet::new();
+    let mut budget = Budget::default();
+
+    // subscriptions must be created within 10 seconds after welcome,
+    // otherwise twitch closes the connection
+    let channels = list_active_channels_by_platform(Platform::Twitch, pool).await?;
+    subscribe_channels(
+        channels,
+        &mut subscribed,
+        &mut budget,
+        &session.id,
+        &token,
+        client,
+    )
+    .await;
+
+    let mut session_id = session.id;
+    // subscriptions carried over by reconnect may still refer to old sessions
+    let mut session_ids = HashSet::from([session_id.clone()]);
+
+    loop {
+        let message = tokio::select! {
+            message = ws.next() => message?,
+            _ = RESUBSCRIBE.notified() => {
+                let token = get_user_access_token(client).await?.access_token;
+
+                let channels = list_active_channels_by_platform(Platform::Twitch, pool).await?;
+                if let Err(err) = unsubscribe_retired(&channels, &session_ids, &mut subscribed, &mut budget, &token, client).await {
+                    tracing::error!(exception.stacktrace = ?err, message= %err);
+                }
+                subscribe_channels(channels, &mut subscribed, &mut budget, &session_id, &token, client).await;
+
+                continue;
+            }
+        };
+
+        let Some(message) = message else {
+            return Ok(());
+        };
+
+        match message {
+            WebSocketMessage::Welcome(_) | WebSocketMessage::Keepalive => {}
+            WebSocketMessage::Notification(event) => {
+                if let Err(err) = handle_event(event, pool).await {
+                    tracing::error!(exception.stacktrace = ?err, message= %err);
+                }
+            }
+            WebSocketMessage::Reconnect(session) => {
+                let Some(url) = session.reconnect_url else {
+                    return Ok(());
+                };
+
+                // subscriptions are carried over, old connection
+                // is dropped after the new one is welcomed
+                let (new_ws, new_session) = EventSubWebSocket::connect(&url).await?;
+                ws = new_ws;
+                session_id = new_session.id;
+                session_ids.insert(session_id.clone());
+            }
+            WebSocketMessage::Revocation(Revocation { subscription }) => {
+                tracing::warn!("twitch revocation: {:?}", subscription);
+
+                let broadcaster_id = &subscription.subscription.condition().broadcaster_user_id;
+
+                // revoked subscription no longer counts toward the limit
+                budget.subscriptions = budget.subscriptions.saturating_sub(1);
+
+                if get_active_channel_by_platform_id(Platform::Twitch, broadcaster_id, pool)
+                    .await?
+                    .is_some()
+                {
+                    let token = get_user_access_token(client).await?.access_token;
+                    if !subscribe(
+                        broadcaster_id,
+                        Some(&subscription.subscription),
+                        &mut budget,
+                        &session_id,
+                        &token,
+                        client,
+                    )
+                    .await
+                    {
+                        tracing::warn!(
+                            "Can't resubscribe {broadcaster_id}, eventsub websocket subscription limit reached"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// websocket transport allows at most 300 enabled subscriptions per session
+const MAX_SUBSCRIPTIONS: usize = 300;
+
+/// subscriptions created in current session, and their total cost
+/// reported by twitch, which is capped by `max_total_cost`
+#[derive(Default)]
+struct Budget {
+    subscriptions: usize,
+    total_cost: i32,
+    max_total_cost: Option<i32>,
+}
+
+impl Budget {
+    /// returns true if `count` more subscriptions can be created, assuming
+    /// each of them costs 1
+    fn allows(&self, count: usize) -> bool {
+        self.subscriptions + count <= MAX_SUBSCRIPTIONS
+            && self
+                .max_total_cost
+                .is_none_or(|max| self.total_cost + count as i32 <= max)
+    }
+
+    fn add(&mut self, res: CreateSubscriptionResponse) {
+        self.subscriptions += 1;
+        self.total_cost = res.total_cost;
+        self.max_total_cost = Some(res.max_total_cost);
+    }
+}
+
+/// subscribes channels which aren't subscribed yet, channels are left
+/// unsubscribed once the limit is reached
+async fn subscribe_channels(
+    channels: Vec<Channel>,
+    subscribed: &mut HashSet<String>,
+    budget: &mut Budget,
+    session_id: &str,
+    token: &str,
+    client: &Client,
+) {
+    let mut skipped = 0;
+
+    for channel in channels {
+        if subscribed.contains(&channel.platform_id) {
+            continue;
+        }
+
+        if subscribe(
+            &channel.platform_id,
+            None,
+            budget,
+            session_id,
+            token,
+            client,
+        )
+        .await
+        {
+            subscribed.insert(channel.platform_id);
+        } else {
+            skipped += 1;
+        }
+    }
+
+    if skipped > 0 {
+        tracing::warn!(
+            "Eventsub websocket subscription limit reached, {skipped} channels left unsubscribed \
+            (subscriptions={} total_cost={} max_total_cost={:?})",
+            budget.subscriptions,
+            budget.total_cost,
+            budget.max_total_cost,
+        );
+    }
+}
+
+/// deletes subscriptions of our sessions whose broadcaster is no longer
+/// active, subscriptions of other sessions are left alone
+async fn unsubscribe_retired(
+    channels: &[Channel],
+    session_ids: &HashSet<String>,
+    subscribed: &mut HashSet<String>,
+    budget: &mut Budget,
+    token: &str,
+    client: &Client,
+) -> anyhow::Result<()> {
+    let mut subscriptions = Vec::new();
+    let mut after = None;
+    loop {
+        let res = list_subscriptions(after, token, client).await?;
+        subscriptions.extend(res.data);
+        match res.pagination.cursor {
+            Some(cursor) => after = Some(cursor),
+            None => break,
+        }
+    }
+
+    for s in subscriptions {
+        let ours =
+            matches!(&s.subscription.transport().session_id, Some(id) if session_ids.contains(id));
+        let broadcaster_id = &s.subscription.condition().broadcaster_user_id;
+
+        if !ours || channels.iter().any(|c| &c.platform_id == broadcaster_id) {
+            continue;
+        }
+
+        if let Err(err) = delete_subscription(&s.id, token, client).await {
+            tracing::error!("Failed to delete twitch subscription {}: {err}", s.id);
+            continue;
+        }
+
+        subscribed.remove(broadcaster_id);
+        budget.subscriptions = budget.subscriptions.saturating_sub(1);
+    }
+
+    Ok(())
+}
+
+/// creates subscriptions of given broadcaster, all three types
+/// are created unless `only` is specified
+///
+/// returns false without subscribing anything if it would exceed the limit
+async fn subscribe(
+    broadcaster_id: &str,
+    only: Option<&Subscription>,
+    budget: &mut Budget,
+    session_id: &str,
+    token: &str,
+    client: &Client,
+) -> bool {
+    if !budget.allows(if only.is_some() { 1 } else { 3 }) {
+        return false;
+    }
+
+    let transport = || websocket_transport(session_id.to_string());
+    let id = || broadcaster_id.to_string();
+
+    if matches!(only, None | Some(Subscription::StreamOnline { .. })) {
+        match create_stream_online_subscription(id(), transport(), token, client).await {
+            Ok(res) => budget.add(res),
+            Err(err) => {
+                tracing::error!("Failed to subscribe stream.online of {broadcaster_id}: {err}")
+            }
+        }
+    }
+
+    if matches!(only, None | Some(Subscription::StreamOffline { .. })) {
+        match create_stream_offline_subscription(id(), transport(), token, client).await {
+            Ok(res) => budget.add(res),
+            Err(err) => {
+                tracing::error!("Failed to subscribe stream.offline of {broadcaster_id}: {err}")
+            }
+        }
+    }
+
+    if matches!(only, None | Some(Subscription::ChannelUpdate { .. })) {
+        match create_channel_update_subscription(id(), transport(), token, client).await {
+            Ok(res) => budget.add(res),
+            Err(err) => {
+                tracing::error!("Failed to subscribe channel.update of {broadcaster_id}: {err}")
+            }
+        }
+    }
+
+    true
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Months, Utc};
+use reqwest::Client;
+use vtstats_database::{exchange_rates::add_exchange_rate_history, PgPool};
+
+use super::{
+    update_exchange_rates::{monthly_rates, start_of_month},
+    JobResult,
+};
+
+pub async fn execute(
+    pool: &PgPool,
+    client: Client,
+    since: DateTime<Utc>,
+) -> anyhow::Result<JobResult> {
+    let current = start_of_month(Utc::now())?;
+
+    let mut month = start_of_month(since)?;
+
+    while month <= current {
+        let rates = monthly_rates(month, &client).await?;
+
+        if rates.is_empty() {
+            tracing::warn!("No exchange rates found for {}", month.format("%Y-%m"));
+        } else {
+            add_exchange_rate_history(pool, month, rates.into_iter()).await?;
+        }
+
+        month = month
+            .checked_add_months(Months::new(1))
+            .ok_or_else(|| anyhow::anyhow!("Invalid month after {month}"))?;
+    }
+
+    Ok(JobResult::Completed)
+}
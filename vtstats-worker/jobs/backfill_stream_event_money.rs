@@ -0,0 +1,181 @@
+use serde_json::{Map, Value};
+use vtstats_database::{
+    stream_events::{list_paid_event_values, update_paid_event_values, Money, StreamEventKind},
+    PgPool,
+};
+use vtstats_utils::currency::{resolve_currency_symbol, CurrencyCode};
+
+use super::JobResult;
+
+const STREAMS_PER_BATCH: i64 = 100;
+
+/// fills `money` of paid events from their raw amounts, events whose
+/// currency or amount can't be recognized are left untouched
+pub async fn execute(pool: &PgPool) -> anyhow::Result<JobResult> {
+    let mut after_stream_id = 0;
+
+    loop {
+        let events = list_paid_event_values(after_stream_id, STREAMS_PER_BATCH, pool).await?;
+
+        let Some(last_stream_id) = events.iter().map(|e| e.stream_id).max() else {
+            break;
+        };
+
+        let updates: Vec<_> = events
+            .into_iter()
+            .filter_map(|event| {
+                let value = with_money(event.kind, &event.value)?;
+                (value != event.value).then_some((event, value))
+            })
+            .collect();
+
+        if !updates.is_empty() {
+            tracing::info!("Updating money of {} events", updates.len());
+            update_paid_event_values(&updates, pool).await?;
+        }
+
+        after_stream_id = last_stream_id;
+    }
+
+    Ok(JobResult::Completed)
+}
+
+/// returns event value with `money` parsed again, `None` if the value
+/// doesn't carry a raw amount or it can't be parsed, so that raw fields
+/// are never dropped without their money
+fn with_money(kind: StreamEventKind, value: &Value) -> Option<Value> {
+    let mut value = value.as_object()?.clone();
+
+    let money = match kind {
+        StreamEventKind::TwitchHyperChat => {
+            let money = hyper_chat_money(&value)?;
+            value.remove("amount");
+            value.remove("currency_code");
+            money
+        }
+        StreamEventKind::YoutubeSuperChat | StreamEventKind::YoutubeSuperSticker => {
+            let amount = value.get("paid_amount")?.as_str()?;
+            let symbol = value.get("paid_currency_symbol")?.as_str()?;
+            let currency = resolve_currency_symbol(symbol)?;
+            let amount = repair_legacy_amount(amount, currency.code.exponent());
+            Money::from_localized(&amount, currency)?
+        }
+        _ => return None,
+    };
+
+    value.insert("money".into(), serde_json::to_value(money).ok()?);
+
+    Some(Value::Object(value))
+}
+
+/// hyper chats used to be recorded with `amount` in minor units and
+/// `currency_code`, returns `None` if it's recorded in current format
+fn hyper_chat_money(value: &Map<String, Value>) -> Option<Money> {
+    let amount = value.get("amount")?.as_str()?.parse().ok()?;
+    let code = CurrencyCode::new(value.get("currency_code")?.as_str()?)?;
+
+    Money::from_minor_units(amount, code.exponent(), code)
+}
+
+/// youtube amounts used to be recorded with commas removed, so `1.000,50`
+/// became `1.00050`, its fraction has more digits than the currency has
+fn repair_legacy_amount(amount: &str, exponent: u32) -> String {
+    match amount.split_once('.') {
+        Some((integer, fraction))
+            if !amount.contains(',')
+                && !fraction.contains('.')
+                && fraction.len() > 3
+                && fraction.len() > exponent as usize =>
+        {
+            format!("{integer}.{},{}", &fraction[..3], &fraction[3..])
+        }
+        _ => amount.to_string(),
+    }
+}
+
+#[test]
+fn test_repair_legacy_amount() {
+    assert_eq!(repair_legacy_amount("1.00050", 2), "1.000,50");
+    assert_eq!(repair_legacy_amount("1.000500", 3), "1.000,500");
+    assert_eq!(repair_legacy_amount("1.000,50", 2), "1.000,50");
+    assert_eq!(repair_legacy_amount("1000.50", 2), "1000.50");
+    assert_eq!(repair_legacy_amount("1.000", 0), "1.000");
+    assert_eq!(repair_legacy_amount("500", 0), "500");
+}
+
+#[test]
+fn test_with_money() {
+    use serde_json::json;
+
+    assert_eq!(
+        with_money(
+            StreamEventKind::YoutubeSuperChat,
+            &json!({ "paid_amount": "1.00050", "paid_currency_symbol": "€" })
+        ),
+        Some(json!({
+            "paid_amount": "1.00050",
+            "paid_currency_symbol": "€",
+            "money": { "amount": "1000.50", "currency": "EUR" }
+        }))
+    );
+    assert_eq!(
+        with_money(
+            StreamEventKind::YoutubeSuperSticker,
+            &json!({ "paid_amount": "500", "paid_currency_symbol": "₩" })
+        ),
+        Some(json!({
+            "paid_amount": "500",
+            "paid_currency_symbol": "₩",
+            "money": { "amount": "500", "currency": "KRW", "ambiguous": true }
+        }))
+    );
+    assert_eq!(
+        with_money(
+            StreamEventKind::YoutubeSuperChat,
+            &json!({ "paid_amount": "1.500", "paid_currency_symbol": "KWD" })
+        ),
+        Some(json!({
+            "paid_amount": "1.500",
+            "paid_currency_symbol": "KWD",
+            "money": { "amount": "1.500", "currency": "KWD" }
+        }))
+    );
+    assert_eq!(
+        with_money(
+            StreamEventKind::YoutubeSuperChat,
+            &json!({ "paid_amount": "5", "paid_currency_symbol": "?" })
+        ),
+        None
+    );
+    assert_eq!(
+        with_money(
+            StreamEventKind::TwitchHyperChat,
+            &json!({ "amount": "1500", "currency_code": "BHD", "level": "ONE" })
+        ),
+        Some(json!({
+            "level": "ONE",
+            "money": { "amount": "1.500", "currency": "BHD" }
+        }))
+    );
+    assert_eq!(
+        with_money(
+            StreamEventKind::TwitchHyperChat,
+            &json!({ "money": { "amount": "2.00", "currency": "USD" } })
+        ),
+        None
+    );
+    assert_eq!(
+        with_money(
+            StreamEventKind::TwitchHyperChat,
+            &json!({ "amount": "1,500", "currency_code": "USD", "level": "ONE" })
+        ),
+        None
+    );
+    assert_eq!(
+        with_money(
+            StreamEventKind::TwitchHyperChat,
+            &json!({ "amount": "1500", "currency_code": "usd", "level": "ONE" })
+        ),
+        None
+    );
+}
@@ -0,0 +1,153 @@
+use reqwest::Client;
+
+use integration_youtube::{
+    data_api::videos::{list_videos, Stream},
+    youtubei::{
+        browse_channel_live, browse_channel_live_with_continuation, replay_live_chat,
+        replay_live_chat_with_continuation,
+    },
+};
+use vtstats_database::{
+    channels::{get_channel_by_id, Channel, Platform},
+    stream_stats::list_chat_keywords,
+    streams::{ListYouTubeStreamsQuery, StreamStatus, UpsertStreamQuery},
+    PgPool,
+};
+
+use super::{
+    collect_stream_stats::{
+        chatters::Chatters, languages::ChatLanguages, terms::ChatTerms,
+        youtube::collect_chat_and_events,
+    },
+    refresh_youtube_rss::upload_thumbnail,
+    JobResult,
+};
+
+// live tab returns 30 videos per page
+const MAX_PAGES: usize = 100;
+
+/// inserts ended streams listed in live tab of given youtube channel,
+/// which is useful when a vtuber was just added
+pub async fn execute(
+    pool: &PgPool,
+    client: Client,
+    channel_id: i32,
+    with_chats: bool,
+) -> anyhow::Result<JobResult> {
+    let Some(channel) = get_channel_by_id(channel_id, pool).await? else {
+        anyhow::bail!("Channel#{channel_id} not found");
+    };
+
+    if channel.platform != Platform::Youtube {
+        anyhow::bail!("Channel#{channel_id} is not a youtube channel");
+    }
+
+    let mut video_ids = Vec::<String>::new();
+
+    let mut res = browse_channel_live(&channel.platform_id, &client).await?;
+
+    for _ in 0..MAX_PAGES {
+        video_ids.extend(res.ended_streams().into_iter().map(String::from));
+
+        let Some(continuation) = res.next_continuation() else {
+            break;
+        };
+
+        res = browse_channel_live_with_continuation(continuation, &client).await?;
+    }
+
+    let existed = ListYouTubeStreamsQuery {
+        platform_ids: &video_ids,
+        limit: None,
+        ..Default::default()
+    }
+    .execute(pool)
+    .await?;
+
+    let missing: Vec<_> = video_ids
+        .into_iter()
+        .filter(|id| existed.iter().all(|stream| &stream.platform_id != id))
+        .collect();
+
+    tracing::info!(
+        "Backfilling {} streams of youtube channel {}",
+        missing.len(),
+        channel.platform_id
+    );
+
+    let mut streams: Vec<Stream> = Vec::with_capacity(missing.len());
+
+    // youtube limits 50 streams per request
+    for chunk in missing.chunks(50) {
+        let videos = list_videos(&chunk.join(","), &client).await?;
+        streams.extend(
+            videos
+                .into_iter()
+                .filter_map(Into::<Option<Stream>>::into)
+                .filter(|stream| {
+                    stream.status == StreamStatus::Ended && stream.channel_id == channel.platform_id
+                }),
+        );
+    }
+
+    for stream in streams {
+        let thumbnail_url = upload_thumbnail(&stream.id, &client).await;
+
+        let stream_id = UpsertStreamQuery {
+            platform: Platform::Youtube,
+            vtuber_id: &channel.vtuber_id,
+            platform_stream_id: &stream.id,
+            channel_id: channel.channel_id,
+            title: &stream.title,
+            status: stream.status,
+            thumbnail_url,
+            schedule_time: stream.schedule_time,
+            start_time: stream.start_time,
+            end_time: stream.end_time,
+            stream_kind: None,
+            access: None,
+        }
+        .execute(pool)
+        .await?;
+
+        if with_chats {
+            if let Err(err) = replay_chats(&channel, &stream.id, stream_id, &client, pool).await {
+                tracing::warn!("Failed to replay chats of {}: {err:?}", stream.id);
+            }
+        }
+    }
+
+    Ok(JobResult::Completed)
+}
+
+async fn replay_chats(
+    channel: &Channel,
+    platform_stream_id: &str,
+    stream_id: i32,
+    client: &Client,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    let (mut messages, mut continuation) =
+        replay_live_chat(&channel.platform_id, platform_stream_id, None, client).await?;
+    let mut chatters = Chatters::default();
+    let mut terms = ChatTerms::new(list_chat_keywords(pool).await?);
+    let mut languages = ChatLanguages::default();
+
+    loop {
+        collect_chat_and_events(
+            stream_id,
+            messages,
+            &mut chatters,
+            &mut terms,
+            &mut languages,
+            pool,
+        )
+        .await?;
+
+        let Some(next) = continuation.and_then(|c| c.get_next_continuation()) else {
+            return Ok(());
+        };
+
+        (messages, continuation) = replay_live_chat_with_continuation(next, client).await?;
+    }
+}
@@ -1,3 +1,4 @@
+mod bilibili_revenue;
 mod bilibili_subscriber;
 mod bilibili_view;
 mod twitch_revenue;
@@ -25,6 +26,9 @@ pub async fn execute(pool: &PgPool, client: &Client) -> anyhow::Result<JobResult
     let twitch_channels = list_active_channels_by_platform(Platform::Twitch, pool).await?;
 
     let _ = join!(
+        bilibili_revenue::run(&bilibili_channels, now, pool).map_err(|err| {
+            tracing::error!("Can't collect revenue for bilibili: {err}");
+        }),
         bilibili_subscriber::run(&bilibili_channels, client, now, pool).map_err(|err| {
             tracing::error!("Can't collect subscriber for bilibili: {err}");
         }),
@@ -0,0 +1,174 @@
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use integration_bilibili::danmaku::{DanmakuClient, LiveMessage};
+use reqwest::Client;
+use vtstats_database::{
+    stream_events::{
+        add_stream_events, BilibiliGift, BilibiliGuard, BilibiliSuperChat, StreamEventValue,
+    },
+    stream_stats::{AddStreamChatStatsQuery, AddStreamChatStatsRow, AddStreamChattersQuery},
+    PgPool,
+};
+
+use crate::jobs::collect_stream_stats::chatters::Chatters;
+
+const MIN_BACKOFF: std::time::Duration = std::time::Duration::from_secs(1);
+const MAX_BACKOFF: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// collects danmaku count every 15 seconds, and paid events,
+/// messages from guards (captains and above) are counted as from members
+pub async fn collect_chats(
+    stream_id: i32,
+    room_id: &str,
+    client: &Client,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    let mut backoff = MIN_BACKOFF;
+    let mut danmaku = match DanmakuClient::connect(room_id, client).await {
+        Ok(danmaku) => danmaku,
+        Err(err) => {
+            tracing::warn!("Failed to connect to bilibili live room {room_id}: {err:?}");
+            reconnect(room_id, client, &mut backoff).await
+        }
+    };
+
+    let mut time: Option<DateTime<Utc>> = None;
+    let mut count = 0;
+    let mut from_member_count = 0;
+    let mut chatters = Chatters::default();
+
+    loop {
+        let messages = match danmaku.next().await {
+            Ok(Some(messages)) => {
+                backoff = MIN_BACKOFF;
+                messages
+            }
+            res => {
+                if let Err(err) = res {
+                    tracing::warn!("Bilibili live room {room_id} errored: {err:?}");
+                }
+                danmaku = reconnect(room_id, client, &mut backoff).await;
+                continue;
+            }
+        };
+
+        let mut events = Vec::new();
+
+        for message in messages {
+            match message {
+                LiveMessage::Danmaku {
+                    timestamp,
+                    uid,
+                    uname,
+                    guard_level,
+                    ..
+                } => {
+                    let bucket = timestamp.duration_trunc(Duration::seconds(15))?;
+
+                    if let Some(time) = time.filter(|time| *time != bucket) {
+                        AddStreamChatStatsQuery {
+                            stream_id,
+                            rows: vec![AddStreamChatStatsRow {
+                                time,
+                                count,
+                                from_member_count,
+                                chatter_count: chatters.count(time),
+                            }],
+                        }
+                        .execute(pool)
+                        .await?;
+                        AddStreamChattersQuery {
+                            stream_id,
+                            rows: chatters.take_authors(),
+                        }
+                        .execute(pool)
+                        .await?;
+                        count = 0;
+                        from_member_count = 0;
+                    }
+
+                    time = Some(bucket);
+                    count += 1;
+                    if guard_level > 0 {
+                        from_member_count += 1;
+                    }
+                    chatters.add(bucket, timestamp, &uid.to_string(), &uname);
+                }
+                LiveMessage::SuperChat {
+                    timestamp,
+                    uid,
+                    uname,
+                    message,
+                    price,
+                } => events.push((
+                    timestamp,
+                    StreamEventValue::BilibiliSuperChat(BilibiliSuperChat {
+                        author_uid: uid,
+                        author_name: uname,
+                        message,
+                        price,
+                    }),
+                )),
+                LiveMessage::Gift {
+                    timestamp,
+                    uid,
+                    uname,
+                    gift_name,
+                    num,
+                    coin_type,
+                    total_coin,
+                } if coin_type == "gold" => events.push((
+                    timestamp,
+                    StreamEventValue::BilibiliGift(BilibiliGift {
+                        author_uid: uid,
+                        author_name: uname,
+                        gift_name,
+                        num,
+                        total_coin,
+                    }),
+                )),
+                LiveMessage::Gift { .. } => {}
+                LiveMessage::Guard {
+                    timestamp,
+                    uid,
+                    uname,
+                    guard_level,
+                    num,
+                    price,
+                } => events.push((
+                    timestamp,
+                    StreamEventValue::BilibiliGuard(BilibiliGuard {
+                        author_uid: uid,
+                        author_name: uname,
+                        guard_level,
+                        num,
+                        price,
+                    }),
+                )),
+            }
+        }
+
+        if !events.is_empty() {
+            add_stream_events(stream_id, events, pool).await?;
+        }
+    }
+}
+
+/// reconnects until succeeded, waiting longer after each failure
+async fn reconnect(
+    room_id: &str,
+    client: &Client,
+    backoff: &mut std::time::Duration,
+) -> DanmakuClient {
+    loop {
+        tracing::warn!("Reconnecting to bilibili live room {room_id} in {backoff:?}");
+        tokio::time::sleep(*backoff).await;
+        *backoff = (*backoff * 2).min(MAX_BACKOFF);
+
+        match DanmakuClient::connect(room_id, client).await {
+            Ok(danmaku) => return danmaku,
+            Err(err) => {
+                tracing::warn!("Failed to connect to bilibili live room {room_id}: {err:?}")
+            }
+        }
+    }
+}
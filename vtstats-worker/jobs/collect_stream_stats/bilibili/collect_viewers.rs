@@ -0,0 +1,40 @@
+use chrono::{Duration, DurationRound, Utc};
+use integration_bilibili::live::{status_info_by_uids, LiveStatus};
+use reqwest::Client;
+use vtstats_database::{stream_stats::AddStreamViewerStatsQuery, streams::end_stream, PgPool};
+
+use crate::jobs::refresh_bilibili_live::stream_platform_id;
+
+/// polls room status every 15 seconds, and records the online (popularity) count,
+/// returns after the room goes offline or starts another broadcast
+pub async fn collect_viewers(
+    stream_id: i32,
+    platform_id: &str,
+    mid: &str,
+    client: &Client,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    loop {
+        let rooms = status_info_by_uids(&[mid], client).await?;
+
+        let room = rooms
+            .get(mid)
+            .filter(|room| room.live_status == LiveStatus::Live)
+            .filter(|room| stream_platform_id(room) == platform_id);
+
+        let Some(room) = room else {
+            end_stream(stream_id, pool).await?;
+            return Ok(());
+        };
+
+        AddStreamViewerStatsQuery {
+            stream_id,
+            time: Utc::now().duration_trunc(Duration::seconds(15))?,
+            count: room.online,
+        }
+        .execute(pool)
+        .await?;
+
+        tokio::time::sleep(std::time::Duration::from_secs(15)).await;
+    }
+}
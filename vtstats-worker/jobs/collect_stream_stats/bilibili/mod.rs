@@ -0,0 +1,5 @@
+mod collect_chats;
+mod collect_viewers;
+
+pub use collect_chats::*;
+pub use collect_viewers::*;
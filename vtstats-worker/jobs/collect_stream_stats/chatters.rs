@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use vtstats_database::stream_stats::AddStreamChatterRow;
+
+// buckets older than this are not expected to receive more messages
+const RECENT_BUCKETS: usize = 8;
+
+/// chatters seen by a chat collector, authors of recent buckets are kept,
+/// so a bucket inserted more than once still counts each chatter once
+#[derive(Default)]
+pub struct Chatters {
+    buckets: BTreeMap<DateTime<Utc>, HashSet<String>>,
+    authors: HashMap<String, AddStreamChatterRow>,
+}
+
+impl Chatters {
+    pub fn add(&mut self, bucket: DateTime<Utc>, time: DateTime<Utc>, id: &str, name: &str) {
+        self.buckets
+            .entry(bucket)
+            .or_default()
+            .insert(id.to_string());
+
+        while self.buckets.len() > RECENT_BUCKETS {
+            self.buckets.pop_first();
+        }
+
+        self.authors
+            .entry(id.to_string())
+            .and_modify(|row| {
+                row.author_name = name.to_string();
+                row.message_count += 1;
+                row.first_message_at = row.first_message_at.min(time);
+                row.last_message_at = row.last_message_at.max(time);
+            })
+            .or_insert_with(|| AddStreamChatterRow {
+                author_id: id.to_string(),
+                author_name: name.to_string(),
+                message_count: 1,
+                first_message_at: time,
+                last_message_at: time,
+            });
+    }
+
+    /// unique chatters of given bucket so far
+    pub fn count(&self, bucket: DateTime<Utc>) -> i32 {
+        self.buckets
+            .get(&bucket)
+            .map(|s| s.len() as i32)
+            .unwrap_or(0)
+    }
+
+    /// returns authors seen since last call, message counts are reset
+    pub fn take_authors(&mut self) -> Vec<AddStreamChatterRow> {
+        self.authors.drain().map(|(_, row)| row).collect()
+    }
+}
+
+#[test]
+fn test_chatters() {
+    use chrono::{Duration, TimeZone};
+
+    let time = Utc.timestamp_opt(9000, 0).single().unwrap();
+    let mut chatters = Chatters::default();
+
+    chatters.add(time, time, "a", "a");
+    chatters.add(time, time + Duration::seconds(1), "a", "A");
+    chatters.add(time, time + Duration::seconds(2), "b", "b");
+    assert_eq!(chatters.count(time), 2);
+
+    let mut authors = chatters.take_authors();
+    authors.sort_by(|a, b| a.author_id.cmp(&b.author_id));
+    assert_eq!(authors.len(), 2);
+    assert_eq!(authors[0].author_name, "A");
+    assert_eq!(authors[0].message_count, 2);
+    assert_eq!(authors[0].last_message_at, time + Duration::seconds(1));
+
+    // bucket keeps its chatters after authors are taken
+    chatters.add(time, time + Duration::seconds(3), "a", "A");
+    assert_eq!(chatters.count(time), 2);
+    assert_eq!(chatters.take_authors().len(), 1);
+
+    for i in 1..=RECENT_BUCKETS as i64 {
+        chatters.add(time + Duration::seconds(15 * i), time, "c", "c");
+    }
+    assert_eq!(chatters.count(time), 0);
+}
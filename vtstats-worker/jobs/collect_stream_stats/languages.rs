@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use vtstats_database::stream_stats::AddStreamChatLanguageRow;
+
+/// language of unreliable detections, e.g. short messages
+const UNDETERMINED: &str = "und";
+
+/// languages of chat messages seen by a chat collector
+#[derive(Default)]
+pub struct ChatLanguages {
+    counts: HashMap<&'static str, i32>,
+}
+
+impl ChatLanguages {
+    pub fn add<'a>(&mut self, text: &str, emotes: impl IntoIterator<Item = &'a str>) {
+        if let Some(language) = detect_language(text, emotes) {
+            *self.counts.entry(language).or_default() += 1;
+        }
+    }
+
+    /// returns counts since last call
+    pub fn take_rows(&mut self) -> Vec<AddStreamChatLanguageRow> {
+        self.counts
+            .drain()
+            .map(|(language, count)| AddStreamChatLanguageRow {
+                language: language.to_string(),
+                count,
+            })
+            .collect()
+    }
+}
+
+/// returns ISO 639-3 code of the message, or `None` if it has no words
+/// after removing emotes, links and mentions
+fn detect_language<'a>(
+    text: &str,
+    emotes: impl IntoIterator<Item = &'a str>,
+) -> Option<&'static str> {
+    let mut text = text.to_string();
+
+    for emote in emotes {
+        text = text.replace(emote, " ");
+    }
+
+    let text = text
+        .split_whitespace()
+        .filter(|word| !word.starts_with("http") && !word.starts_with('@'))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if !text.chars().any(char::is_alphabetic) {
+        return None;
+    }
+
+    match whatlang::detect(&text) {
+        Some(info) if info.is_reliable() => Some(info.lang().code()),
+        _ => Some(UNDETERMINED),
+    }
+}
+
+#[test]
+fn test_detect_language() {
+    assert_eq!(
+        detect_language(
+            "Thank you so much for the stream today, it was really fun to watch",
+            []
+        ),
+        Some("eng")
+    );
+    assert_eq!(
+        detect_language(":_kusa: 今日も配信ありがとうございました", [":_kusa:"]),
+        Some("jpn")
+    );
+    assert_eq!(
+        detect_language("오늘 방송 정말 재미있었어요 감사합니다", []),
+        Some("kor")
+    );
+    assert_eq!(
+        detect_language("Kappa :_kusa: 8888", ["Kappa", ":_kusa:"]),
+        None
+    );
+    assert_eq!(detect_language("@someone https://example.com", []), None);
+}
+
+#[test]
+fn test_chat_languages() {
+    let mut languages = ChatLanguages::default();
+
+    languages.add("今日も配信ありがとうございました", []);
+    languages.add("お疲れ様でした、また明日も楽しみにしています", []);
+    languages.add("Kappa", ["Kappa"]);
+
+    assert_eq!(
+        languages.take_rows(),
+        vec![AddStreamChatLanguageRow {
+            language: "jpn".into(),
+            count: 2,
+        }]
+    );
+    assert!(languages.take_rows().is_empty());
+}
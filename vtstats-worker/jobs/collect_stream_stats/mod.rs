@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use std::future::Future;
 
 use integration_twitch::gql::channel_panels;
 use reqwest::Client;
@@ -11,6 +12,10 @@ use vtstats_database::{
 
 use super::JobResult;
 
+pub mod bilibili;
+pub mod chatters;
+pub mod languages;
+pub mod terms;
 pub mod twitch;
 pub mod youtube;
 
@@ -42,7 +47,27 @@ pub async fn execute(
 
     match stream.platform {
         Platform::Bilibili => {
-            anyhow::bail!("We don't support bilibili stream")
+            // platform id of bilibili stream is `{room_id}-{live_time}`
+            let Some((room_id, _)) = stream.platform_id.split_once('-') else {
+                anyhow::bail!("Invalid bilibili stream id {}", stream.platform_id)
+            };
+
+            tokio::select! {
+                res = bilibili::collect_viewers(stream_id, &stream.platform_id, &channel.platform_id, &client, pool) => {
+                    res.map(|_| JobResult::Completed)
+                },
+                res = keep_collecting(stream_id, bilibili::collect_chats(stream_id, room_id, &client, pool)) => {
+                    res.map(|_| JobResult::Completed)
+                },
+                _ = sigint.recv() => {
+                    let run = next_run.unwrap_or_else(Utc::now);
+                    Ok(JobResult::Next { run })
+                },
+                _ = sigterm.recv() => {
+                    let run = next_run.unwrap_or_else(Utc::now);
+                    Ok(JobResult::Next { run })
+                },
+            }
         }
         Platform::Youtube => {
             tokio::select! {
@@ -88,3 +113,17 @@ pub async fn execute(
         }
     }
 }
+
+/// danmaku are collected on a best-effort basis, so a failed danmaku
+/// collector is logged and never completes, and viewers are still
+/// collected until the stream ends
+async fn keep_collecting(
+    stream_id: i32,
+    collect_chats: impl Future<Output = anyhow::Result<()>>,
+) -> anyhow::Result<()> {
+    if let Err(err) = collect_chats.await {
+        tracing::error!("Failed to collect danmaku of stream {stream_id}: {err:?}");
+    }
+
+    std::future::pending().await
+}
@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use vtstats_database::stream_stats::{AddStreamChatTermRow, ChatKeyword, ChatTermKind};
+
+/// emote and keyword counts of each bucket seen by a chat collector
+#[derive(Default)]
+pub struct ChatTerms {
+    keywords: Vec<ChatKeyword>,
+    counts: HashMap<(DateTime<Utc>, ChatTermKind, String), i32>,
+}
+
+impl ChatTerms {
+    pub fn new(mut keywords: Vec<ChatKeyword>) -> Self {
+        for keyword in &mut keywords {
+            for pattern in &mut keyword.patterns {
+                *pattern = pattern.to_lowercase();
+            }
+            keyword.patterns.retain(|p| !p.is_empty());
+        }
+
+        ChatTerms {
+            keywords,
+            counts: HashMap::new(),
+        }
+    }
+
+    /// emotes are counted once per occurrence, keywords once per message,
+    /// keywords are matched after removing emotes, so that e.g. `:_kusa:`
+    /// doesn't count as keyword `kusa`
+    pub fn add<'a>(
+        &mut self,
+        bucket: DateTime<Utc>,
+        emotes: impl IntoIterator<Item = &'a str>,
+        text: &str,
+    ) {
+        let mut text = text.to_string();
+
+        for emote in emotes {
+            *self
+                .counts
+                .entry((bucket, ChatTermKind::Emote, emote.to_string()))
+                .or_default() += 1;
+
+            text = text.replace(emote, " ");
+        }
+
+        let text = text.to_lowercase();
+
+        for keyword in &self.keywords {
+            if keyword.patterns.iter().any(|p| text.contains(p.as_str())) {
+                *self
+                    .counts
+                    .entry((bucket, ChatTermKind::Keyword, keyword.keyword.clone()))
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    /// returns counts since last call
+    pub fn take_rows(&mut self) -> Vec<AddStreamChatTermRow> {
+        self.counts
+            .drain()
+            .map(|((time, kind, term), count)| AddStreamChatTermRow {
+                time,
+                kind,
+                term,
+                count,
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_chat_terms() {
+    use chrono::{Duration, TimeZone};
+
+    let time = Utc.timestamp_opt(9000, 0).single().unwrap();
+    let mut terms = ChatTerms::new(vec![ChatKeyword {
+        keyword: "草".into(),
+        patterns: vec!["草".into(), "KUSA".into(), "".into()],
+    }]);
+
+    terms.add(time, [":_kusa:", ":_kusa:"], ":_kusa::_kusa:");
+    terms.add(time, [], "草草草");
+    terms.add(time + Duration::seconds(15), [], "hello");
+
+    let mut rows = terms.take_rows();
+    rows.sort_by(|a, b| a.term.cmp(&b.term));
+
+    assert_eq!(
+        rows,
+        vec![
+            AddStreamChatTermRow {
+                time,
+                kind: ChatTermKind::Emote,
+                term: ":_kusa:".into(),
+                count: 2,
+            },
+            AddStreamChatTermRow {
+                time,
+                kind: ChatTermKind::Keyword,
+                term: "草".into(),
+                count: 1,
+            },
+        ]
+    );
+    assert!(terms.take_rows().is_empty());
+}
@@ -1,37 +1,57 @@
+use std::sync::{Mutex, PoisonError};
+
 use chrono::{DateTime, Duration, DurationRound, Utc};
-use integration_twitch::{connect_chat_room, read_live_chat_message, LiveChatMessage};
+use integration_twitch::{ChatClient, LiveChatMessage};
 use vtstats_database::{
     stream_events::{
         add_stream_events, StreamEvent, StreamEventKind, StreamEventValue, TwitchCheering,
-        TwitchHyperChat,
+        TwitchGiftSubscription, TwitchHyperChat, TwitchRaid, TwitchSubscription,
+    },
+    stream_stats::{
+        list_chat_keywords, AddStreamChatLanguagesQuery, AddStreamChatStatsQuery,
+        AddStreamChatStatsRow, AddStreamChatTermsQuery, AddStreamChattersQuery,
     },
-    stream_stats::{AddStreamChatStatsQuery, AddStreamChatStatsRow},
     PgPool,
 };
 
+use crate::jobs::collect_stream_stats::{
+    chatters::Chatters, languages::ChatLanguages, terms::ChatTerms,
+};
+
+// all chat rooms are joined over one shared connection
+static CHAT_CLIENT: Mutex<Option<ChatClient>> = Mutex::new(None);
+
+/// returns the shared client, a new one is created if its task has stopped
+fn chat_client() -> ChatClient {
+    let mut client = CHAT_CLIENT.lock().unwrap_or_else(PoisonError::into_inner);
+
+    match &*client {
+        Some(client) if !client.is_closed() => client.clone(),
+        _ => client.insert(ChatClient::new()).clone(),
+    }
+}
+
 pub async fn collect_chats(stream_id: i32, login: &str, pool: &PgPool) -> anyhow::Result<()> {
-    let mut tcp = connect_chat_room(login).await?;
+    let mut room = chat_client().join(login);
 
     let mut time: Option<DateTime<Utc>> = None;
     let mut count = 0;
     let mut from_member_count = 0;
     let mut events: Option<StreamEvent> = None;
+    let mut chatters = Chatters::default();
+    let mut terms = ChatTerms::new(list_chat_keywords(pool).await?);
+    let mut languages = ChatLanguages::default();
 
     loop {
-        let msg = read_live_chat_message(&mut tcp).await?;
-
-        let Some(msg) = msg else {
-            tracing::warn!("Reconnecting to twitch chatroom ##{login}");
-            tcp = connect_chat_room(login).await?;
-            continue;
+        let Some(msg) = room.recv().await else {
+            anyhow::bail!("Twitch chat client stopped");
         };
 
-        let (timestamp, from_subscriber) = match msg {
+        let (timestamp, from_subscriber, author_username, text, emotes) = match msg {
             LiveChatMessage::HyperChat {
                 timestamp,
-                amount,
+                money,
                 level,
-                currency_code,
                 author_username,
                 badges,
                 text,
@@ -40,15 +60,14 @@ pub async fn collect_chats(stream_id: i32, login: &str, pool: &PgPool) -> anyhow
                     kind: StreamEventKind::TwitchHyperChat,
                     time: timestamp,
                     value: StreamEventValue::TwitchHyperChat(TwitchHyperChat {
-                        amount,
-                        author_username,
+                        author_username: author_username.clone(),
                         badges,
-                        currency_code,
                         level,
-                        message: text,
+                        money: Some(money),
+                        message: text.clone(),
                     }),
                 });
-                (timestamp, false)
+                (timestamp, false, author_username, text, Vec::new())
             }
             LiveChatMessage::Cheering {
                 timestamp,
@@ -63,42 +82,121 @@ pub async fn collect_chats(stream_id: i32, login: &str, pool: &PgPool) -> anyhow
                     value: StreamEventValue::TwitchCheering(TwitchCheering {
                         badges,
                         bits,
-                        message: text,
-                        author_username,
+                        message: text.clone(),
+                        author_username: author_username.clone(),
                     }),
                 });
-                (timestamp, false)
+                (timestamp, false, author_username, text, Vec::new())
+            }
+            LiveChatMessage::Text {
+                timestamp,
+                author_username,
+                text,
+                emotes,
+                ..
+            } => (timestamp, false, author_username, text, emotes),
+            LiveChatMessage::Subscriber {
+                timestamp,
+                author_username,
+                text,
+                emotes,
+                ..
+            } => (timestamp, true, author_username, text, emotes),
+            // notices aren't chat messages, so they're not counted in chat stats
+            LiveChatMessage::Subscription {
+                timestamp,
+                author_username,
+                badges,
+                text,
+                plan,
+                cumulative_months,
+            } => {
+                let value = StreamEventValue::TwitchSubscription(TwitchSubscription {
+                    author_username,
+                    badges,
+                    message: text,
+                    plan,
+                    cumulative_months,
+                });
+                add_stream_events(stream_id, vec![(timestamp, value)], pool).await?;
+                continue;
+            }
+            LiveChatMessage::GiftSubscription {
+                timestamp,
+                author_username,
+                badges,
+                plan,
+                count,
+                recipient_username,
+            } => {
+                let value = StreamEventValue::TwitchGiftSubscription(TwitchGiftSubscription {
+                    author_username,
+                    badges,
+                    plan,
+                    count,
+                    recipient_username,
+                });
+                add_stream_events(stream_id, vec![(timestamp, value)], pool).await?;
+                continue;
+            }
+            LiveChatMessage::Raid {
+                timestamp,
+                from_username,
+                viewer_count,
+            } => {
+                let value = StreamEventValue::TwitchRaid(TwitchRaid {
+                    from_username,
+                    viewer_count,
+                });
+                add_stream_events(stream_id, vec![(timestamp, value)], pool).await?;
+                continue;
             }
-            LiveChatMessage::Text { timestamp, .. } => (timestamp, false),
-            LiveChatMessage::Subscriber { timestamp, .. } => (timestamp, true),
         };
 
-        let timestamp = timestamp.duration_trunc(Duration::seconds(15))?;
+        let bucket = timestamp.duration_trunc(Duration::seconds(15))?;
 
-        match time {
-            Some(time) if time != timestamp => {
-                AddStreamChatStatsQuery {
-                    stream_id,
-                    rows: vec![AddStreamChatStatsRow {
-                        time,
-                        count,
-                        from_member_count,
-                    }],
-                }
-                .execute(pool)
-                .await?;
-                count = 0;
-                from_member_count = 0;
+        if let Some(time) = time.filter(|time| *time != bucket) {
+            AddStreamChatStatsQuery {
+                stream_id,
+                rows: vec![AddStreamChatStatsRow {
+                    time,
+                    count,
+                    from_member_count,
+                    chatter_count: chatters.count(time),
+                }],
+            }
+            .execute(pool)
+            .await?;
+            AddStreamChattersQuery {
+                stream_id,
+                rows: chatters.take_authors(),
             }
-            _ => {
-                count += 1;
-                if from_subscriber {
-                    from_member_count += 1;
-                }
+            .execute(pool)
+            .await?;
+            let rows = terms.take_rows();
+            if !rows.is_empty() {
+                AddStreamChatTermsQuery { stream_id, rows }
+                    .execute(pool)
+                    .await?;
             }
+            let rows = languages.take_rows();
+            if !rows.is_empty() {
+                AddStreamChatLanguagesQuery { stream_id, rows }
+                    .execute(pool)
+                    .await?;
+            }
+            count = 0;
+            from_member_count = 0;
         }
 
-        time = Some(timestamp);
+        time = Some(bucket);
+        count += 1;
+        if from_subscriber {
+            from_member_count += 1;
+        }
+        chatters.add(bucket, timestamp, &author_username, &author_username);
+        terms.add(bucket, emotes.iter().map(String::as_str), &text);
+        languages.add(&text, emotes.iter().map(String::as_str));
 
         if let Some(event) = events.take() {
             add_stream_events(stream_id, vec![(event.time, event.value)], pool).await?;
@@ -8,13 +8,21 @@ use std::time::Duration;
 use vtstats_database::{
     channels::Channel,
     stream_events::{
-        add_stream_events, StreamEventValue, YoutubeMemberMilestone, YoutubeNewMember,
-        YoutubeSuperChat, YoutubeSuperSticker,
+        add_stream_events, Money, StreamEventValue, YoutubeMemberMilestone, YoutubeMembershipGift,
+        YoutubeMembershipGiftRedemption, YoutubeNewMember, YoutubeSuperChat, YoutubeSuperSticker,
+    },
+    stream_stats::{
+        list_chat_keywords, AddStreamChatLanguagesQuery, AddStreamChatStatsQuery,
+        AddStreamChatStatsRow, AddStreamChatTermsQuery, AddStreamChattersQuery,
     },
-    stream_stats::{AddStreamChatStatsQuery, AddStreamChatStatsRow},
     streams::Stream,
     PgPool,
 };
+use vtstats_utils::currency::{record_unresolved_symbol, resolve_currency_symbol};
+
+use crate::jobs::collect_stream_stats::{
+    chatters::Chatters, languages::ChatLanguages, terms::ChatTerms,
+};
 
 pub async fn collect_chats(
     channel: &Channel,
@@ -24,6 +32,9 @@ pub async fn collect_chats(
 ) -> anyhow::Result<()> {
     let mut continuation: Option<String> = None;
     let mut timeout = Duration::from_secs(15);
+    let mut chatters = Chatters::default();
+    let mut terms = ChatTerms::new(list_chat_keywords(pool).await?);
+    let mut languages = ChatLanguages::default();
 
     loop {
         let messages;
@@ -47,7 +58,15 @@ pub async fn collect_chats(
             }
         }
 
-        let _ = collect_chat_and_events(stream.stream_id, messages, pool).await;
+        let _ = collect_chat_and_events(
+            stream.stream_id,
+            messages,
+            &mut chatters,
+            &mut terms,
+            &mut languages,
+            pool,
+        )
+        .await;
 
         tokio::time::sleep(timeout).await;
     }
@@ -56,6 +75,9 @@ pub async fn collect_chats(
 pub async fn collect_chat_and_events(
     stream_id: i32,
     messages: Vec<LiveChatMessage>,
+    chatters: &mut Chatters,
+    terms: &mut ChatTerms,
+    languages: &mut ChatLanguages,
     pool: &PgPool,
 ) -> anyhow::Result<()> {
     if messages.is_empty() {
@@ -69,14 +91,23 @@ pub async fn collect_chat_and_events(
     for message in messages {
         match message {
             LiveChatMessage::Text {
-                timestamp, badges, ..
+                timestamp,
+                badges,
+                author_name,
+                author_channel_id,
+                text,
+                emotes,
             } => {
-                let Some(time) = parse_timestamp(&timestamp) else {
+                let Some(timestamp) = parse_timestamp(&timestamp) else {
                     tracing::warn!("Failed to parse timestamp: {timestamp:?}.");
                     continue;
                 };
 
-                let time = time.duration_trunc(chrono::Duration::seconds(15))?;
+                let time = timestamp.duration_trunc(chrono::Duration::seconds(15))?;
+
+                chatters.add(time, timestamp, &author_channel_id, &author_name);
+                terms.add(time, emotes.iter().map(String::as_str), &text);
+                languages.add(&text, emotes.iter().map(String::as_str));
 
                 let from_member = badges
                     .iter()
@@ -90,6 +121,7 @@ pub async fn collect_chat_and_events(
                         time,
                         count: 1,
                         from_member_count: if from_member { 1 } else { 0 },
+                        chatter_count: 0,
                     })
                 }
             }
@@ -150,14 +182,20 @@ pub async fn collect_chat_and_events(
                     continue;
                 };
 
+                let money = parse_money(paid_symbol, paid_amount);
+                if money.is_none() {
+                    tracing::warn!("Failed to parse money: {amount:?}.");
+                }
+
                 let badges = badges.join(",");
 
                 let value = match ty {
                     PaidMessageType::SuperChat => {
                         StreamEventValue::YoutubeSuperChat(YoutubeSuperChat {
-                            paid_amount,
+                            paid_amount: paid_amount.into(),
                             paid_currency_symbol: paid_symbol.into(),
                             paid_color: color,
+                            money,
                             message: (!text.is_empty()).then_some(text),
                             author_name,
                             author_badges: (!badges.is_empty()).then_some(badges),
@@ -166,9 +204,10 @@ pub async fn collect_chat_and_events(
                     }
                     PaidMessageType::SuperSticker => {
                         StreamEventValue::YoutubeSuperSticker(YoutubeSuperSticker {
-                            paid_amount,
+                            paid_amount: paid_amount.into(),
                             paid_currency_symbol: paid_symbol.into(),
                             paid_color: color,
+                            money,
                             message: (!text.is_empty()).then_some(text),
                             author_name,
                             author_badges: (!badges.is_empty()).then_some(badges),
@@ -179,16 +218,96 @@ pub async fn collect_chat_and_events(
 
                 stream_event_rows.push((time, value))
             }
+
+            LiveChatMessage::GiftPurchase {
+                author_name,
+                author_channel_id,
+                timestamp,
+                badges,
+                count,
+            } => {
+                let Some(time) = parse_timestamp(&timestamp) else {
+                    tracing::warn!("Failed to parse timestamp: {timestamp:?}.");
+                    continue;
+                };
+
+                let badges = badges.join(",");
+
+                let value = StreamEventValue::YoutubeMembershipGift(YoutubeMembershipGift {
+                    author_name,
+                    author_badges: (!badges.is_empty()).then_some(badges),
+                    author_channel_id,
+                    count,
+                });
+
+                stream_event_rows.push((time, value))
+            }
+
+            LiveChatMessage::GiftRedemption {
+                author_name,
+                author_channel_id,
+                timestamp,
+                badges,
+                text,
+            } => {
+                let Some(time) = parse_timestamp(&timestamp) else {
+                    tracing::warn!("Failed to parse timestamp: {timestamp:?}.");
+                    continue;
+                };
+
+                let badges = badges.join(",");
+
+                let value = StreamEventValue::YoutubeMembershipGiftRedemption(
+                    YoutubeMembershipGiftRedemption {
+                        message: text,
+                        author_name,
+                        author_badges: (!badges.is_empty()).then_some(badges),
+                        author_channel_id,
+                    },
+                );
+
+                stream_event_rows.push((time, value))
+            }
+
+            LiveChatMessage::Poll { .. }
+            | LiveChatMessage::Banner { .. }
+            | LiveChatMessage::Deleted { .. }
+            | LiveChatMessage::AuthorDeleted { .. } => {}
         }
     }
 
     if !chat_stats_rows.is_empty() {
+        for row in &mut chat_stats_rows {
+            row.chatter_count = chatters.count(row.time);
+        }
+
         AddStreamChatStatsQuery {
             stream_id,
             rows: chat_stats_rows,
         }
         .execute(pool)
         .await?;
+
+        AddStreamChattersQuery {
+            stream_id,
+            rows: chatters.take_authors(),
+        }
+        .execute(pool)
+        .await?;
+
+        let rows = terms.take_rows();
+        if !rows.is_empty() {
+            AddStreamChatTermsQuery { stream_id, rows }
+                .execute(pool)
+                .await?;
+        }
+
+        let rows = languages.take_rows();
+        if !rows.is_empty() {
+            AddStreamChatLanguagesQuery { stream_id, rows }
+                .execute(pool)
+                .await?;
+        }
     }
 
     if !stream_event_rows.is_empty() {
@@ -203,7 +322,8 @@ pub fn parse_timestamp(string: &str) -> Option<DateTime<Utc>> {
         .single()
 }
 
-fn parse_amount(amount: &str) -> Option<(&str, String)> {
+/// splits currency symbol and locale-formatted value, e.g. `$1,000.00`
+fn parse_amount(amount: &str) -> Option<(&str, &str)> {
     let i = amount.find(|ch: char| ch.is_ascii_digit())?;
 
     let (mut symbol, mut value) = amount.split_at(i);
@@ -215,11 +335,24 @@ fn parse_amount(amount: &str) -> Option<(&str, String)> {
         return None;
     }
 
-    let value = value.replace(',', "");
+    Some((symbol, value))
+}
 
-    let _: f32 = value.parse().ok()?;
+fn parse_money(symbol: &str, value: &str) -> Option<Money> {
+    let Some(currency) = resolve_currency_symbol(symbol) else {
+        record_unresolved_symbol(symbol);
+        tracing::warn!("Unresolved currency symbol {symbol:?}.");
+        return None;
+    };
 
-    Some((symbol, value))
+    if currency.ambiguous {
+        tracing::debug!(
+            "Ambiguous currency symbol {symbol:?}, assuming {}.",
+            currency.code
+        );
+    }
+
+    Money::from_localized(value, currency)
 }
 
 #[test]
@@ -252,13 +385,22 @@ fn test_parse_amount() {
     assert_eq!(parse_amount("¥¥",), None);
     assert_eq!(parse_amount("JPY",), None);
     assert_eq!(parse_amount("10.00",), None);
-    assert_eq!(parse_amount("1.0.00",), None);
-    assert_eq!(parse_amount("$10.0.00",), None);
-    assert_eq!(parse_amount("$1,000.00",), Some(("$", "1000.00".into())));
-    assert_eq!(parse_amount("JPY 99.99",), Some(("JPY", "99.99".into())));
-    assert_eq!(parse_amount("¥99.9999",), Some(("¥", "99.9999".into())));
-    assert_eq!(
-        parse_amount("USD 99.9901",),
-        Some(("USD", "99.9901".into()))
-    );
+    assert_eq!(parse_amount("$1,000.00",), Some(("$", "1,000.00")));
+    assert_eq!(parse_amount("JPY 99.99",), Some(("JPY", "99.99")));
+    assert_eq!(parse_amount("¥99.9999",), Some(("¥", "99.9999")));
+    assert_eq!(parse_amount("USD 99.9901",), Some(("USD", "99.9901")));
+}
+
+#[test]
+fn test_parse_money() {
+    let amount =
+        |symbol: &str, value: &str| parse_money(symbol, value).map(|m| m.amount.to_string());
+
+    assert_eq!(amount("$", "1,000.00"), Some("1000.00".into()));
+    assert_eq!(amount("€", "1.000,50"), Some("1000.50".into()));
+    assert_eq!(amount("¥", "1.000"), Some("1000".into()));
+    assert_eq!(amount("JPY", "99.99"), Some("99.99".into()));
+    assert_eq!(amount("$", "10.0.00"), None);
+    assert_eq!(amount("RM", "12.00"), Some("12.00".into()));
+    assert_eq!(amount("?", "1.00"), None);
 }
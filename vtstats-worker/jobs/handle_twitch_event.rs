@@ -0,0 +1,14 @@
+use integration_twitch::Event;
+use vtstats_database::PgPool;
+
+use super::JobResult;
+
+/// handles eventsub notification which was received by webhook
+pub async fn execute(pool: &PgPool, event: serde_json::Value) -> anyhow::Result<JobResult> {
+    // event borrows raw json, so it can't be deserialized from a `Value`
+    let event = serde_json::from_str::<Event>(&event.to_string())?;
+
+    crate::eventsub::handle_event(event, pool).await?;
+
+    Ok(JobResult::Completed)
+}
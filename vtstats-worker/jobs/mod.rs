@@ -1,8 +1,16 @@
+pub mod backfill_exchange_rates;
+pub mod backfill_stream_event_money;
+pub mod backfill_youtube_streams;
 pub mod collect_channel_stats;
 pub mod collect_stream_stats;
+pub mod handle_twitch_event;
 pub mod health_check;
+pub mod refresh_bilibili_live;
+pub mod refresh_twitch_schedule;
+pub mod refresh_vod_status;
 pub mod refresh_youtube_rss;
 pub mod send_notification;
+pub mod subscribe_twitch_eventsub;
 pub mod subscribe_youtube_pubsub;
 pub mod update_exchange_rates;
 
@@ -31,6 +39,7 @@ pub async fn execute(job: Job, pool: PgPool, client: Client, _shutdown_complete_
     let stream_id = match &payload {
         CollectYoutubeStreamMetadata(p) => Some(p.stream_id),
         CollectTwitchStreamMetadata(p) => Some(p.stream_id),
+        CollectBilibiliStreamMetadata(p) => Some(p.stream_id),
         SendNotification(p) => Some(p.stream_id),
         _ => None,
     };
@@ -67,10 +76,31 @@ pub async fn execute(job: Job, pool: PgPool, client: Client, _shutdown_complete_
             CollectTwitchStreamMetadata(payload) => {
                 collect_stream_stats::execute(&pool, client, payload.stream_id, next_run).await
             }
+            CollectBilibiliStreamMetadata(payload) => {
+                collect_stream_stats::execute(&pool, client, payload.stream_id, next_run).await
+            }
+            RefreshBilibiliLive => refresh_bilibili_live::execute(&pool, client).await,
+            SubscribeTwitchEventsub => subscribe_twitch_eventsub::execute(&pool, client).await,
+            RefreshTwitchSchedule => refresh_twitch_schedule::execute(&pool, client).await,
+            RefreshVodStatus => refresh_vod_status::execute(&pool, client).await,
+            BackfillStreamEventMoney => backfill_stream_event_money::execute(&pool).await,
+            HandleTwitchEvent(payload) => handle_twitch_event::execute(&pool, payload.event).await,
             SendNotification(payload) => {
                 send_notification::execute(&pool, client, payload.stream_id).await
             }
             UpdateExchangeRates => update_exchange_rates::execute(&pool, client).await,
+            BackfillExchangeRates(payload) => {
+                backfill_exchange_rates::execute(&pool, client, payload.since).await
+            }
+            BackfillYoutubeStreams(payload) => {
+                backfill_youtube_streams::execute(
+                    &pool,
+                    client,
+                    payload.channel_id,
+                    payload.with_chats,
+                )
+                .await
+            }
         };
 
         let status = if result.is_ok() { "ok" } else { "err" };
@@ -0,0 +1,75 @@
+use chrono::{Duration, DurationRound, TimeZone, Utc};
+use reqwest::Client;
+
+use integration_bilibili::live::{status_info_by_uids, LiveStatus, StatusInfo};
+use vtstats_database::{
+    channels::{list_active_channels_by_platform, Platform},
+    jobs::queue_collect_bilibili_stream_metadata,
+    streams::{get_stream_by_platform_id, StreamStatus, UpsertStreamQuery},
+    PgPool,
+};
+
+use super::JobResult;
+
+pub async fn execute(pool: &PgPool, client: Client) -> anyhow::Result<JobResult> {
+    let now = Utc::now().duration_trunc(Duration::minutes(1))?;
+
+    let bilibili_channels = list_active_channels_by_platform(Platform::Bilibili, pool).await?;
+
+    let mids: Vec<_> = bilibili_channels
+        .iter()
+        .map(|ch| ch.platform_id.as_str())
+        .collect();
+
+    for chunk in mids.chunks(50) {
+        let rooms = status_info_by_uids(chunk, &client).await?;
+
+        for channel in &bilibili_channels {
+            let Some(room) = rooms.get(&channel.platform_id) else {
+                continue;
+            };
+
+            if room.live_status != LiveStatus::Live {
+                continue;
+            }
+
+            let platform_stream_id = stream_platform_id(room);
+
+            if get_stream_by_platform_id(Platform::Bilibili, &platform_stream_id, pool)
+                .await?
+                .is_some()
+            {
+                continue;
+            }
+
+            let stream_id = UpsertStreamQuery {
+                platform: Platform::Bilibili,
+                vtuber_id: &channel.vtuber_id,
+                platform_stream_id: &platform_stream_id,
+                channel_id: channel.channel_id,
+                title: &room.title,
+                status: StreamStatus::Live,
+                thumbnail_url: Some(room.cover_from_user.clone()).filter(|url| !url.is_empty()),
+                schedule_time: None,
+                start_time: Utc.timestamp_opt(room.live_time, 0).single(),
+                end_time: None,
+                stream_kind: None,
+                access: None,
+            }
+            .execute(pool)
+            .await?;
+
+            queue_collect_bilibili_stream_metadata(Utc::now(), stream_id, pool).await?;
+        }
+    }
+
+    Ok(JobResult::Next {
+        run: now + Duration::minutes(1),
+    })
+}
+
+// bilibili doesn't assign ids to each broadcast, so
+// room id and start time are combined to identify one
+pub fn stream_platform_id(room: &StatusInfo) -> String {
+    format!("{}-{}", room.room_id, room.live_time)
+}
@@ -0,0 +1,163 @@
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use reqwest::Client;
+
+use integration_twitch::gql::{channel_panels, stream_schedule};
+use vtstats_database::{
+    categories::{assign_stream_category, UpsertCategoryQuery},
+    channels::{list_active_channels_by_platform, Channel, Platform},
+    streams::{
+        delete_stream, find_live_stream_id, list_scheduled_streams_by_channel, ScheduledStream,
+        StreamStatus, UpsertStreamQuery,
+    },
+    PgPool,
+};
+
+use super::JobResult;
+
+/// pulls schedule of each active twitch channel, streams scheduled
+/// in schedule segments are reconciled with the real broadcast when
+/// it goes online, see `crate::eventsub::handle_event`
+pub async fn execute(pool: &PgPool, client: Client) -> anyhow::Result<JobResult> {
+    let now = Utc::now().duration_trunc(Duration::hours(1))?;
+
+    let channels = list_active_channels_by_platform(Platform::Twitch, pool).await?;
+
+    for channel in channels {
+        if let Err(err) = refresh_channel(&channel, now, &client, pool).await {
+            tracing::warn!(
+                "Failed to refresh twitch schedule vtuber_id={} platform_id={}: {err:?}",
+                channel.vtuber_id,
+                channel.platform_id
+            );
+        }
+    }
+
+    Ok(JobResult::Next {
+        run: now + Duration::hours(1),
+    })
+}
+
+async fn refresh_channel(
+    channel: &Channel,
+    now: DateTime<Utc>,
+    client: &Client,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    let login = channel_panels(&channel.platform_id, client)
+        .await?
+        .data
+        .user
+        .login;
+
+    let res = stream_schedule(login.clone(), now, client).await?;
+
+    let is_live = find_live_stream_id(channel.channel_id, pool)
+        .await?
+        .is_some();
+
+    let segments: Vec<_> = res
+        .data
+        .user
+        .channel
+        .schedule
+        .iter()
+        .flat_map(|schedule| schedule.active_segments())
+        .collect();
+
+    for segment in &segments {
+        // segment of the ongoing broadcast has been claimed already
+        if is_live && segment.start_at < now + Duration::hours(2) {
+            continue;
+        }
+
+        let category = segment.categories.first();
+
+        let title = match (segment.title.as_str(), category) {
+            ("", Some(category)) => category.name.clone(),
+            ("", None) => format!("Twitch stream #{login}"),
+            (title, _) => title.to_string(),
+        };
+
+        // schedule segment id is used until the broadcast goes online
+        let stream_id = UpsertStreamQuery {
+            vtuber_id: &channel.vtuber_id,
+            platform: Platform::Twitch,
+            platform_stream_id: &segment.id,
+            channel_id: channel.channel_id,
+            title: &title,
+            status: StreamStatus::Scheduled,
+            schedule_time: Some(segment.start_at),
+            ..Default::default()
+        }
+        .execute(pool)
+        .await?;
+
+        if let Some(category) = category {
+            let category_id = UpsertCategoryQuery {
+                platform: Platform::Twitch,
+                platform_category_id: &category.id,
+                name: &category.name,
+            }
+            .execute(pool)
+            .await?;
+
+            assign_stream_category(stream_id, category_id, segment.start_at, pool).await?;
+        }
+    }
+
+    let scheduled = list_scheduled_streams_by_channel(channel.channel_id, pool).await?;
+
+    let segment_ids: Vec<_> = segments.iter().map(|s| s.id.as_str()).collect();
+
+    for stream_id in outdated_streams(&scheduled, &segment_ids, now) {
+        delete_stream(stream_id, pool).await?;
+    }
+
+    Ok(())
+}
+
+/// returns ids of scheduled streams to delete, which are either
+/// removed from the schedule, cancelled, during vacation, or never
+/// went online within 6 hours after schedule time
+fn outdated_streams(
+    scheduled: &[ScheduledStream],
+    segment_ids: &[&str],
+    now: DateTime<Utc>,
+) -> Vec<i32> {
+    scheduled
+        .iter()
+        .filter(|stream| match stream.schedule_time {
+            Some(time) if time < now - Duration::hours(6) => true,
+            Some(time) if time < now || time >= now + Duration::weeks(1) => false,
+            _ => !segment_ids.contains(&stream.platform_id.as_str()),
+        })
+        .map(|stream| stream.stream_id)
+        .collect()
+}
+
+#[test]
+fn test_outdated_streams() {
+    use chrono::TimeZone;
+
+    let now = Utc.timestamp_opt(1_700_000_000, 0).single().unwrap();
+
+    let stream = |stream_id: i32, platform_id: &str, hours: Option<i64>| ScheduledStream {
+        stream_id,
+        platform_id: platform_id.into(),
+        schedule_time: hours.map(|h| now + Duration::hours(h)),
+    };
+
+    let scheduled = vec![
+        stream(1, "segment1", Some(2)),
+        stream(2, "segment2", Some(26)),
+        stream(3, "segment3", Some(-2)),
+        stream(4, "segment4", Some(-8)),
+        stream(5, "segment5", Some(24 * 10)),
+        stream(6, "segment6", None),
+    ];
+
+    assert_eq!(
+        outdated_streams(&scheduled, &["segment1", "segment4"], now),
+        vec![2, 4, 6]
+    );
+}
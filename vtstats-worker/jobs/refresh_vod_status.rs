@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, DurationRound, Utc};
+use reqwest::Client;
+
+use integration_twitch::get_access_token;
+use integration_youtube::{data_api::videos::list_videos, youtubei::player};
+use vtstats_database::{
+    channels::Platform,
+    streams::{list_vod_checks, update_vod_status, VodCheck, VodStatus},
+    PgPool,
+};
+
+use super::JobResult;
+
+const LIMIT: i64 = 500;
+
+/// re-checks vods of ended streams, see `list_vod_checks` for the schedule
+pub async fn execute(pool: &PgPool, client: Client) -> anyhow::Result<JobResult> {
+    let now = Utc::now().duration_trunc(Duration::hours(1))?;
+
+    let checks = list_vod_checks(now, LIMIT, pool).await?;
+
+    let (youtube, twitch): (Vec<_>, Vec<_>) = checks
+        .iter()
+        .partition(|check| check.platform == Platform::Youtube);
+
+    // youtube limits 50 streams per request
+    for chunk in youtube.chunks(50) {
+        let ids: Vec<_> = chunk.iter().map(|c| c.platform_id.as_str()).collect();
+        let videos = match list_videos(&ids.join(","), &client).await {
+            Ok(videos) => videos,
+            Err(err) => {
+                tracing::warn!("Failed to list youtube videos: {err}");
+                continue;
+            }
+        };
+
+        for check in chunk {
+            let listed = videos.iter().any(|v| v.id == check.platform_id);
+
+            let status = match player(&check.platform_id, &client).await {
+                Ok(res) => res.vod_status(listed),
+                Err(err) => {
+                    tracing::warn!("Failed to get player of {}: {err}", check.platform_id);
+                    continue;
+                }
+            };
+
+            update_vod_status(check.stream_id, status, now, pool).await?;
+        }
+    }
+
+    if !twitch.is_empty() {
+        refresh_twitch(&twitch, &client, pool).await?;
+    }
+
+    Ok(JobResult::Next {
+        run: now + Duration::hours(1),
+    })
+}
+
+async fn refresh_twitch(
+    checks: &[&VodCheck],
+    client: &Client,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    let now = Utc::now().duration_trunc(Duration::hours(1))?;
+
+    let token = get_access_token(client).await?.access_token;
+
+    let mut by_channel = HashMap::<&str, Vec<&VodCheck>>::new();
+    for check in checks {
+        by_channel
+            .entry(check.channel_platform_id.as_str())
+            .or_default()
+            .push(check);
+    }
+
+    for (user_id, checks) in by_channel {
+        // archives of last 30 days always fit in the first page
+        let res = match integration_twitch::list_videos(user_id.to_string(), None, &token, client)
+            .await
+        {
+            Ok(res) => res,
+            Err(err) => {
+                tracing::warn!("Failed to list twitch videos of {user_id}: {err}");
+                continue;
+            }
+        };
+
+        for check in checks {
+            let video = res.data.iter().find(|v| v.stream_id == check.platform_id);
+
+            // app access tokens never see private vods, and channels with
+            // storage disabled never archive, so a missing vod can't be told
+            // apart from a deleted one
+            let status = match video {
+                Some(video) if video.viewable == "public" => VodStatus::Available,
+                Some(_) => VodStatus::Private,
+                None => VodStatus::Unarchived,
+            };
+
+            update_vod_status(check.stream_id, status, now, pool).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -86,21 +86,7 @@ pub async fn execute(pool: &PgPool, client: Client) -> anyhow::Result<JobResult>
             continue;
         };
 
-        let thumbnail_url = match get_thumbnail(&stream.id, &client).await {
-            Ok((filename, content_type, bytes)) => {
-                match upload_file(&filename, bytes, &content_type, &client).await {
-                    Ok(thumbnail_url) => Some(thumbnail_url),
-                    Err(err) => {
-                        tracing::error!(exception.stacktrace = ?err, message= %err);
-                        None
-                    }
-                }
-            }
-            Err(err) => {
-                tracing::error!(exception.stacktrace = ?err, message= %err);
-                None
-            }
-        };
+        let thumbnail_url = upload_thumbnail(&stream.id, &client).await;
 
         UpsertStreamQuery {
             platform: Platform::Youtube,
@@ -113,6 +99,8 @@ pub async fn execute(pool: &PgPool, client: Client) -> anyhow::Result<JobResult>
             schedule_time: stream.schedule_time,
             start_time: stream.start_time,
             end_time: stream.end_time,
+            stream_kind: None,
+            access: None,
         }
         .execute(pool)
         .await?;
@@ -123,6 +111,25 @@ pub async fn execute(pool: &PgPool, client: Client) -> anyhow::Result<JobResult>
     })
 }
 
+/// uploads thumbnail of given video to s3, returns its url
+pub async fn upload_thumbnail(video_id: &str, client: &Client) -> Option<String> {
+    match get_thumbnail(video_id, client).await {
+        Ok((filename, content_type, bytes)) => {
+            match upload_file(&filename, bytes, &content_type, client).await {
+                Ok(thumbnail_url) => Some(thumbnail_url),
+                Err(err) => {
+                    tracing::error!(exception.stacktrace = ?err, message= %err);
+                    None
+                }
+            }
+        }
+        Err(err) => {
+            tracing::error!(exception.stacktrace = ?err, message= %err);
+            None
+        }
+    }
+}
+
 // TODO: add unit tests
 
 fn find_video_id(feed: &str) -> Option<String> {
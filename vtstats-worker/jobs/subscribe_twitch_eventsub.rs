@@ -0,0 +1,222 @@
+use chrono::{Duration, DurationRound, Utc};
+use reqwest::Client;
+
+use integration_twitch::{
+    callback_url, create_channel_update_subscription, create_stream_offline_subscription,
+    create_stream_online_subscription, delete_subscription, get_access_token, list_subscriptions,
+    webhook_transport, CreatedSubscription, Subscription,
+};
+use vtstats_database::{
+    channels::{list_active_channels_by_platform, Platform},
+    PgPool,
+};
+
+use super::JobResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SubscriptionType {
+    StreamOnline,
+    StreamOffline,
+    ChannelUpdate,
+}
+
+const SUBSCRIPTION_TYPES: [SubscriptionType; 3] = [
+    SubscriptionType::StreamOnline,
+    SubscriptionType::StreamOffline,
+    SubscriptionType::ChannelUpdate,
+];
+
+impl From<&Subscription> for SubscriptionType {
+    fn from(subscription: &Subscription) -> Self {
+        match subscription {
+            Subscription::StreamOnline { .. } => SubscriptionType::StreamOnline,
+            Subscription::StreamOffline { .. } => SubscriptionType::StreamOffline,
+            Subscription::ChannelUpdate { .. } => SubscriptionType::ChannelUpdate,
+        }
+    }
+}
+
+/// makes eventsub subscriptions match active twitch channels, this job
+/// is also queued when twitch revokes one of our subscriptions
+///
+/// with websocket transport, it only asks the session to subscribe
+/// newly active channels and unsubscribe retired ones, see `crate::eventsub`
+pub async fn execute(pool: &PgPool, client: Client) -> anyhow::Result<JobResult> {
+    let next_run = Utc::now().duration_trunc(Duration::hours(1))? + Duration::hours(1);
+
+    if crate::eventsub::is_enabled() {
+        crate::eventsub::resubscribe();
+        return Ok(JobResult::Next { run: next_run });
+    }
+
+    let channels = list_active_channels_by_platform(Platform::Twitch, pool).await?;
+
+    let token = get_access_token(&client).await?.access_token;
+
+    let mut subscriptions = Vec::new();
+    let mut after = None;
+    loop {
+        let res = list_subscriptions(after, &token, &client).await?;
+        subscriptions.extend(res.data);
+        match res.pagination.cursor {
+            Some(cursor) => after = Some(cursor),
+            None => break,
+        }
+    }
+
+    let broadcaster_ids: Vec<_> = channels.iter().map(|c| c.platform_id.as_str()).collect();
+
+    // subscriptions of other deployments may use websocket transport
+    subscriptions.retain(|s| s.subscription.transport().method == "webhook");
+
+    let (to_delete, to_create) = reconcile(&subscriptions, &broadcaster_ids, &callback_url()?);
+
+    for id in to_delete {
+        if let Err(err) = delete_subscription(id, &token, &client).await {
+            tracing::error!("Failed to delete twitch subscription {id}: {err}");
+        }
+    }
+
+    for (ty, broadcaster_id) in to_create {
+        let id = broadcaster_id.to_string();
+        let transport = webhook_transport()?;
+        let result = match ty {
+            SubscriptionType::StreamOnline => {
+                create_stream_online_subscription(id, transport, &token, &client).await
+            }
+            SubscriptionType::StreamOffline => {
+                create_stream_offline_subscription(id, transport, &token, &client).await
+            }
+            SubscriptionType::ChannelUpdate => {
+                create_channel_update_subscription(id, transport, &token, &client).await
+            }
+        };
+        if let Err(err) = result {
+            tracing::error!(
+                "Failed to create twitch {ty:?} subscription of {broadcaster_id}: {err}"
+            );
+        }
+    }
+
+    Ok(JobResult::Next { run: next_run })
+}
+
+/// returns ids of subscriptions to delete, and subscriptions to create
+///
+/// only subscriptions delivered to our callback are considered, others
+/// belong to other deployments sharing the twitch app and are left alone,
+/// a subscription is kept when it's enabled or still waiting for callback
+/// verification and its broadcaster is still active, anything else is
+/// deleted and created again if its broadcaster is active
+fn reconcile<'a>(
+    subscriptions: &'a [CreatedSubscription],
+    broadcaster_ids: &[&'a str],
+    callback: &str,
+) -> (Vec<&'a str>, Vec<(SubscriptionType, &'a str)>) {
+    let (kept, deleted): (Vec<_>, Vec<_>) = subscriptions
+        .iter()
+        .filter(|s| s.subscription.transport().callback == callback)
+        .partition(|s| {
+            matches!(
+                s.status.as_str(),
+                "enabled" | "webhook_callback_verification_pending"
+            ) && broadcaster_ids.contains(&s.subscription.condition().broadcaster_user_id.as_str())
+        });
+
+    let to_create = broadcaster_ids
+        .iter()
+        .flat_map(|id| SUBSCRIPTION_TYPES.iter().map(move |ty| (*ty, *id)))
+        .filter(|(ty, id)| {
+            !kept.iter().any(|s| {
+                SubscriptionType::from(&s.subscription) == *ty
+                    && s.subscription.condition().broadcaster_user_id == *id
+            })
+        })
+        .collect();
+
+    (
+        deleted.into_iter().map(|s| s.id.as_str()).collect(),
+        to_create,
+    )
+}
+
+#[test]
+fn test_reconcile() {
+    use integration_twitch::{Condition, Transport};
+
+    let subscription = |id: &str, status: &str, ty: SubscriptionType, broadcaster: &str| {
+        let transport = Transport {
+            method: "webhook".into(),
+            callback: "https://vt.poi.cat/api/twitch".into(),
+            secret: None,
+            session_id: None,
+        };
+        let condition = Condition {
+            broadcaster_user_id: broadcaster.into(),
+        };
+        let version = "1".to_string();
+        CreatedSubscription {
+            id: id.into(),
+            status: status.into(),
+            created_at: Utc::now(),
+            subscription: match ty {
+                SubscriptionType::StreamOnline => Subscription::StreamOnline {
+                    version,
+                    transport,
+                    condition,
+                },
+                SubscriptionType::StreamOffline => Subscription::StreamOffline {
+                    version,
+                    transport,
+                    condition,
+                },
+                SubscriptionType::ChannelUpdate => Subscription::ChannelUpdate {
+                    version,
+                    transport,
+                    condition,
+                },
+            },
+        }
+    };
+
+    let subscriptions = vec![
+        subscription("1", "enabled", SubscriptionType::StreamOnline, "100"),
+        subscription("2", "enabled", SubscriptionType::StreamOffline, "100"),
+        subscription(
+            "3",
+            "authorization_revoked",
+            SubscriptionType::ChannelUpdate,
+            "100",
+        ),
+        subscription("4", "enabled", SubscriptionType::StreamOnline, "200"),
+        subscription(
+            "5",
+            "webhook_callback_verification_pending",
+            SubscriptionType::StreamOnline,
+            "300",
+        ),
+    ];
+
+    let (to_delete, to_create) = reconcile(
+        &subscriptions,
+        &["100", "300"],
+        "https://vt.poi.cat/api/twitch",
+    );
+
+    assert_eq!(to_delete, vec!["3", "4"]);
+    assert_eq!(
+        to_create,
+        vec![
+            (SubscriptionType::ChannelUpdate, "100"),
+            (SubscriptionType::StreamOffline, "300"),
+            (SubscriptionType::ChannelUpdate, "300"),
+        ]
+    );
+
+    // subscriptions of another deployment are neither deleted nor reused
+    let (to_delete, to_create) =
+        reconcile(&subscriptions, &["100"], "https://example.com/api/twitch");
+
+    assert!(to_delete.is_empty());
+    assert_eq!(to_create.len(), 3);
+}
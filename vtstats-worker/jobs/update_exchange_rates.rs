@@ -1,13 +1,36 @@
-use chrono::{Duration, DurationRound, Utc};
-use reqwest::Client;
+use chrono::{DateTime, Datelike, Duration, DurationRound, TimeZone, Utc};
+use reqwest::{Client, Url};
 use serde::Deserialize;
-use vtstats_database::{exchange_rates::update_exchange_rates, PgPool};
+use vtstats_database::{
+    exchange_rates::{add_exchange_rate_history, update_exchange_rates},
+    PgPool,
+};
+use vtstats_utils::send_request;
 
 use super::JobResult;
 
 pub async fn execute(pool: &PgPool, client: Client) -> anyhow::Result<JobResult> {
     let now = Utc::now().duration_trunc(Duration::hours(1))?;
 
+    let month = start_of_month(now)?;
+
+    let rates = monthly_rates(month, &client).await?;
+
+    update_exchange_rates(pool, now, rates.iter().cloned()).await?;
+
+    add_exchange_rate_history(pool, month, rates.into_iter()).await?;
+
+    Ok(JobResult::Next {
+        run: now + Duration::days(10),
+    })
+}
+
+/// fetch monthly exchange rates (based on EUR) of given month from InforEuro,
+/// rates of a month are valid from its first day until the next month
+pub async fn monthly_rates(
+    month: DateTime<Utc>,
+    client: &Client,
+) -> anyhow::Result<Vec<(String, f32)>> {
     #[derive(Deserialize)]
     struct Rate {
         #[serde(rename = "isoA3Code")]
@@ -15,16 +38,35 @@ pub async fn execute(pool: &PgPool, client: Client) -> anyhow::Result<JobResult>
         value: f32,
     }
 
-    let res = client
-        .get("https://ec.europa.eu/budg/inforeuro/api/public/monthly-rates")
-        .send()
-        .await?;
+    let url = Url::parse_with_params(
+        "https://ec.europa.eu/budg/inforeuro/api/public/monthly-rates",
+        &[
+            ("year", month.year().to_string()),
+            ("month", month.month().to_string()),
+        ],
+    )?;
+
+    let res = send_request!(client.get(url), "/budg/inforeuro/api/public/monthly-rates")?;
 
     let rates: Vec<Rate> = res.json().await?;
 
-    update_exchange_rates(pool, now, rates.into_iter().map(|r| (r.code, r.value))).await?;
+    Ok(rates.into_iter().map(|r| (r.code, r.value)).collect())
+}
 
-    Ok(JobResult::Next {
-        run: now + Duration::days(10),
-    })
+pub fn start_of_month(time: DateTime<Utc>) -> anyhow::Result<DateTime<Utc>> {
+    Utc.with_ymd_and_hms(time.year(), time.month(), 1, 0, 0, 0)
+        .single()
+        .ok_or_else(|| anyhow::anyhow!("Invalid month of {time}"))
+}
+
+#[test]
+fn test_start_of_month() {
+    assert_eq!(
+        start_of_month(Utc.with_ymd_and_hms(2023, 7, 31, 23, 59, 59).unwrap()).unwrap(),
+        Utc.with_ymd_and_hms(2023, 7, 1, 0, 0, 0).unwrap()
+    );
+    assert_eq!(
+        start_of_month(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()).unwrap(),
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+    );
 }
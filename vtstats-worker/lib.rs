@@ -14,6 +14,7 @@ use vtstats_database::{
     PgListener, PgPool, PgPoolOptions,
 };
 
+pub mod eventsub;
 pub mod jobs;
 
 pub async fn main(shutdown_rx: Receiver<()>) -> anyhow::Result<()> {
@@ -50,6 +51,10 @@ async fn execute(shutdown_complete_tx: Sender<()>) -> anyhow::Result<()> {
 
     let client = vtstats_utils::reqwest::new()?;
 
+    if eventsub::is_enabled() {
+        tokio::spawn(eventsub::run(pool.clone(), client.clone()));
+    }
+
     tracing::warn!("Start executing jobs...");
 
     loop {